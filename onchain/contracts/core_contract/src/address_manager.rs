@@ -1,15 +1,35 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, Symbol, Vec};
 
+use crate::custom_resolver::CustomResolvers;
+use crate::delegates::Delegates;
 use crate::errors::{ChainAddressError, CoreError};
-use crate::events::{shielded_add_event, stellar_rem_event, ADDR_ADD, CHAIN_ADD, CHAIN_REM};
+use crate::events::{
+    addr_label_event, chain_entry_rem_event, chain_primary_event, chain_verified_event,
+    shielded_add_event, shielded_removed_event, shielded_rotated_event, stellar_primary_event,
+    stellar_rem_event, ADDR_ADD, CHAIN_ADD, CHAIN_REM,
+};
+use crate::history::History;
+use crate::link_verifier::LinkVerifier;
+use crate::payer_memos::PayerMemos;
+use crate::privacy::Privacy;
 use crate::registration::{DataKey as CommitmentKey, Registration};
-use crate::storage::{self, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
-use crate::types::ChainType;
+use crate::storage::{
+    self, MAX_ADDRESSES_PER_CHAIN, MAX_PAGE_SIZE, MAX_RESOLVE_BATCH, MAX_SHIELDED_HISTORY,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD,
+};
+use crate::transfer::Transfer;
+use crate::types::{
+    AddressMetadata, ChainAddressRecord, ChainType, DelegateRole, HistoryField, ResolveStatus,
+    ShieldedRecord, StellarAddressEntry, Visibility,
+};
 
 #[contracttype]
 #[derive(Clone)]
 pub enum ChainAddrKey {
+    /// Primary address for a chain, as raw bytes.
     ChainAddress(BytesN<32>, ChainType),
+    /// Bounded list of every address linked for a chain.
+    ChainAddresses(BytesN<32>, ChainType),
 }
 
 pub struct AddressManager;
@@ -18,7 +38,9 @@ impl AddressManager {
     /// Adds a blockchain address for a registered commitment on a specified chain.
     ///
     /// Links a non-Stellar blockchain address (Bitcoin, Ethereum, Solana, Cosmos) to the username.
-    /// Only the commitment owner can authorize this action. Validates the address format for the chain.
+    /// Only the commitment owner or an `Addresses` delegate can authorize this action.
+    /// Validates the address format for the chain. The new address is appended to the chain's
+    /// list (up to `MAX_ADDRESSES_PER_CHAIN`) and becomes the primary address for that chain.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -29,8 +51,11 @@ impl AddressManager {
     ///
     /// ### Errors
     /// - `NotRegistered`: If the username commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
     /// - `InvalidAddress`: If the address format is invalid for the specified chain.
+    /// - `DuplicateAddress`: If the address is already linked for this chain.
+    /// - `AddressLimitReached`: If the chain already holds the maximum number of addresses.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     ///
     /// ### Events
     /// - Emits `CHAIN_ADD` event with (username_hash, chain, address).
@@ -41,40 +66,148 @@ impl AddressManager {
         chain: ChainType,
         address: Bytes,
     ) {
-        caller.require_auth();
+        Self::require_chain_manager(&env, &caller, &username_hash);
+        Transfer::require_not_frozen(&env, &username_hash);
 
-        let owner_key = CommitmentKey::Commitment(username_hash.clone());
-        let owner: Address = env
-            .storage()
-            .persistent()
-            .get(&owner_key)
-            .unwrap_or_else(|| panic_with_error!(&env, ChainAddressError::NotRegistered));
+        if !Self::validate_address(&chain, &address) {
+            panic_with_error!(&env, ChainAddressError::InvalidAddress);
+        }
 
-        if owner != caller {
-            panic_with_error!(&env, ChainAddressError::Unauthorized);
+        let mut records = Self::chain_records(&env, &username_hash, &chain);
+        if Self::find_chain_record(&records, &address).is_some() {
+            panic_with_error!(&env, ChainAddressError::DuplicateAddress);
         }
+        if records.len() >= MAX_ADDRESSES_PER_CHAIN {
+            panic_with_error!(&env, ChainAddressError::AddressLimitReached);
+        }
+        records.push_back(ChainAddressRecord {
+            address: address.clone(),
+            verified: false,
+            metadata: Self::default_metadata(&env),
+        });
+        Self::store_chain_records(&env, &username_hash, &chain, &records);
+        Self::store_chain_primary(&env, &username_hash, &chain, &address);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((CHAIN_ADD,), (username_hash, chain, address));
+    }
+
+    /// Adds a blockchain address whose control is proven by a signature from that address.
+    ///
+    /// The external key must sign the message returned by [`Self::chain_link_message`]
+    /// for the current link nonce. EVM addresses take a 65-byte `personal_sign`
+    /// secp256k1 signature (`r || s || v`); Solana addresses take a 64-byte ed25519
+    /// signature. On success the entry is stored with `verified: true`, becomes the
+    /// chain's primary address, and the nonce is advanced so the signature cannot be
+    /// replayed. An already-linked unverified address is upgraded in place.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner authorizing the addition. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `chain`: The blockchain type (EVM or Solana).
+    /// - `address`: The blockchain address as bytes (format validated per chain).
+    /// - `signature`: The external key's signature over the link message.
+    ///
+    /// ### Errors
+    /// - `NotRegistered`: If the username commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `InvalidAddress`: If the address format is invalid for the specified chain.
+    /// - `UnsupportedChain`: If the chain has no signature verification scheme.
+    /// - `InvalidSignature`: If an EVM signature does not prove control of `address`, or a
+    ///   Solana signature is malformed (wrong length or non-canonical `S`). A well-formed
+    ///   Solana signature that fails to verify traps in the host instead.
+    /// - `AddressLimitReached`: If the chain already holds the maximum number of addresses.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `CHAIN_VERIFIED` event with (username_hash, chain, address, nonce).
+    pub fn add_verified_chain_address(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+        address: Bytes,
+        signature: Bytes,
+    ) {
+        Self::require_chain_manager(&env, &caller, &username_hash);
+        Transfer::require_not_frozen(&env, &username_hash);
 
         if !Self::validate_address(&chain, &address) {
             panic_with_error!(&env, ChainAddressError::InvalidAddress);
         }
 
-        let key = ChainAddrKey::ChainAddress(username_hash.clone(), chain.clone());
-        env.storage().persistent().set(&key, &address);
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
+        let nonce = storage::get_link_nonce(&env, &username_hash);
+        let message = LinkVerifier::link_message(&env, &username_hash, &chain, nonce);
+        let valid = match chain {
+            ChainType::Evm => LinkVerifier::verify_evm(&env, &address, &message, &signature),
+            ChainType::Solana => LinkVerifier::verify_solana(&env, &address, &message, &signature),
+            _ => panic_with_error!(&env, ChainAddressError::UnsupportedChain),
+        };
+        if !valid {
+            panic_with_error!(&env, ChainAddressError::InvalidSignature);
+        }
+        storage::set_link_nonce(&env, &username_hash, nonce + 1);
+
+        let mut records = Self::chain_records(&env, &username_hash, &chain);
+        match Self::find_chain_record(&records, &address) {
+            Some(index) => {
+                let mut record = records
+                    .get(index)
+                    .expect("index returned by find_chain_record is in bounds");
+                record.verified = true;
+                records.set(index, record);
+            }
+            None => {
+                if records.len() >= MAX_ADDRESSES_PER_CHAIN {
+                    panic_with_error!(&env, ChainAddressError::AddressLimitReached);
+                }
+                records.push_back(ChainAddressRecord {
+                    address: address.clone(),
+                    verified: true,
+                    metadata: Self::default_metadata(&env),
+                });
+            }
+        }
+        Self::store_chain_records(&env, &username_hash, &chain, &records);
+        Self::store_chain_primary(&env, &username_hash, &chain, &address);
 
         #[allow(deprecated)]
-        env.events()
-            .publish((CHAIN_ADD,), (username_hash, chain, address));
+        env.events().publish(
+            (chain_verified_event(&env),),
+            (username_hash, chain, address, nonce),
+        );
+    }
+
+    /// Returns the message an external key must sign for the next verified link.
+    ///
+    /// The message binds the contract id, the commitment, the chain and the current
+    /// link nonce. Clients sign it off-chain and pass the signature to
+    /// [`Self::add_verified_chain_address`].
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `chain`: The blockchain type the link is for.
+    ///
+    /// ### Returns
+    /// The raw message bytes (EVM wallets apply the EIP-191 prefix when signing).
+    pub fn chain_link_message(env: Env, username_hash: BytesN<32>, chain: ChainType) -> Bytes {
+        let nonce = storage::get_link_nonce(&env, &username_hash);
+        LinkVerifier::link_message(&env, &username_hash, &chain, nonce)
     }
 
-    /// Retrieves the blockchain address for a commitment on a specified chain.
+    /// Returns the nonce expected by the next verified chain link for a commitment.
+    pub fn get_link_nonce(env: Env, username_hash: BytesN<32>) -> u64 {
+        storage::get_link_nonce(&env, &username_hash)
+    }
+
+    /// Retrieves the primary blockchain address for a commitment on a specified chain.
     ///
-    /// Returns the stored address for the given commitment and blockchain type, if set.
-    /// This is a read-only operation with no authentication requirement.
+    /// Returns the primary address for the given commitment and blockchain type, if set.
+    /// A custom resolver, when set, is asked first. This is a read-only operation with no
+    /// authentication requirement.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -83,30 +216,176 @@ impl AddressManager {
     ///
     /// ### Returns
     /// - `Some(Bytes)` if an address exists for this chain.
-    /// - `None` if no address is set for this chain.
+    /// - `None` if no address is set for this chain, or the chain is not public.
     pub fn get_chain_address(
         env: Env,
         username_hash: BytesN<32>,
         chain: ChainType,
     ) -> Option<Bytes> {
+        if !Self::is_chain_public(&env, &username_hash, &chain) {
+            return None;
+        }
+        if let Some(address) = CustomResolvers::resolve_chain(&env, &username_hash, &chain) {
+            return Some(address);
+        }
         let key = ChainAddrKey::ChainAddress(username_hash, chain);
         env.storage().persistent().get(&key)
     }
 
-    /// Removes a blockchain address for a commitment on a specified chain.
+    /// Retrieves a signature-verified blockchain address for a chain.
+    ///
+    /// Returns the primary address when it is verified; otherwise the earliest verified
+    /// address in the chain's list.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `chain`: The blockchain type to query.
+    ///
+    /// ### Returns
+    /// - `Some(Bytes)` if a verified address exists for this chain.
+    /// - `None` if no verified address is linked, or the chain is not public.
+    pub fn get_verified_chain_address(
+        env: Env,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+    ) -> Option<Bytes> {
+        if !Self::is_chain_public(&env, &username_hash, &chain) {
+            return None;
+        }
+        let records = Self::chain_records(&env, &username_hash, &chain);
+        let primary_key = ChainAddrKey::ChainAddress(username_hash, chain);
+        if let Some(primary) = env.storage().persistent().get::<_, Bytes>(&primary_key) {
+            if let Some(index) = Self::find_chain_record(&records, &primary) {
+                if records.get(index).is_some_and(|r| r.verified) {
+                    return Some(primary);
+                }
+            }
+        }
+        records
+            .iter()
+            .find(|record| record.verified)
+            .map(|record| record.address)
+    }
+
+    /// Lists the addresses linked for a chain, paginated.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `chain`: The blockchain type to query.
+    /// - `cursor`: Index of the first entry to return.
+    /// - `limit`: Maximum number of entries to return (capped at `MAX_PAGE_SIZE`).
+    ///
+    /// ### Returns
+    /// The entries in insertion order, with their verification flag and label. Empty if
+    /// the chain is not public.
+    pub fn list_chain_addresses(
+        env: Env,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<ChainAddressRecord> {
+        if !Self::is_chain_public(&env, &username_hash, &chain) {
+            return Vec::new(&env);
+        }
+        let records = Self::chain_records(&env, &username_hash, &chain);
+        Self::page(&env, &records, cursor, limit)
+    }
+
+    /// Designates an already-linked address as the primary address for a chain.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `chain`: The blockchain type.
+    /// - `address`: An address already present in the chain's list.
+    ///
+    /// ### Errors
+    /// - `NotRegistered`: If the username commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `AddressNotFound`: If the address is not linked for this chain.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `CHAIN_PRIMARY` event with (username_hash, chain, address).
+    pub fn set_primary_chain_address(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+        address: Bytes,
+    ) {
+        Self::require_chain_manager(&env, &caller, &username_hash);
+        Transfer::require_not_frozen(&env, &username_hash);
+
+        let records = Self::chain_records(&env, &username_hash, &chain);
+        if Self::find_chain_record(&records, &address).is_none() {
+            panic_with_error!(&env, ChainAddressError::AddressNotFound);
+        }
+        Self::store_chain_primary(&env, &username_hash, &chain, &address);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (chain_primary_event(&env),),
+            (username_hash, chain, address),
+        );
+    }
+
+    /// Sets the label of a linked chain address.
     ///
-    /// Deletes the stored address for the given commitment and blockchain type.
-    /// Only the commitment owner can authorize this action.
+    /// ### Errors
+    /// - `NotRegistered`: If the username commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `AddressNotFound`: If the address is not linked for this chain.
+    ///
+    /// ### Events
+    /// - Emits `ADDR_LABEL` event with (username_hash, address, label).
+    pub fn set_chain_address_label(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+        address: Bytes,
+        metadata: AddressMetadata,
+    ) {
+        Self::require_chain_manager(&env, &caller, &username_hash);
+
+        let mut records = Self::chain_records(&env, &username_hash, &chain);
+        let index = Self::find_chain_record(&records, &address)
+            .unwrap_or_else(|| panic_with_error!(&env, ChainAddressError::AddressNotFound));
+        let mut record = records
+            .get(index)
+            .expect("index returned by find_chain_record is in bounds");
+        record.metadata = metadata.clone();
+        records.set(index, record);
+        Self::store_chain_records(&env, &username_hash, &chain, &records);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (addr_label_event(&env),),
+            (username_hash, address, metadata.label),
+        );
+    }
+
+    /// Removes every address linked for a chain.
+    ///
+    /// Deletes the address list and primary address for the given commitment and
+    /// blockchain type. Only the commitment owner or an `Addresses` delegate can
+    /// authorize this action.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
     /// - `caller`: The commitment owner authorizing the removal. Must be authorized.
     /// - `username_hash`: The 32-byte username commitment.
-    /// - `chain`: The blockchain type to remove the address from.
+    /// - `chain`: The blockchain type to remove the addresses from.
     ///
     /// ### Errors
     /// - `NotRegistered`: If the username commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     ///
     /// ### Events
     /// - Emits `CHAIN_REM` event with (username_hash, chain).
@@ -116,31 +395,80 @@ impl AddressManager {
         username_hash: BytesN<32>,
         chain: ChainType,
     ) {
-        caller.require_auth();
+        Self::require_chain_manager(&env, &caller, &username_hash);
+        Transfer::require_not_frozen(&env, &username_hash);
 
-        let owner_key = CommitmentKey::Commitment(username_hash.clone());
-        let owner: Address = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&owner_key)
-            .unwrap_or_else(|| panic_with_error!(&env, ChainAddressError::NotRegistered));
+            .remove(&ChainAddrKey::ChainAddress(
+                username_hash.clone(),
+                chain.clone(),
+            ));
+        env.storage()
+            .persistent()
+            .remove(&ChainAddrKey::ChainAddresses(
+                username_hash.clone(),
+                chain.clone(),
+            ));
 
-        if owner != caller {
-            panic_with_error!(&env, ChainAddressError::Unauthorized);
-        }
+        #[allow(deprecated)]
+        env.events().publish((CHAIN_REM,), (username_hash, chain));
+    }
 
-        let key = ChainAddrKey::ChainAddress(username_hash.clone(), chain.clone());
-        env.storage().persistent().remove(&key);
+    /// Removes a single linked address for a chain.
+    ///
+    /// If the removed address was the primary, the most recently added remaining
+    /// address becomes primary, or the primary is cleared when none remain.
+    ///
+    /// ### Errors
+    /// - `NotRegistered`: If the username commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `AddressNotFound`: If the address is not linked for this chain.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `CHAIN_ENTRY_REM` event with (username_hash, chain, address).
+    pub fn remove_chain_address_entry(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        chain: ChainType,
+        address: Bytes,
+    ) {
+        Self::require_chain_manager(&env, &caller, &username_hash);
+        Transfer::require_not_frozen(&env, &username_hash);
+
+        let mut records = Self::chain_records(&env, &username_hash, &chain);
+        let index = Self::find_chain_record(&records, &address)
+            .unwrap_or_else(|| panic_with_error!(&env, ChainAddressError::AddressNotFound));
+        records.remove(index);
+        Self::store_chain_records(&env, &username_hash, &chain, &records);
+
+        let primary_key = ChainAddrKey::ChainAddress(username_hash.clone(), chain.clone());
+        let primary: Option<Bytes> = env.storage().persistent().get(&primary_key);
+        if primary == Some(address.clone()) {
+            match records.last() {
+                Some(last) => {
+                    Self::store_chain_primary(&env, &username_hash, &chain, &last.address)
+                }
+                None => env.storage().persistent().remove(&primary_key),
+            }
+        }
 
         #[allow(deprecated)]
-        env.events().publish((CHAIN_REM,), (username_hash, chain));
+        env.events().publish(
+            (chain_entry_rem_event(&env),),
+            (username_hash, chain, address),
+        );
     }
 
     /// Adds a Stellar address (receiver) for a registered commitment.
     ///
     /// Links a Stellar wallet address to the username, enabling payment resolution on Stellar.
-    /// Only the commitment owner can authorize this action. This address is separate from
-    /// the owner address and represents where payments should be received.
+    /// Only the commitment owner or a `Payments` delegate can authorize this action.
+    /// This address is separate from the owner address and represents where payments should
+    /// be received. The new address is appended to the list (up to `MAX_ADDRESSES_PER_CHAIN`)
+    /// and becomes the primary.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -150,6 +478,10 @@ impl AddressManager {
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor a `Payments` delegate.
+    /// - `DuplicateAddress`: If the address is already linked.
+    /// - `AddressLimitReached`: If the maximum number of Stellar addresses is linked.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     ///
     /// ### Events
     /// - Emits `ADDR_ADD` event with stellar_address as data.
@@ -159,30 +491,22 @@ impl AddressManager {
         username_hash: BytesN<32>,
         stellar_address: Address,
     ) {
-        caller.require_auth();
-
-        let owner = Registration::get_owner(env.clone(), username_hash.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &username_hash);
 
-        if owner != caller {
-            panic_with_error!(&env, CoreError::NotFound);
+        let mut entries = Self::stellar_entries(&env, &username_hash);
+        if Self::find_stellar_entry(&entries, &stellar_address).is_some() {
+            panic_with_error!(&env, CoreError::DuplicateAddress);
         }
-
-        let mut linked_addresses: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&storage::DataKey::StellarAddresses(username_hash.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-        linked_addresses.push_back(stellar_address.clone());
-        env.storage().persistent().set(
-            &storage::DataKey::StellarAddresses(username_hash.clone()),
-            &linked_addresses,
-        );
-
-        env.storage().persistent().set(
-            &storage::DataKey::StellarAddress(username_hash),
-            &stellar_address,
-        );
+        if entries.len() >= MAX_ADDRESSES_PER_CHAIN {
+            panic_with_error!(&env, CoreError::AddressLimitReached);
+        }
+        entries.push_back(StellarAddressEntry {
+            address: stellar_address.clone(),
+            metadata: Self::default_metadata(&env),
+        });
+        Self::store_stellar_entries(&env, &username_hash, &entries);
+        Self::store_stellar_primary(&env, &username_hash, &stellar_address);
 
         #[allow(deprecated)]
         env.events().publish((ADDR_ADD,), stellar_address.clone());
@@ -193,7 +517,7 @@ impl AddressManager {
     /// Removes the address from the history list. If it was the primary address
     /// (`StellarAddress` key), the primary is updated to the most-recently added
     /// remaining address, or the key is removed entirely when the list is empty.
-    /// Only the commitment owner can authorize this action.
+    /// Only the commitment owner or a `Payments` delegate can authorize this action.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -203,7 +527,8 @@ impl AddressManager {
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     ///
     /// ### Events
     /// - Emits `STELLAR_REM` event with (username_hash, stellar_address).
@@ -213,32 +538,15 @@ impl AddressManager {
         username_hash: BytesN<32>,
         stellar_address: Address,
     ) {
-        caller.require_auth();
-
-        let owner = Registration::get_owner(env.clone(), username_hash.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
-
-        if owner != caller {
-            panic_with_error!(&env, CoreError::Unauthorized);
-        }
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &username_hash);
 
         // Rebuild the history list without the removed address.
-        let existing: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&storage::DataKey::StellarAddresses(username_hash.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-
-        let mut updated: Vec<Address> = Vec::new(&env);
-        for addr in existing.iter() {
-            if addr != stellar_address {
-                updated.push_back(addr);
-            }
+        let mut entries = Self::stellar_entries(&env, &username_hash);
+        if let Some(index) = Self::find_stellar_entry(&entries, &stellar_address) {
+            entries.remove(index);
         }
-        env.storage().persistent().set(
-            &storage::DataKey::StellarAddresses(username_hash.clone()),
-            &updated,
-        );
+        Self::store_stellar_entries(&env, &username_hash, &entries);
 
         // If the removed address was the current primary, update or clear it.
         let primary: Option<Address> = env
@@ -248,18 +556,14 @@ impl AddressManager {
 
         if let Some(p) = primary {
             if p == stellar_address {
-                if updated.is_empty() {
-                    env.storage()
-                        .persistent()
-                        .remove(&storage::DataKey::StellarAddress(username_hash.clone()));
-                } else {
-                    let last = updated
-                        .get(updated.len() - 1)
-                        .expect("updated stellar address list should be non-empty");
-                    env.storage().persistent().set(
-                        &storage::DataKey::StellarAddress(username_hash.clone()),
-                        &last,
-                    );
+                match entries.last() {
+                    Some(last) => Self::store_stellar_primary(&env, &username_hash, &last.address),
+                    None => {
+                        env.storage()
+                            .persistent()
+                            .remove(&storage::DataKey::StellarAddress(username_hash.clone()));
+                        History::record(&env, &username_hash, HistoryField::StellarAddress);
+                    }
                 }
             }
         }
@@ -269,49 +573,201 @@ impl AddressManager {
             .publish((stellar_rem_event(&env),), (username_hash, stellar_address));
     }
 
+    /// Designates an already-linked Stellar address as the primary payment address.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `stellar_address`: An address already present in the Stellar address list.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or the address is not linked.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `STELLAR_PRIMARY` event with (username_hash, stellar_address).
+    pub fn set_primary_stellar_address(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        stellar_address: Address,
+    ) {
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &username_hash);
+
+        let entries = Self::stellar_entries(&env, &username_hash);
+        if Self::find_stellar_entry(&entries, &stellar_address).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        Self::store_stellar_primary(&env, &username_hash, &stellar_address);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (stellar_primary_event(&env),),
+            (username_hash, stellar_address),
+        );
+    }
+
+    /// Sets the label of a linked Stellar address.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or the address is not linked.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    ///
+    /// ### Events
+    /// - Emits `ADDR_LABEL` event with (username_hash, stellar_address, label).
+    pub fn set_stellar_address_label(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        stellar_address: Address,
+        metadata: AddressMetadata,
+    ) {
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+
+        let mut entries = Self::stellar_entries(&env, &username_hash);
+        let index = Self::find_stellar_entry(&entries, &stellar_address)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        entries.set(
+            index,
+            StellarAddressEntry {
+                address: stellar_address.clone(),
+                metadata: metadata.clone(),
+            },
+        );
+        Self::store_stellar_entries(&env, &username_hash, &entries);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (addr_label_event(&env),),
+            (username_hash, stellar_address, metadata.label),
+        );
+    }
+
+    /// Returns the linked Stellar addresses, or an empty list if they are not public.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
     pub fn get_stellar_addresses(env: Env, username_hash: BytesN<32>) -> Vec<Address> {
         if Registration::get_owner(env.clone(), username_hash.clone()).is_none() {
             panic_with_error!(&env, CoreError::NotFound);
         }
+        if Privacy::policy(&env, &username_hash).stellar != Visibility::Public {
+            return Vec::new(&env);
+        }
+        Self::stellar_addresses(&env, &username_hash)
+    }
 
-        env.storage()
-            .persistent()
-            .get::<storage::DataKey, Vec<Address>>(&storage::DataKey::StellarAddresses(
-                username_hash,
-            ))
-            .unwrap_or_else(|| Vec::new(&env))
+    /// Lists the linked Stellar addresses with their labels, paginated.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `cursor`: Index of the first entry to return.
+    /// - `limit`: Maximum number of entries to return (capped at `MAX_PAGE_SIZE`).
+    ///
+    /// ### Returns
+    /// The entries in insertion order. Empty if Stellar addresses are not public.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    pub fn list_stellar_addresses(
+        env: Env,
+        username_hash: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<StellarAddressEntry> {
+        if Registration::get_owner(env.clone(), username_hash.clone()).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        if Privacy::policy(&env, &username_hash).stellar != Visibility::Public {
+            return Vec::new(&env);
+        }
+        let entries = Self::stellar_entries(&env, &username_hash);
+        Self::page(&env, &entries, cursor, limit)
     }
 
-    /// Resolves a commitment to its linked Stellar address.
+    /// Resolves a commitment to its primary Stellar address.
     ///
     /// Returns the Stellar address designated for receiving payments for this username.
-    /// This is a read-only query that must have a valid linked address.
+    /// A custom resolver, when set, is asked first and its wallet is used; the primary
+    /// linked address is the fallback.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
     /// - `username_hash`: The 32-byte username commitment.
     ///
     /// ### Returns
-    /// The Stellar address linked to this commitment.
+    /// The Stellar address linked to this commitment, or the contract address if Stellar
+    /// addresses are not public.
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
-    /// - `NoAddressLinked`: If no Stellar address has been set for this commitment.
+    /// - `NoAddressLinked`: If neither the custom resolver nor a linked Stellar address
+    ///   resolves the commitment.
     pub fn resolve_stellar(env: Env, username_hash: BytesN<32>) -> Address {
         if Registration::get_owner(env.clone(), username_hash.clone()).is_none() {
             panic_with_error!(&env, CoreError::NotFound);
         }
 
-        env.storage()
-            .persistent()
-            .get::<storage::DataKey, Address>(&storage::DataKey::StellarAddress(username_hash))
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NoAddressLinked))
+        let address = Self::stellar_record(&env, &username_hash)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NoAddressLinked));
+        if Privacy::policy(&env, &username_hash).stellar != Visibility::Public {
+            return env.current_contract_address();
+        }
+        address
+    }
+
+    /// Resolves a batch of commitments to their primary Stellar addresses, reporting a
+    /// status per entry instead of panicking on the first one that does not resolve.
+    ///
+    /// Each entry follows [`Self::resolve_stellar`], except that a non-public Stellar
+    /// address is reported as `Shielded` rather than replaced by the contract address.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hashes`: The commitments to resolve, at most `MAX_RESOLVE_BATCH`, of
+    ///   which at most `MAX_RESOLVER_CALLS` may have a custom resolver.
+    ///
+    /// ### Returns
+    /// One `ResolveStatus` per commitment, in input order, with no memo.
+    ///
+    /// ### Errors
+    /// - `BatchTooLarge`: If `username_hashes` holds more than `MAX_RESOLVE_BATCH` entries,
+    ///   or more than `MAX_RESOLVER_CALLS` entries with a custom resolver.
+    pub fn resolve_stellar_many(env: Env, username_hashes: Vec<BytesN<32>>) -> Vec<ResolveStatus> {
+        if username_hashes.len() > MAX_RESOLVE_BATCH {
+            panic_with_error!(&env, CoreError::BatchTooLarge);
+        }
+        let mut resolver_calls = 0;
+        let mut statuses = Vec::new(&env);
+        for username_hash in username_hashes.iter() {
+            CustomResolvers::count_call(&env, &username_hash, &mut resolver_calls);
+            let status = match Self::stellar_record(&env, &username_hash) {
+                None if Registration::get_owner(env.clone(), username_hash.clone()).is_none() => {
+                    ResolveStatus::NotFound
+                }
+                None => ResolveStatus::NoAddress,
+                Some(_) if Privacy::policy(&env, &username_hash).stellar != Visibility::Public => {
+                    ResolveStatus::Shielded
+                }
+                Some(address) => ResolveStatus::Resolved(address, None),
+            };
+            statuses.push_back(status);
+        }
+        statuses
     }
 
     /// Adds a shielded (privacy-preserving) address commitment for a commitment.
     ///
     /// Stores a privacy commitment (e.g., a hash of a private address) that enables
-    /// shielded payment routing. Only the commitment owner can authorize this action.
+    /// shielded payment routing. Only the owner or a `Payments` delegate may authorize this.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -321,7 +777,8 @@ impl AddressManager {
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     ///
     /// ### Events
     /// - Emits shielded add event with (username_hash, address_commitment).
@@ -331,13 +788,9 @@ impl AddressManager {
         username_hash: BytesN<32>,
         address_commitment: BytesN<32>,
     ) {
-        caller.require_auth();
-        let owner = Registration::get_owner(env.clone(), username_hash.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
-        if owner != caller {
-            panic_with_error!(&env, CoreError::Unauthorized);
-        }
-        storage::set_shielded_address(&env, &username_hash, &address_commitment);
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &username_hash);
+        Self::store_shielded(&env, &username_hash, &address_commitment);
         #[allow(deprecated)]
         env.events().publish(
             (shielded_add_event(&env),),
@@ -345,6 +798,125 @@ impl AddressManager {
         );
     }
 
+    /// Replaces the current shielded address commitment, e.g. after it leaked.
+    ///
+    /// The previous commitment stays in the shielded history with its validity window
+    /// closed at the current ledger timestamp, so senders with in-flight payments can
+    /// still check it was valid when they sent.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `address_commitment`: The new 32-byte privacy commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no shielded address.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `DuplicateAddress`: If `address_commitment` is already the current one.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `SHIELDED_ROTATE` event with (username_hash, old_commitment, new_commitment).
+    pub fn rotate_shielded_address(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        address_commitment: BytesN<32>,
+    ) {
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        let old = storage::get_shielded_address(&env, &username_hash)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if old == address_commitment {
+            panic_with_error!(&env, CoreError::DuplicateAddress);
+        }
+        Transfer::require_not_frozen(&env, &username_hash);
+        Self::store_shielded(&env, &username_hash, &address_commitment);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (shielded_rotated_event(&env),),
+            (username_hash, old, address_commitment),
+        );
+    }
+
+    /// Removes the current shielded address commitment.
+    ///
+    /// The commitment stays in the shielded history with its validity window closed at
+    /// the current ledger timestamp.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no shielded address.
+    /// - `Unauthorized`: If the caller is neither the commitment owner nor an authorized delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `SHIELDED_REM` event with (username_hash, old_commitment).
+    pub fn remove_shielded_address(env: Env, caller: Address, username_hash: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Payments));
+        let old = storage::get_shielded_address(&env, &username_hash)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        Transfer::require_not_frozen(&env, &username_hash);
+        Self::close_shielded(&env, &username_hash);
+        env.storage()
+            .persistent()
+            .remove(&storage::DataKey::ShieldedAddress(username_hash.clone()));
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((shielded_removed_event(&env),), (username_hash, old));
+    }
+
+    /// Lists the last `MAX_SHIELDED_HISTORY` shielded address commitments, oldest first,
+    /// with their validity windows.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    ///
+    /// ### Returns
+    /// The records, the current one last with `valid_until == None`. Empty if the
+    /// shielded address is not public.
+    pub fn shielded_address_history(env: Env, username_hash: BytesN<32>) -> Vec<ShieldedRecord> {
+        if Privacy::policy(&env, &username_hash).shielded != Visibility::Public {
+            return Vec::new(&env);
+        }
+        storage::get_shielded_history(&env, &username_hash)
+    }
+
+    /// Returns whether `address_commitment` was the shielded address at `timestamp`.
+    ///
+    /// Validity windows include `valid_from` and exclude `valid_until`. Commitments older
+    /// than the retained history report `false`.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `address_commitment`: The shielded address commitment a sender paid to.
+    /// - `timestamp`: Ledger timestamp of the payment.
+    ///
+    /// ### Returns
+    /// `true` if the commitment was current at `timestamp` and the shielded address is public.
+    pub fn was_shielded_address_valid(
+        env: Env,
+        username_hash: BytesN<32>,
+        address_commitment: BytesN<32>,
+        timestamp: u64,
+    ) -> bool {
+        Self::shielded_address_history(env, username_hash)
+            .iter()
+            .any(|record| {
+                record.address_commitment == address_commitment
+                    && record.valid_from <= timestamp
+                    && record.valid_until.is_none_or(|until| timestamp < until)
+            })
+    }
+
     /// Retrieves the shielded address commitment for a commitment, if set.
     ///
     /// Returns the stored privacy commitment for the given username, or None if not set.
@@ -356,8 +928,11 @@ impl AddressManager {
     ///
     /// ### Returns
     /// - `Some(BytesN<32>)` if a shielded address commitment exists.
-    /// - `None` if no shielded address has been set.
+    /// - `None` if no shielded address has been set, or it is not public.
     pub fn get_shielded_address(env: Env, username_hash: BytesN<32>) -> Option<BytesN<32>> {
+        if Privacy::policy(&env, &username_hash).shielded != Visibility::Public {
+            return None;
+        }
         storage::get_shielded_address(&env, &username_hash)
     }
 
@@ -371,9 +946,270 @@ impl AddressManager {
     /// - `username_hash`: The 32-byte username commitment.
     ///
     /// ### Returns
-    /// `true` if a shielded address is set, `false` otherwise.
+    /// `true` if a shielded address is set and public, `false` otherwise.
     pub fn is_shielded(env: Env, username_hash: BytesN<32>) -> bool {
-        storage::has_shielded_address(&env, &username_hash)
+        Privacy::policy(&env, &username_hash).shielded == Visibility::Public
+            && storage::has_shielded_address(&env, &username_hash)
+    }
+
+    /// (Internal) Makes `address_commitment` the current shielded address, closing the
+    /// previous one's validity window and dropping the oldest record beyond
+    /// `MAX_SHIELDED_HISTORY`.
+    fn store_shielded(env: &Env, username_hash: &BytesN<32>, address_commitment: &BytesN<32>) {
+        Self::close_shielded(env, username_hash);
+        let mut records = storage::get_shielded_history(env, username_hash);
+        if records.len() >= MAX_SHIELDED_HISTORY {
+            records.pop_front();
+        }
+        records.push_back(ShieldedRecord {
+            address_commitment: address_commitment.clone(),
+            valid_from: env.ledger().timestamp(),
+            valid_until: None,
+        });
+        storage::set_shielded_history(env, username_hash, &records);
+        storage::set_shielded_address(env, username_hash, address_commitment);
+    }
+
+    /// (Internal) Ends the validity window of the current shielded address, if any.
+    fn close_shielded(env: &Env, username_hash: &BytesN<32>) {
+        let mut records = storage::get_shielded_history(env, username_hash);
+        let Some(mut current) = records.last().filter(|r| r.valid_until.is_none()) else {
+            return;
+        };
+        current.valid_until = Some(env.ledger().timestamp());
+        records.set(records.len() - 1, current);
+        storage::set_shielded_history(env, username_hash, &records);
+    }
+
+    /// (Internal) Returns the linked Stellar addresses, ignoring the privacy policy.
+    pub(crate) fn stellar_addresses(env: &Env, username_hash: &BytesN<32>) -> Vec<Address> {
+        let mut addresses = Vec::new(env);
+        for entry in Self::stellar_entries(env, username_hash).iter() {
+            addresses.push_back(entry.address);
+        }
+        addresses
+    }
+
+    /// (Internal) Returns the custom resolver's wallet, falling back to the primary
+    /// linked Stellar address, before any privacy filtering.
+    fn stellar_record(env: &Env, username_hash: &BytesN<32>) -> Option<Address> {
+        CustomResolvers::resolve(env, username_hash)
+            .map(|(wallet, _)| wallet)
+            .or_else(|| {
+                env.storage().persistent().get::<storage::DataKey, Address>(
+                    &storage::DataKey::StellarAddress(username_hash.clone()),
+                )
+            })
+    }
+
+    /// (Internal) Returns whether `chain`'s addresses are public under the privacy policy.
+    fn is_chain_public(env: &Env, username_hash: &BytesN<32>, chain: &ChainType) -> bool {
+        let policy = Privacy::policy(env, username_hash);
+        Privacy::chain_visibility(&policy, chain) == Visibility::Public
+    }
+
+    /// (Internal) Removes every Stellar, chain and shielded address linked to `username_hash`,
+    /// the shielded address history, and the encrypted record with its grants.
+    ///
+    /// The link nonce is kept so old link signatures stay unusable.
+    pub(crate) fn clear_addresses(env: &Env, username_hash: &BytesN<32>) {
+        let persistent = env.storage().persistent();
+        persistent.remove(&storage::DataKey::StellarAddress(username_hash.clone()));
+        persistent.remove(&storage::DataKey::StellarAddresses(username_hash.clone()));
+        persistent.remove(&storage::DataKey::StellarAddressEntries(
+            username_hash.clone(),
+        ));
+        persistent.remove(&storage::DataKey::ShieldedAddress(username_hash.clone()));
+        storage::remove_shielded_history(env, username_hash);
+        persistent.remove(&storage::DataKey::StealthMeta(username_hash.clone()));
+        persistent.remove(&storage::DataKey::EncryptedRecord(username_hash.clone()));
+        persistent.remove(&storage::DataKey::CustomResolver(username_hash.clone()));
+        persistent.remove(&storage::DataKey::GatewaySigner(username_hash.clone()));
+        PayerMemos::clear(env, username_hash);
+        for chain in [
+            ChainType::Evm,
+            ChainType::Bitcoin,
+            ChainType::Solana,
+            ChainType::Cosmos,
+        ] {
+            persistent.remove(&ChainAddrKey::ChainAddress(
+                username_hash.clone(),
+                chain.clone(),
+            ));
+            persistent.remove(&ChainAddrKey::ChainAddresses(username_hash.clone(), chain));
+        }
+    }
+
+    /// (Internal) Requires `caller` auth and that it is the owner of `username_hash` or an
+    /// `Addresses` delegate, using chain-address errors.
+    fn require_chain_manager(env: &Env, caller: &Address, username_hash: &BytesN<32>) {
+        caller.require_auth();
+
+        let owner_key = CommitmentKey::Commitment(username_hash.clone());
+        let owner: Address = env
+            .storage()
+            .persistent()
+            .get(&owner_key)
+            .unwrap_or_else(|| panic_with_error!(env, ChainAddressError::NotRegistered));
+
+        if !Delegates::is_authorized(env, &owner, caller, username_hash, DelegateRole::Addresses) {
+            panic_with_error!(env, ChainAddressError::Unauthorized);
+        }
+    }
+
+    /// (Internal) Label assigned to addresses added without explicit metadata.
+    fn default_metadata(env: &Env) -> AddressMetadata {
+        AddressMetadata {
+            label: Symbol::new(env, ""),
+        }
+    }
+
+    /// (Internal) Returns the slice `[cursor, cursor + limit)` of `items`, with `limit` capped.
+    pub(crate) fn page<T>(env: &Env, items: &Vec<T>, cursor: u32, limit: u32) -> Vec<T>
+    where
+        T: soroban_sdk::IntoVal<Env, soroban_sdk::Val>
+            + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+    {
+        let start = cursor.min(items.len());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(items.len());
+        if start == end {
+            return Vec::new(env);
+        }
+        items.slice(start..end)
+    }
+
+    /// (Internal) Loads the linked addresses for a chain.
+    ///
+    /// A primary address written before the list existed is returned as a single
+    /// unverified record, and is persisted as such by the next write to the list.
+    fn chain_records(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        chain: &ChainType,
+    ) -> Vec<ChainAddressRecord> {
+        let persistent = env.storage().persistent();
+        if let Some(records) = persistent.get(&ChainAddrKey::ChainAddresses(
+            username_hash.clone(),
+            chain.clone(),
+        )) {
+            return records;
+        }
+        let mut records = Vec::new(env);
+        if let Some(address) = persistent.get::<_, Bytes>(&ChainAddrKey::ChainAddress(
+            username_hash.clone(),
+            chain.clone(),
+        )) {
+            records.push_back(ChainAddressRecord {
+                address,
+                verified: false,
+                metadata: Self::default_metadata(env),
+            });
+        }
+        records
+    }
+
+    /// (Internal) Returns the index of `address` in `records`, if present.
+    fn find_chain_record(records: &Vec<ChainAddressRecord>, address: &Bytes) -> Option<u32> {
+        records
+            .iter()
+            .position(|record| record.address == *address)
+            .map(|i| i as u32)
+    }
+
+    /// (Internal) Writes the linked addresses for a chain and bumps their TTL.
+    fn store_chain_records(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        chain: &ChainType,
+        records: &Vec<ChainAddressRecord>,
+    ) {
+        let key = ChainAddrKey::ChainAddresses(username_hash.clone(), chain.clone());
+        env.storage().persistent().set(&key, records);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// (Internal) Writes the primary address for a chain and bumps its TTL.
+    fn store_chain_primary(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        chain: &ChainType,
+        address: &Bytes,
+    ) {
+        let key = ChainAddrKey::ChainAddress(username_hash.clone(), chain.clone());
+        env.storage().persistent().set(&key, address);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// (Internal) Loads the linked Stellar addresses with their labels.
+    ///
+    /// Falls back to the unlabeled list written before labels existed, giving each
+    /// address the default label.
+    fn stellar_entries(env: &Env, username_hash: &BytesN<32>) -> Vec<StellarAddressEntry> {
+        let persistent = env.storage().persistent();
+        if let Some(entries) = persistent.get(&storage::DataKey::StellarAddressEntries(
+            username_hash.clone(),
+        )) {
+            return entries;
+        }
+        let mut entries = Vec::new(env);
+        let legacy: Vec<Address> = persistent
+            .get(&storage::DataKey::StellarAddresses(username_hash.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        for address in legacy.iter() {
+            entries.push_back(StellarAddressEntry {
+                address,
+                metadata: Self::default_metadata(env),
+            });
+        }
+        entries
+    }
+
+    /// (Internal) Returns the index of `address` in `entries`, if present.
+    fn find_stellar_entry(entries: &Vec<StellarAddressEntry>, address: &Address) -> Option<u32> {
+        entries
+            .iter()
+            .position(|entry| entry.address == *address)
+            .map(|i| i as u32)
+    }
+
+    /// (Internal) Writes the linked Stellar addresses and bumps their TTL.
+    fn store_stellar_entries(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        entries: &Vec<StellarAddressEntry>,
+    ) {
+        env.storage()
+            .persistent()
+            .remove(&storage::DataKey::StellarAddresses(username_hash.clone()));
+        let key = storage::DataKey::StellarAddressEntries(username_hash.clone());
+        env.storage().persistent().set(&key, entries);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// (Internal) Writes the primary Stellar address and bumps its TTL.
+    fn store_stellar_primary(env: &Env, username_hash: &BytesN<32>, address: &Address) {
+        let key = storage::DataKey::StellarAddress(username_hash.clone());
+        env.storage().persistent().set(&key, address);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        History::record(env, username_hash, HistoryField::StellarAddress);
     }
 
     /// (Internal) Validates a blockchain address format for a given chain.
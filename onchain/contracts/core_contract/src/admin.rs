@@ -1,7 +1,7 @@
 use soroban_sdk::{panic_with_error, Address, BytesN, Env};
 
 use crate::errors::CoreError;
-use crate::events::INIT_EVENT;
+use crate::events::{cooldown_set_event, direct_transfers_set_event, INIT_EVENT};
 use crate::{smt_root, storage};
 
 pub struct Admin;
@@ -89,4 +89,67 @@ impl Admin {
 
         smt_root::SmtRoot::update_root(&env, new_root);
     }
+
+    /// Enables or disables direct (single-step) ownership transfers.
+    ///
+    /// When disabled, `transfer_ownership` is rejected and owners must use the
+    /// two-step `propose_transfer` / `accept_transfer` flow. Enabled by default.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `enabled`: Whether `transfer_ownership` is allowed.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract owner has not been initialized.
+    ///
+    /// ### Events
+    /// - Emits `DIRECT_XFER_SET` event with `enabled`.
+    pub fn set_direct_transfers_enabled(env: Env, enabled: bool) {
+        let owner = storage::get_owner(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        owner.require_auth();
+
+        storage::set_direct_transfers_enabled(&env, enabled);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((direct_transfers_set_event(&env),), enabled);
+    }
+
+    /// Returns whether direct (single-step) ownership transfers are enabled.
+    pub fn direct_transfers_enabled(env: Env) -> bool {
+        storage::direct_transfers_enabled(&env)
+    }
+
+    /// Sets the cooldown applied after every ownership change.
+    ///
+    /// For `seconds` after a username changes hands, its payment-routing records cannot
+    /// be changed and it cannot be transferred again, so a stolen key cannot move a name
+    /// and repoint its payments in one go. Zero disables the cooldown, which is the
+    /// default. Only affects ownership changes made after the call.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `seconds`: Length of the freeze following an ownership change.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract owner has not been initialized.
+    ///
+    /// ### Events
+    /// - Emits `COOLDOWN_SET` event with `seconds`.
+    pub fn set_transfer_cooldown(env: Env, seconds: u64) {
+        let owner = storage::get_owner(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        owner.require_auth();
+
+        storage::set_transfer_cooldown(&env, seconds);
+
+        #[allow(deprecated)]
+        env.events().publish((cooldown_set_event(&env),), seconds);
+    }
+
+    /// Returns the cooldown, in seconds, applied after every ownership change.
+    pub fn transfer_cooldown(env: Env) -> u64 {
+        storage::get_transfer_cooldown(&env)
+    }
 }
@@ -14,10 +14,6 @@ pub fn owner_key(env: &Env) -> Symbol {
     Symbol::new(env, "Owner")
 }
 
-pub fn username_key(env: &Env) -> Symbol {
-    Symbol::new(env, "Username")
-}
-
 pub fn created_at_key(env: &Env, _username_hash: &BytesN<32>) -> Symbol {
     Symbol::new(env, "CreatedAt")
 }
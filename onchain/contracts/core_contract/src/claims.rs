@@ -0,0 +1,151 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::CoreError;
+use crate::events::{claim_round_event, claimed_event, REGISTER_EVENT};
+use crate::registration::Registration;
+use crate::storage::{self, MAX_CLAIM_PROOF_DEPTH};
+use crate::types::ClaimRound;
+
+pub struct Claims;
+
+impl Claims {
+    /// Publishes a new allocation round of pre-assigned usernames.
+    ///
+    /// `root` is the Merkle root of `sha256(commitment || claimant XDR)` leaves, hashed
+    /// pairwise with `sha256(min(a, b) || max(a, b))`. Each round is independent, so
+    /// partners can be onboarded in batches.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `root`: The 32-byte Merkle root of the round's allocations.
+    /// - `deadline`: Ledger timestamp after which the round can no longer be claimed.
+    ///
+    /// ### Returns
+    /// The id of the new round, starting at 0.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract owner has not been initialized.
+    /// - `InvalidExpiry`: If `deadline` is not in the future.
+    ///
+    /// ### Events
+    /// - Emits `CLAIM_ROUND` event with (round_id, root, deadline).
+    pub fn create_claim_round(env: Env, root: BytesN<32>, deadline: u64) -> u32 {
+        let owner = storage::get_owner(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        owner.require_auth();
+        if deadline <= env.ledger().timestamp() {
+            panic_with_error!(&env, CoreError::InvalidExpiry);
+        }
+
+        let round_id = storage::get_claim_round_count(&env);
+        storage::set_claim_round(
+            &env,
+            round_id,
+            &ClaimRound {
+                root: root.clone(),
+                deadline,
+            },
+        );
+        storage::set_claim_round_count(&env, round_id + 1);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((claim_round_event(&env),), (round_id, root, deadline));
+        round_id
+    }
+
+    /// Registers a pre-allocated username to its claimant.
+    ///
+    /// Bypasses fees, auctions and reservations: the allocation itself was published
+    /// by the contract owner. Each commitment can be claimed once per round.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `claimant`: The allocated owner. Must be authorized.
+    /// - `round_id`: The allocation round containing the leaf.
+    /// - `commitment`: The 32-byte username commitment being claimed.
+    /// - `proof`: Sibling hashes from the leaf up to the round root.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the round does not exist.
+    /// - `ClaimExpired`: If the round deadline has passed.
+    /// - `AlreadyClaimed`: If the commitment was already claimed in this round.
+    /// - `AlreadyRegistered`: If the commitment is already registered.
+    /// - `InvalidProof`: If the proof does not lead to the round root.
+    ///
+    /// ### Events
+    /// - Emits `CLAIMED` event with (round_id, commitment, claimant).
+    /// - Emits `REGISTER_EVENT` with (commitment, claimant).
+    pub fn claim_allocated(
+        env: Env,
+        claimant: Address,
+        round_id: u32,
+        commitment: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) {
+        claimant.require_auth();
+        let round = storage::get_claim_round(&env, round_id)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if env.ledger().timestamp() >= round.deadline {
+            panic_with_error!(&env, CoreError::ClaimExpired);
+        }
+        if storage::is_claimed(&env, round_id, &commitment) {
+            panic_with_error!(&env, CoreError::AlreadyClaimed);
+        }
+        if Registration::get_owner(env.clone(), commitment.clone()).is_some() {
+            panic_with_error!(&env, CoreError::AlreadyRegistered);
+        }
+        if proof.len() > MAX_CLAIM_PROOF_DEPTH
+            || Self::compute_root(&env, Self::leaf(&env, &commitment, &claimant), &proof)
+                != round.root
+        {
+            panic_with_error!(&env, CoreError::InvalidProof);
+        }
+
+        storage::set_claimed(&env, round_id, &commitment);
+        Registration::record_owner(&env, &commitment, &claimant);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (claimed_event(&env),),
+            (round_id, commitment.clone(), claimant.clone()),
+        );
+        #[allow(deprecated)]
+        env.events()
+            .publish((REGISTER_EVENT,), (commitment, claimant));
+    }
+
+    /// Returns an allocation round, if it exists.
+    pub fn get_claim_round(env: Env, round_id: u32) -> Option<ClaimRound> {
+        storage::get_claim_round(&env, round_id)
+    }
+
+    /// Returns whether a commitment has been claimed in a round.
+    pub fn is_claimed(env: Env, round_id: u32, commitment: BytesN<32>) -> bool {
+        storage::is_claimed(&env, round_id, &commitment)
+    }
+
+    /// (Internal) Allocation leaf: `sha256(commitment || claimant XDR)`.
+    fn leaf(env: &Env, commitment: &BytesN<32>, claimant: &Address) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(env, &commitment.to_array());
+        preimage.append(&claimant.clone().to_xdr(env));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// (Internal) Folds `proof` into `leaf` using sorted-pair sha256 hashing.
+    fn compute_root(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut node = leaf;
+        for sibling in proof.iter() {
+            let (left, right) = if node.to_array() <= sibling.to_array() {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            let mut pair = Bytes::from_array(env, &left.to_array());
+            pair.append(&Bytes::from_array(env, &right.to_array()));
+            node = env.crypto().sha256(&pair).into();
+        }
+        node
+    }
+}
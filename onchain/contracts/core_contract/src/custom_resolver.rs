@@ -0,0 +1,158 @@
+use soroban_sdk::{contractclient, panic_with_error, Address, Bytes, BytesN, Env, String};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{custom_resolver_removed_event, custom_resolver_set_event};
+use crate::privacy::Privacy;
+use crate::storage::{self, MAX_RESOLVER_CALLS};
+use crate::transfer::Transfer;
+use crate::types::{ChainType, DelegateRole, Visibility};
+
+/// The interface an external resolver contract implements.
+///
+/// Every method returns `None` to let core fall back to its built-in records.
+#[allow(dead_code)]
+#[contractclient(name = "CustomResolverClient")]
+pub trait CustomResolverInterface {
+    /// Returns the wallet and optional memo a commitment currently resolves to.
+    fn resolve(env: Env, commitment: BytesN<32>) -> Option<(Address, Option<u64>)>;
+
+    /// Returns the commitment's current address on `chain`.
+    fn resolve_chain(env: Env, commitment: BytesN<32>, chain: ChainType) -> Option<Bytes>;
+
+    /// Returns the text record stored under `key`.
+    fn text(env: Env, commitment: BytesN<32>, key: String) -> Option<String>;
+}
+
+pub struct CustomResolvers;
+
+impl CustomResolvers {
+    /// Points a commitment at an external resolver contract.
+    ///
+    /// `resolve`, `get_chain_address` and `text` ask the resolver first and fall back to
+    /// the built-in records when it returns `None` or fails. The privacy policy still
+    /// applies to whatever the resolver returns. Verified chain addresses are never
+    /// delegated.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `resolver`: A contract implementing [`CustomResolverInterface`].
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `CUSTOM_RES_SET` event with (commitment, resolver).
+    pub fn set_custom_resolver(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        resolver: Address,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::set_custom_resolver(&env, &commitment, &resolver);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((custom_resolver_set_event(&env),), (commitment, resolver));
+    }
+
+    /// Stops delegating resolution for a commitment.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no custom resolver.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `CUSTOM_RES_REM` event with (commitment,).
+    pub fn remove_custom_resolver(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        if storage::get_custom_resolver(&env, &commitment).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::remove_custom_resolver(&env, &commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((custom_resolver_removed_event(&env),), (commitment,));
+    }
+
+    /// Returns the custom resolver of a commitment, if any.
+    pub fn get_custom_resolver(env: Env, commitment: BytesN<32>) -> Option<Address> {
+        storage::get_custom_resolver(&env, &commitment)
+    }
+
+    /// Returns a text record from the commitment's custom resolver.
+    ///
+    /// Core stores no text records, so this is `None` without a custom resolver.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `key`: The record key, such as `url` or `avatar`.
+    ///
+    /// ### Returns
+    /// The record, or `None` if unset, the resolver fails, or text records are not public.
+    pub fn text(env: Env, commitment: BytesN<32>, key: String) -> Option<String> {
+        if Privacy::policy(&env, &commitment).text != Visibility::Public {
+            return None;
+        }
+        let client = Self::client(&env, &commitment)?;
+        client.try_text(&commitment, &key).ok()?.ok()?
+    }
+
+    /// (Internal) Asks the custom resolver for the wallet and memo, if one is set.
+    ///
+    /// Returns `None` when the resolver is unset, returns `None` or fails, so callers
+    /// fall back to the built-in record.
+    pub(crate) fn resolve(env: &Env, commitment: &BytesN<32>) -> Option<(Address, Option<u64>)> {
+        Self::client(env, commitment)?
+            .try_resolve(commitment)
+            .ok()?
+            .ok()?
+    }
+
+    /// (Internal) Counts a batch entry that will call its custom resolver, so one batch
+    /// makes at most `MAX_RESOLVER_CALLS` external calls.
+    ///
+    /// Panics with `BatchTooLarge` once the batch goes over the limit.
+    pub(crate) fn count_call(env: &Env, commitment: &BytesN<32>, calls: &mut u32) {
+        if storage::get_custom_resolver(env, commitment).is_none() {
+            return;
+        }
+        *calls += 1;
+        if *calls > MAX_RESOLVER_CALLS {
+            panic_with_error!(env, CoreError::BatchTooLarge);
+        }
+    }
+
+    /// (Internal) Asks the custom resolver for a chain address, if one is set.
+    pub(crate) fn resolve_chain(
+        env: &Env,
+        commitment: &BytesN<32>,
+        chain: &ChainType,
+    ) -> Option<Bytes> {
+        Self::client(env, commitment)?
+            .try_resolve_chain(commitment, chain)
+            .ok()?
+            .ok()?
+    }
+
+    /// (Internal) Builds a client for the commitment's custom resolver.
+    fn client<'a>(env: &'a Env, commitment: &BytesN<32>) -> Option<CustomResolverClient<'a>> {
+        storage::get_custom_resolver(env, commitment)
+            .map(|resolver| CustomResolverClient::new(env, &resolver))
+    }
+}
@@ -0,0 +1,216 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+
+use crate::errors::CoreError;
+use crate::events::{delegate_grant_event, delegate_revoke_event};
+use crate::registration::Registration;
+use crate::storage::{self, MAX_DELEGATES};
+use crate::types::{DelegateGrant, DelegateRole};
+
+pub struct Delegates;
+
+impl Delegates {
+    /// Grants a delegate role on a commitment.
+    ///
+    /// Lets the owner authorize another address to perform a class of mutations
+    /// without sharing the owner key. Re-granting an existing (delegate, role) pair
+    /// replaces its expiry. Expired grants are pruned on every grant.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `delegate`: The address receiving the role.
+    /// - `role`: The class of mutations the delegate may perform.
+    /// - `expires_at`: Optional ledger timestamp after which the grant is inactive.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `InvalidExpiry`: If `expires_at` is not in the future.
+    /// - `DelegateLimitReached`: If the commitment already has the maximum number of grants.
+    ///
+    /// ### Events
+    /// - Emits `DELEGATE_GRANT` event with (username_hash, delegate, role, expires_at).
+    pub fn grant_delegate(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        delegate: Address,
+        role: DelegateRole,
+        expires_at: Option<u64>,
+    ) {
+        Self::require_authorized(&env, &caller, &username_hash, None);
+        Self::grant(&env, &username_hash, delegate, role, expires_at);
+    }
+
+    /// Revokes a delegate role on a commitment.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `username_hash`: The 32-byte username commitment.
+    /// - `delegate`: The address losing the role.
+    /// - `role`: The role to revoke.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or no such grant exists.
+    /// - `Unauthorized`: If the caller is not the commitment owner.
+    ///
+    /// ### Events
+    /// - Emits `DELEGATE_REVOKE` event with (username_hash, delegate, role).
+    pub fn revoke_delegate(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        delegate: Address,
+        role: DelegateRole,
+    ) {
+        Self::require_authorized(&env, &caller, &username_hash, None);
+        Self::revoke(&env, &username_hash, delegate, role);
+    }
+
+    /// Returns the active (non-expired) delegate grants for a commitment.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `username_hash`: The 32-byte username commitment.
+    ///
+    /// ### Returns
+    /// The grants in the order they were made.
+    pub fn get_delegates(env: Env, username_hash: BytesN<32>) -> Vec<DelegateGrant> {
+        let now = env.ledger().timestamp();
+        let mut active = Vec::new(&env);
+        for grant in storage::get_delegates(&env, &username_hash).iter() {
+            if Self::is_active(&grant, now) {
+                active.push_back(grant);
+            }
+        }
+        active
+    }
+
+    /// Returns `true` if `caller` is `owner` or holds an active `role` grant on the commitment.
+    ///
+    /// Does not call `require_auth`; callers are expected to have done so already.
+    pub(crate) fn is_authorized(
+        env: &Env,
+        owner: &Address,
+        caller: &Address,
+        username_hash: &BytesN<32>,
+        role: DelegateRole,
+    ) -> bool {
+        if caller == owner {
+            return true;
+        }
+        let now = env.ledger().timestamp();
+        storage::get_delegates(env, username_hash)
+            .iter()
+            .any(|g| g.delegate == *caller && g.role == role && Self::is_active(&g, now))
+    }
+
+    /// Requires `caller` auth and that it owns `username_hash`, or holds an active `role`
+    /// grant on it when `role` is given.
+    ///
+    /// The single owner check behind every owner- or delegate-gated entrypoint.
+    ///
+    /// ### Returns
+    /// The current owner.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `role` delegate.
+    pub(crate) fn require_authorized(
+        env: &Env,
+        caller: &Address,
+        username_hash: &BytesN<32>,
+        role: Option<DelegateRole>,
+    ) -> Address {
+        caller.require_auth();
+        let owner = Registration::get_owner(env.clone(), username_hash.clone())
+            .unwrap_or_else(|| panic_with_error!(env, CoreError::NotFound));
+        let authorized = match role {
+            Some(role) => Self::is_authorized(env, &owner, caller, username_hash, role),
+            None => *caller == owner,
+        };
+        if !authorized {
+            panic_with_error!(env, CoreError::Unauthorized);
+        }
+        owner
+    }
+
+    /// (Internal) Records a grant once the caller has been authorized as the owner.
+    ///
+    /// Shared by [`Self::grant_delegate`] and group-owned commitments.
+    pub(crate) fn grant(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        delegate: Address,
+        role: DelegateRole,
+        expires_at: Option<u64>,
+    ) {
+        let now = env.ledger().timestamp();
+        if expires_at.is_some_and(|t| t <= now) {
+            panic_with_error!(env, CoreError::InvalidExpiry);
+        }
+
+        let mut grants = Vec::new(env);
+        for grant in storage::get_delegates(env, username_hash).iter() {
+            let replaced = grant.delegate == delegate && grant.role == role;
+            if !replaced && Self::is_active(&grant, now) {
+                grants.push_back(grant);
+            }
+        }
+        if grants.len() >= MAX_DELEGATES {
+            panic_with_error!(env, CoreError::DelegateLimitReached);
+        }
+        grants.push_back(DelegateGrant {
+            delegate: delegate.clone(),
+            role: role.clone(),
+            expires_at,
+        });
+        storage::set_delegates(env, username_hash, &grants);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (delegate_grant_event(env),),
+            (username_hash.clone(), delegate, role, expires_at),
+        );
+    }
+
+    /// (Internal) Removes a grant once the caller has been authorized as the owner.
+    pub(crate) fn revoke(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        delegate: Address,
+        role: DelegateRole,
+    ) {
+        let existing = storage::get_delegates(env, username_hash);
+        let mut grants = Vec::new(env);
+        for grant in existing.iter() {
+            if !(grant.delegate == delegate && grant.role == role) {
+                grants.push_back(grant);
+            }
+        }
+        if grants.len() == existing.len() {
+            panic_with_error!(env, CoreError::NotFound);
+        }
+        storage::set_delegates(env, username_hash, &grants);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (delegate_revoke_event(env),),
+            (username_hash.clone(), delegate, role),
+        );
+    }
+
+    /// (Internal) Drops every grant on a commitment.
+    pub(crate) fn clear(env: &Env, username_hash: &BytesN<32>) {
+        env.storage()
+            .persistent()
+            .remove(&storage::DataKey::Delegates(username_hash.clone()));
+    }
+
+    /// (Internal) Returns `true` while a grant has not yet expired.
+    fn is_active(grant: &DelegateGrant, now: u64) -> bool {
+        grant.expires_at.is_none_or(|t| now < t)
+    }
+}
@@ -0,0 +1,213 @@
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env, Vec};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{
+    encrypted_record_removed_event, encrypted_record_set_event, record_access_granted_event,
+    record_access_revoked_event,
+};
+use crate::storage::{self, MAX_RECORD_CIPHERTEXT, MAX_RECORD_ENVELOPE, MAX_RECORD_GRANTS};
+use crate::transfer::Transfer;
+use crate::types::{DelegateRole, EncryptedRecord, RecordGrant};
+
+pub struct EncryptedRecords;
+
+impl EncryptedRecords {
+    /// Stores address records encrypted to a set of viewing keys.
+    ///
+    /// The contract only holds opaque bytes: the owner encrypts the records off-chain
+    /// under a fresh content key and wraps that key once per viewer, so only payers
+    /// holding a granted viewing key learn the destinations. Replaces any existing record
+    /// and all of its grants, which is also how the content key is rotated after a
+    /// revocation.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `ciphertext`: The encrypted records, at most `MAX_RECORD_CIPHERTEXT` bytes.
+    /// - `grants`: One wrapped content key per viewer, at most `MAX_RECORD_GRANTS`.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `InvalidEncryptedRecord`: If the ciphertext or an envelope is empty or too large,
+    ///   a viewer is listed twice, or there are too many grants.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `ENC_RECORD_SET` event with (commitment, version).
+    pub fn set_encrypted_record(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        ciphertext: Bytes,
+        grants: Vec<RecordGrant>,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        if ciphertext.is_empty()
+            || ciphertext.len() > MAX_RECORD_CIPHERTEXT
+            || grants.len() > MAX_RECORD_GRANTS
+        {
+            panic_with_error!(&env, CoreError::InvalidEncryptedRecord);
+        }
+        for (i, grant) in grants.iter().enumerate() {
+            Self::validate_envelope(&env, &grant.envelope);
+            if grants.iter().position(|other| other.viewer == grant.viewer) != Some(i) {
+                panic_with_error!(&env, CoreError::InvalidEncryptedRecord);
+            }
+        }
+        Transfer::require_not_frozen(&env, &commitment);
+
+        let version =
+            storage::get_encrypted_record(&env, &commitment).map_or(1, |record| record.version + 1);
+        storage::set_encrypted_record(
+            &env,
+            &commitment,
+            &EncryptedRecord {
+                ciphertext,
+                version,
+                grants,
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((encrypted_record_set_event(&env),), (commitment, version));
+    }
+
+    /// Grants a viewing key access to the current encrypted record.
+    ///
+    /// `envelope` must wrap the record's current content key. Granting a viewer that
+    /// already has access replaces its envelope.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `grant`: The viewer's X25519 public key and wrapped content key.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no encrypted record.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `InvalidEncryptedRecord`: If the envelope is empty or too large, or the record
+    ///   already has `MAX_RECORD_GRANTS` viewers.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `RECORD_GRANTED` event with (commitment, viewer).
+    pub fn grant_record_access(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        grant: RecordGrant,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        let mut record = Self::require_record(&env, &commitment);
+        Self::validate_envelope(&env, &grant.envelope);
+        Transfer::require_not_frozen(&env, &commitment);
+
+        let viewer = grant.viewer.clone();
+        match record.grants.iter().position(|g| g.viewer == viewer) {
+            Some(i) => record.grants.set(i as u32, grant),
+            None if record.grants.len() >= MAX_RECORD_GRANTS => {
+                panic_with_error!(&env, CoreError::InvalidEncryptedRecord)
+            }
+            None => record.grants.push_back(grant),
+        }
+        storage::set_encrypted_record(&env, &commitment, &record);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((record_access_granted_event(&env),), (commitment, viewer));
+    }
+
+    /// Removes a viewing key's envelope from the encrypted record.
+    ///
+    /// A revoked viewer that kept the content key can still read the current ciphertext;
+    /// call [`Self::set_encrypted_record`] with a fresh content key to cut it off.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `viewer`: The X25519 public key losing access.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered, has no encrypted record, or the
+    ///   viewer has no grant.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `RECORD_REVOKED` event with (commitment, viewer).
+    pub fn revoke_record_access(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        viewer: BytesN<32>,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        let mut record = Self::require_record(&env, &commitment);
+        let index = record
+            .grants
+            .iter()
+            .position(|g| g.viewer == viewer)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        Transfer::require_not_frozen(&env, &commitment);
+
+        record.grants.remove(index as u32);
+        storage::set_encrypted_record(&env, &commitment, &record);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((record_access_revoked_event(&env),), (commitment, viewer));
+    }
+
+    /// Removes the encrypted record and all of its grants.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no encrypted record.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `ENC_RECORD_REM` event with (commitment,).
+    pub fn remove_encrypted_record(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        Self::require_record(&env, &commitment);
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::remove_encrypted_record(&env, &commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((encrypted_record_removed_event(&env),), (commitment,));
+    }
+
+    /// Returns the encrypted record of a commitment, if any.
+    ///
+    /// Anyone can read the ciphertext and envelopes; only holders of a granted viewing
+    /// key can decrypt them.
+    pub fn get_encrypted_record(env: Env, commitment: BytesN<32>) -> Option<EncryptedRecord> {
+        storage::get_encrypted_record(&env, &commitment)
+    }
+
+    /// (Internal) Returns the encrypted record, panicking with `NotFound` if unset.
+    fn require_record(env: &Env, commitment: &BytesN<32>) -> EncryptedRecord {
+        storage::get_encrypted_record(env, commitment)
+            .unwrap_or_else(|| panic_with_error!(env, CoreError::NotFound))
+    }
+
+    /// (Internal) Panics with `InvalidEncryptedRecord` unless the envelope is non-empty
+    /// and at most `MAX_RECORD_ENVELOPE` bytes.
+    fn validate_envelope(env: &Env, envelope: &Bytes) {
+        if envelope.is_empty() || envelope.len() > MAX_RECORD_ENVELOPE {
+            panic_with_error!(env, CoreError::InvalidEncryptedRecord);
+        }
+    }
+}
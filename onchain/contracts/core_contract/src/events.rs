@@ -15,6 +15,7 @@ pub const VAULT_CREATE: Symbol = symbol_short!("VAULT_CRT");
 pub const DEPOSIT: Symbol = symbol_short!("DEPOSIT");
 pub const WITHDRAW: Symbol = symbol_short!("WITHDRAW");
 pub const SCHED_PAY: Symbol = symbol_short!("SCHED_PAY");
+pub const BURN_EVENT: Symbol = symbol_short!("BURN");
 
 pub fn privacy_set_event(env: &Env) -> Symbol {
     Symbol::new(env, "PRIVACY_SET")
@@ -24,6 +25,14 @@ pub fn shielded_add_event(env: &Env) -> Symbol {
     Symbol::new(env, "SHIELDED_ADD")
 }
 
+pub fn shielded_rotated_event(env: &Env) -> Symbol {
+    Symbol::new(env, "SHIELDED_ROTATE")
+}
+
+pub fn shielded_removed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "SHIELDED_REM")
+}
+
 pub fn username_registered_event(env: &Env) -> Symbol {
     Symbol::new(env, "UsernameRegistered")
 }
@@ -31,3 +40,169 @@ pub fn username_registered_event(env: &Env) -> Symbol {
 pub fn stellar_rem_event(env: &Env) -> Symbol {
     Symbol::new(env, "Strellar_Rem_Events")
 }
+
+pub fn chain_verified_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CHAIN_VERIFIED")
+}
+
+pub fn stellar_primary_event(env: &Env) -> Symbol {
+    Symbol::new(env, "STELLAR_PRIMARY")
+}
+
+pub fn chain_primary_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CHAIN_PRIMARY")
+}
+
+pub fn chain_entry_rem_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CHAIN_ENTRY_REM")
+}
+
+pub fn addr_label_event(env: &Env) -> Symbol {
+    Symbol::new(env, "ADDR_LABEL")
+}
+
+pub fn delegate_grant_event(env: &Env) -> Symbol {
+    Symbol::new(env, "DELEGATE_GRANT")
+}
+
+pub fn delegate_revoke_event(env: &Env) -> Symbol {
+    Symbol::new(env, "DELEGATE_REVOKE")
+}
+
+pub fn transfer_proposed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "TRANSFER_PROPOSED")
+}
+
+pub fn transfer_accepted_event(env: &Env) -> Symbol {
+    Symbol::new(env, "TRANSFER_ACCEPTED")
+}
+
+pub fn transfer_cancelled_event(env: &Env) -> Symbol {
+    Symbol::new(env, "TRANSFER_CANCELLED")
+}
+
+pub fn direct_transfers_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "DIRECT_XFER_SET")
+}
+
+pub fn records_reset_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECORDS_RESET")
+}
+
+pub fn guardians_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "GUARDIANS_SET")
+}
+
+pub fn recovery_approved_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECOVERY_APPROVED")
+}
+
+pub fn recovery_executed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECOVERY_EXECUTED")
+}
+
+pub fn recovery_vetoed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECOVERY_VETOED")
+}
+
+pub fn reserved_add_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RESERVED_ADD")
+}
+
+pub fn reserved_rem_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RESERVED_REM")
+}
+
+pub fn claim_round_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CLAIM_ROUND")
+}
+
+pub fn claimed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CLAIMED")
+}
+
+pub fn transfers_locked_event(env: &Env) -> Symbol {
+    Symbol::new(env, "TRANSFERS_LOCKED")
+}
+
+pub fn cooldown_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "COOLDOWN_SET")
+}
+
+pub fn multisig_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "MULTISIG_SET")
+}
+
+pub fn multisig_proposed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "MULTISIG_PROPOSED")
+}
+
+pub fn multisig_approved_event(env: &Env) -> Symbol {
+    Symbol::new(env, "MULTISIG_APPROVED")
+}
+
+pub fn multisig_executed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "MULTISIG_EXECUTED")
+}
+
+pub fn stealth_meta_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "STEALTH_META_SET")
+}
+
+pub fn stealth_meta_removed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "STEALTH_META_REM")
+}
+
+pub const ANNOUNCE_EVENT: Symbol = symbol_short!("ANNOUNCE");
+
+pub fn encrypted_record_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "ENC_RECORD_SET")
+}
+
+pub fn encrypted_record_removed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "ENC_RECORD_REM")
+}
+
+pub fn record_access_granted_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECORD_GRANTED")
+}
+
+pub fn record_access_revoked_event(env: &Env) -> Symbol {
+    Symbol::new(env, "RECORD_REVOKED")
+}
+
+pub fn privacy_policy_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "PRIVACY_POLICY")
+}
+
+pub fn username_published_event(env: &Env) -> Symbol {
+    Symbol::new(env, "USERNAME_PUB")
+}
+
+pub fn username_unpublished_event(env: &Env) -> Symbol {
+    Symbol::new(env, "USERNAME_UNPUB")
+}
+
+pub fn custom_resolver_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CUSTOM_RES_SET")
+}
+
+pub fn custom_resolver_removed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "CUSTOM_RES_REM")
+}
+
+pub fn gateway_signer_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "GATEWAY_SET")
+}
+
+pub fn gateway_signer_removed_event(env: &Env) -> Symbol {
+    Symbol::new(env, "GATEWAY_REM")
+}
+
+pub fn payer_memo_commitment_set_event(env: &Env) -> Symbol {
+    Symbol::new(env, "PAYER_COMMIT")
+}
+
+pub fn payer_memo_assigned_event(env: &Env) -> Symbol {
+    Symbol::new(env, "PAYER_MEMO")
+}
@@ -0,0 +1,100 @@
+use soroban_sdk::{BytesN, Env, Vec};
+
+use crate::address_manager::AddressManager;
+use crate::privacy::Privacy;
+use crate::registration::Registration;
+use crate::storage::{self, MAX_HISTORY};
+use crate::types::{HistoryEntry, HistoryField, ResolveData, Visibility};
+
+pub struct History;
+
+impl History {
+    /// Lists a commitment's resolution change history, oldest first, paginated.
+    ///
+    /// Only the latest `MAX_HISTORY` changes are kept. Fields the privacy policy does not
+    /// make public are blanked.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `cursor`: Index of the first entry to return.
+    /// - `limit`: Maximum number of entries to return (capped at `MAX_PAGE_SIZE`).
+    pub fn history(env: Env, commitment: BytesN<32>, cursor: u32, limit: u32) -> Vec<HistoryEntry> {
+        let entries = storage::get_history(&env, &commitment);
+        let mut page = Vec::new(&env);
+        for entry in AddressManager::page(&env, &entries, cursor, limit).iter() {
+            page.push_back(Self::redact(&env, &commitment, entry));
+        }
+        page
+    }
+
+    /// Returns the resolution state of a commitment as it was at `timestamp`.
+    ///
+    /// This is the latest history entry recorded at or before `timestamp`. Returns `None`
+    /// if `timestamp` predates the oldest retained entry, since older state is unknown.
+    /// Fields the privacy policy does not make public are blanked.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `timestamp`: Ledger timestamp to resolve at.
+    pub fn resolve_at(env: Env, commitment: BytesN<32>, timestamp: u64) -> Option<HistoryEntry> {
+        let entries = storage::get_history(&env, &commitment);
+        let mut found = None;
+        for entry in entries.iter() {
+            if entry.timestamp > timestamp {
+                break;
+            }
+            found = Some(entry);
+        }
+        found.map(|entry| Self::redact(&env, &commitment, entry))
+    }
+
+    /// (Internal) Blanks the fields of `entry` that the current privacy policy does not make
+    /// public. The owner is kept unless it was taken from a hidden resolver wallet.
+    fn redact(env: &Env, commitment: &BytesN<32>, mut entry: HistoryEntry) -> HistoryEntry {
+        let policy = Privacy::policy(env, commitment);
+        if policy.stellar != Visibility::Public {
+            entry.stellar_address = None;
+        }
+        if policy.memo != Visibility::Public {
+            entry.memo = None;
+        }
+        if policy.primary != Visibility::Public
+            && Registration::get_owner(env.clone(), commitment.clone()).is_none()
+        {
+            entry.owner = None;
+        }
+        entry
+    }
+
+    /// (Internal) Appends a snapshot of the commitment's current resolution state,
+    /// dropping the oldest entry once `MAX_HISTORY` is reached.
+    ///
+    /// Call after the change has been written.
+    pub(crate) fn record(env: &Env, commitment: &BytesN<32>, changed: HistoryField) {
+        let resolver = env
+            .storage()
+            .persistent()
+            .get::<storage::DataKey, ResolveData>(&storage::DataKey::Resolver(commitment.clone()));
+        let entry = HistoryEntry {
+            timestamp: env.ledger().timestamp(),
+            changed,
+            owner: Registration::get_owner(env.clone(), commitment.clone())
+                .or_else(|| resolver.clone().map(|data| data.wallet)),
+            stellar_address: env
+                .storage()
+                .persistent()
+                .get(&storage::DataKey::StellarAddress(commitment.clone())),
+            memo: resolver.and_then(|data| data.memo),
+            privacy_mode: storage::get_privacy_mode(env, commitment),
+        };
+
+        let mut entries = storage::get_history(env, commitment);
+        if entries.len() >= MAX_HISTORY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+        storage::set_history(env, commitment, &entries);
+    }
+}
@@ -19,6 +19,8 @@
 //! - A cryptographic hash representing a username (or identity).
 //! - Acts as the **primary identifier** in the system.
 //! - Designed to preserve privacy by avoiding plaintext usernames on-chain.
+//! - Owners may opt in to publishing the plaintext username; the contract checks its
+//!   Poseidon hash against the commitment before storing it.
 //!
 //! ### 2. Username Ownership
 //! - A commitment is **owned by an `Address`**.
@@ -36,10 +38,33 @@
 //!   - Optional memo
 //!   - Multiple chain-specific addresses
 //!   - Multiple Stellar addresses
-//!   - Optional shielded (privacy-preserving) address
+//!   - Optional shielded (privacy-preserving) address, rotatable with a bounded
+//!     history of validity windows
+//!   - Optional stealth meta-address (spending and viewing keys)
+//!   - Optional encrypted records readable only by granted viewing keys
+//! - An owner may point the commitment at an external resolver contract implementing
+//!   `CustomResolverInterface` (`resolve`, `resolve_chain`, `text`); core asks it first
+//!   and falls back to the built-in records.
+//! - Records too numerous to store on-chain can be served by an off-chain gateway whose
+//!   ed25519 key the owner declares; `resolve_with_signed_record` verifies them.
+//! - `resolve_for_payer` gives each payer a stable deposit memo, derived from a merchant
+//!   secret or counted up, and `memo_payer` maps memos back to payers for reconciliation.
+//! - `resolve_many` and `resolve_stellar_many` resolve up to 100 commitments at once,
+//!   reporting a status per entry instead of aborting on the first failure.
+//!
+//! ### 5. Stealth Payments
+//! - Senders derive a fresh one-time address from the stealth meta-address off-chain and
+//!   call `announce` with their ephemeral key and view tag.
+//! - Recipients scan `ANNOUNCE` events with their viewing key; the `stealth` helper
+//!   crate implements derivation and scanning.
 //!
 //! ## Privacy & Zero-Knowledge Proofs
 //!
+//! A per-field privacy policy marks the primary wallet, memo, Stellar list, each chain,
+//! the shielded address and text records as public, hidden or delegates-only. Every
+//! public getter returns only public fields; `resolve_records` serves the rest to the
+//! owner and delegates.
+//!
 //! Certain operations (e.g., resolver registration, ownership transfer) support
 //! **zero-knowledge proofs (ZKPs)** to:
 //!
@@ -60,20 +85,46 @@
 //! - Uses a zero-knowledge proof to authorize the transfer.
 //! - Enables privacy-preserving ownership changes.
 //!
+//! ### 3. Two-step Transfer
+//! - The owner proposes a recipient and expiry; ownership moves when the recipient accepts.
+//!
+//! ### 4. Guardian Recovery
+//! - Owners may configure an M-of-N guardian set with a recovery delay.
+//! - Guardians approve a new owner; the owner can veto until the timelock elapses.
+//!
+//! ### Transfer Cooldown
+//! - An optional cooldown, set by the contract owner, follows every ownership change. Until it
+//!   ends the name cannot be transferred again and its payment addresses are frozen.
+//!
+//! ### Soulbound Names
+//! - An owner can irreversibly lock transfers; the name then only moves through
+//!   recovery approved by the contract owner.
+//!
+//! ### Group Ownership
+//! - An owner can hand a name to an M-of-N signer group. The group acts through
+//!   typed actions signed by M signers at once or approved across ledgers, and
+//!   leaves group ownership through a two-step transfer.
+//! - Every owner-only operation has a group action except the direct and ZK transfers,
+//!   which reject group-owned names. Record updates go through delegates the group grants;
+//!   owner-only reads (`Hidden` fields, private payer memos) are closed to a group.
+//!
 //! ### Guarantees
-//! - Only the **current valid owner** (or valid proof) can transfer ownership.
+//! - Only the **current valid owner** (or its signer group or its guardians) can transfer
+//!   ownership.
 //! - All ownership changes are **atomic and consistent**.
 //!
 //! ## Storage Model
 //!
 //! The contract maintains:
 //! - Commitment → Owner mappings
+//! - Owner → Commitments index, paginated with `owned_by` / `owned_count`
 //! - Commitment → Address mappings (multi-chain + Stellar)
 //! - Commitment → Metadata (memo, privacy mode)
+//! - Commitment → Bounded history of owner, primary Stellar address, memo and privacy mode
 //! - SMT root (global state anchor)
 //!
 //! Soft constraints:
-//! - No plaintext usernames stored
+//! - No plaintext usernames stored unless the owner publishes one
 //! - All lookups are keyed by commitment
 //!
 //! ## Purpose
@@ -90,14 +141,30 @@
 pub mod address_manager;
 pub mod admin;
 pub mod alien_gateway;
+pub mod claims;
+pub mod custom_resolver;
+pub mod delegates;
+pub mod encrypted_records;
 pub mod errors;
 pub mod events;
+pub mod history;
+pub mod link_verifier;
+pub mod multisig;
+pub mod owner_index;
+pub mod payer_memos;
+pub mod poseidon;
+pub mod privacy;
+pub mod recovery;
 pub mod registration;
+pub mod reserved;
 pub mod resolver;
+pub mod signed_records;
 pub mod smt_root;
+pub mod stealth;
 pub mod storage;
 pub mod transfer;
 pub mod types;
+pub mod username;
 pub mod zk_verifier;
 
 #[cfg(test)]
@@ -105,11 +172,31 @@ mod test;
 
 use address_manager::AddressManager;
 use admin::Admin;
+use claims::Claims;
+use custom_resolver::CustomResolvers;
+use delegates::Delegates;
+use encrypted_records::EncryptedRecords;
+use history::History;
+use multisig::Multisig;
+use owner_index::OwnerIndex;
+use payer_memos::PayerMemos;
+use privacy::Privacy;
+use recovery::Recovery;
 use registration::Registration;
+use reserved::Reserved;
 use resolver::Resolver;
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Symbol};
+use signed_records::SignedRecords;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, MuxedAddress, String};
+use stealth::Stealth;
 use transfer::Transfer;
-use types::{ChainType, PrivacyMode, Proof, PublicSignals};
+use types::{
+    AddressMetadata, ChainAddressRecord, ChainType, ClaimRound, DelegateGrant, DelegateRole,
+    EncryptedRecord, GuardianConfig, HistoryEntry, MultisigAction, MultisigConfig,
+    MultisigProposal, PendingTransfer, PrivacyMode, PrivacyPolicy, Proof, PublicSignals,
+    RecordGrant, RecordPolicy, RecordView, RecoveryRequest, ReservedName, ReservedReason,
+    ResolveStatus, ShieldedRecord, StealthMetaAddress, StellarAddressEntry,
+};
+use username::Username;
 
 #[contract]
 pub struct Contract;
@@ -129,6 +216,42 @@ impl Contract {
     /// Updates the SMT root with owner authorization. See [admin::Admin::update_smt_root].
     pub fn update_smt_root(e: Env, r: BytesN<32>) { Admin::update_smt_root(e, r) }
 
+    /// Enables or disables direct ownership transfers. See [admin::Admin::set_direct_transfers_enabled].
+    pub fn set_direct_transfers_enabled(e: Env, v: bool) { Admin::set_direct_transfers_enabled(e, v) }
+
+    /// Returns whether direct ownership transfers are enabled. See [admin::Admin::direct_transfers_enabled].
+    pub fn direct_transfers_enabled(e: Env) -> bool { Admin::direct_transfers_enabled(e) }
+
+    /// Sets the freeze applied after ownership changes. See [admin::Admin::set_transfer_cooldown].
+    pub fn set_transfer_cooldown(e: Env, s: u64) { Admin::set_transfer_cooldown(e, s) }
+
+    /// Returns the post-transfer cooldown in seconds. See [admin::Admin::transfer_cooldown].
+    pub fn transfer_cooldown(e: Env) -> u64 { Admin::transfer_cooldown(e) }
+
+    /// Reserves a username commitment. See [reserved::Reserved::reserve_name].
+    pub fn reserve_name(e: Env, h: BytesN<32>, r: ReservedReason, c: Option<Address>) { Reserved::reserve_name(e, h, r, c) }
+
+    /// Reserves many username commitments. See [reserved::Reserved::reserve_names].
+    pub fn reserve_names(e: Env, n: soroban_sdk::Vec<ReservedName>) { Reserved::reserve_names(e, n) }
+
+    /// Removes a reservation. See [reserved::Reserved::unreserve_name].
+    pub fn unreserve_name(e: Env, h: BytesN<32>) { Reserved::unreserve_name(e, h) }
+
+    /// Gets the reservation for a commitment. See [reserved::Reserved::get_reserved].
+    pub fn get_reserved(e: Env, h: BytesN<32>) -> Option<ReservedName> { Reserved::get_reserved(e, h) }
+
+    /// Publishes an allocation claim round. See [claims::Claims::create_claim_round].
+    pub fn create_claim_round(e: Env, r: BytesN<32>, d: u64) -> u32 { Claims::create_claim_round(e, r, d) }
+
+    /// Claims a pre-allocated username. See [claims::Claims::claim_allocated].
+    pub fn claim_allocated(e: Env, c: Address, r: u32, h: BytesN<32>, p: soroban_sdk::Vec<BytesN<32>>) { Claims::claim_allocated(e, c, r, h, p) }
+
+    /// Gets an allocation claim round. See [claims::Claims::get_claim_round].
+    pub fn get_claim_round(e: Env, r: u32) -> Option<ClaimRound> { Claims::get_claim_round(e, r) }
+
+    /// Returns whether a commitment was claimed in a round. See [claims::Claims::is_claimed].
+    pub fn is_claimed(e: Env, r: u32, h: BytesN<32>) -> bool { Claims::is_claimed(e, r, h) }
+
     /// Registers a username commitment from a verified proof submission.
     pub fn submit_proof(e: Env, c: Address, p: Proof, s: PublicSignals) { Registration::submit_proof(e, c, p, s) }
 
@@ -136,25 +259,88 @@ impl Contract {
     pub fn register_resolver(e: Env, c: Address, h: BytesN<32>, p: Proof, s: PublicSignals) { Resolver::register_resolver(e, c, h, p, s); }
 
     /// Sets a memo for a registered commitment. See [resolver::Resolver::set_memo].
-    pub fn set_memo(e: Env, c: BytesN<32>, m: u64) { Resolver::set_memo(e, c, m) }
+    pub fn set_memo(e: Env, c: Address, h: BytesN<32>, m: u64) { Resolver::set_memo(e, c, h, m) }
 
     /// Sets the privacy mode for a commitment. See [resolver::Resolver::set_privacy_mode].
-    pub fn set_privacy_mode(e: Env, h: BytesN<32>, m: PrivacyMode) { Resolver::set_privacy_mode(e, h, m); }
+    pub fn set_privacy_mode(e: Env, c: Address, h: BytesN<32>, m: PrivacyMode) { Resolver::set_privacy_mode(e, c, h, m); }
 
     /// Retrieves the privacy mode for a commitment. See [resolver::Resolver::get_privacy_mode].
     pub fn get_privacy_mode(e: Env, h: BytesN<32>) -> PrivacyMode { Resolver::get_privacy_mode(e, h) }
 
+    /// Sets the per-field privacy policy. See [privacy::Privacy::set_privacy_policy].
+    pub fn set_privacy_policy(e: Env, c: Address, h: BytesN<32>, p: PrivacyPolicy) { Privacy::set_privacy_policy(e, c, h, p); }
+
+    /// Retrieves the per-field privacy policy. See [privacy::Privacy::get_privacy_policy].
+    pub fn get_privacy_policy(e: Env, h: BytesN<32>) -> PrivacyPolicy { Privacy::get_privacy_policy(e, h) }
+
+    /// Resolves the records visible to an authenticated reader. See [privacy::Privacy::resolve_records].
+    pub fn resolve_records(e: Env, v: Address, h: BytesN<32>) -> RecordView { Privacy::resolve_records(e, v, h) }
+
     /// Resolves a commitment to a wallet and memo. See [resolver::Resolver::resolve].
     pub fn resolve(e: Env, c: BytesN<32>) -> (Address, Option<u64>) { Resolver::resolve(e, c) }
 
+    /// Resolves a batch of commitments with a status per entry. See [resolver::Resolver::resolve_many].
+    pub fn resolve_many(e: Env, c: soroban_sdk::Vec<BytesN<32>>) -> soroban_sdk::Vec<ResolveStatus> { Resolver::resolve_many(e, c) }
+
+    /// Resolves a commitment to a muxed account from its wallet and memo. See [resolver::Resolver::resolve_muxed].
+    pub fn resolve_muxed(e: Env, c: BytesN<32>) -> Option<MuxedAddress> { Resolver::resolve_muxed(e, c) }
+
+    /// Points a commitment at an external resolver contract. See [custom_resolver::CustomResolvers::set_custom_resolver].
+    pub fn set_custom_resolver(e: Env, c: Address, h: BytesN<32>, r: Address) { CustomResolvers::set_custom_resolver(e, c, h, r) }
+
+    /// Stops delegating resolution to an external contract. See [custom_resolver::CustomResolvers::remove_custom_resolver].
+    pub fn remove_custom_resolver(e: Env, c: Address, h: BytesN<32>) { CustomResolvers::remove_custom_resolver(e, c, h) }
+
+    /// Gets the custom resolver of a commitment. See [custom_resolver::CustomResolvers::get_custom_resolver].
+    pub fn get_custom_resolver(e: Env, h: BytesN<32>) -> Option<Address> { CustomResolvers::get_custom_resolver(e, h) }
+
+    /// Gets a text record from the custom resolver. See [custom_resolver::CustomResolvers::text].
+    pub fn text(e: Env, h: BytesN<32>, k: String) -> Option<String> { CustomResolvers::text(e, h, k) }
+
+    /// Declares the off-chain gateway signer of a commitment. See [signed_records::SignedRecords::set_gateway_signer].
+    pub fn set_gateway_signer(e: Env, c: Address, h: BytesN<32>, s: BytesN<32>) { SignedRecords::set_gateway_signer(e, c, h, s) }
+
+    /// Removes the off-chain gateway signer. See [signed_records::SignedRecords::remove_gateway_signer].
+    pub fn remove_gateway_signer(e: Env, c: Address, h: BytesN<32>) { SignedRecords::remove_gateway_signer(e, c, h) }
+
+    /// Gets the off-chain gateway signer. See [signed_records::SignedRecords::get_gateway_signer].
+    pub fn get_gateway_signer(e: Env, h: BytesN<32>) -> Option<BytesN<32>> { SignedRecords::get_gateway_signer(e, h) }
+
+    /// Verifies and returns a gateway-signed record. See [signed_records::SignedRecords::resolve_with_signed_record].
+    pub fn resolve_with_signed_record(e: Env, h: BytesN<32>, r: Bytes, s: BytesN<64>, x: u64) -> Bytes { SignedRecords::resolve_with_signed_record(e, h, r, s, x) }
+
+    /// Sets or clears the secret commitment payer memos are derived from. See [payer_memos::PayerMemos::set_payer_memo_commitment].
+    pub fn set_payer_memo_commitment(e: Env, c: Address, h: BytesN<32>, s: Option<BytesN<32>>) { PayerMemos::set_payer_memo_commitment(e, c, h, s) }
+
+    /// Resolves the wallet and the payer's deposit memo. See [payer_memos::PayerMemos::resolve_for_payer].
+    pub fn resolve_for_payer(e: Env, h: BytesN<32>, p: Address) -> (Address, u64) { PayerMemos::resolve_for_payer(e, h, p) }
+
+    /// Gets the payer a deposit memo was assigned to. See [payer_memos::PayerMemos::memo_payer].
+    pub fn memo_payer(e: Env, h: BytesN<32>, m: u64) -> Option<Address> { PayerMemos::memo_payer(e, h, m) }
+
     /// Registers a username commitment. See [registration::Registration::register].
     pub fn register(e: Env, c: Address, h: BytesN<32>) { Registration::register(e, c, h) }
 
+    /// Burns a username and removes all its records. See [registration::Registration::burn].
+    pub fn burn(e: Env, c: Address, h: BytesN<32>) { Registration::burn(e, c, h) }
+
     /// Gets the owner of a commitment. See [registration::Registration::get_owner].
     pub fn get_owner(e: Env, h: BytesN<32>) -> Option<Address> { Registration::get_owner(e, h) }
 
-    /// Gets the stored username symbol when present.
-    pub fn get_username(e: Env) -> Option<Symbol> { e.storage().instance().get(&alien_gateway::storage::username_key(&e)) }
+    /// Lists the commitments an address owns, one page at a time. See [owner_index::OwnerIndex::owned_by].
+    pub fn owned_by(e: Env, o: Address, c: u32, l: u32) -> soroban_sdk::Vec<BytesN<32>> { OwnerIndex::owned_by(e, o, c, l) }
+
+    /// Counts the commitments an address owns. See [owner_index::OwnerIndex::owned_count].
+    pub fn owned_count(e: Env, o: Address) -> u32 { OwnerIndex::owned_count(e, o) }
+
+    /// Publishes the plaintext username after checking its hash. See [username::Username::publish_username].
+    pub fn publish_username(e: Env, c: Address, h: BytesN<32>, u: String) { Username::publish_username(e, c, h, u) }
+
+    /// Removes a published username. See [username::Username::unpublish_username].
+    pub fn unpublish_username(e: Env, c: Address, h: BytesN<32>) { Username::unpublish_username(e, c, h) }
+
+    /// Gets the published username of a commitment. See [username::Username::get_username].
+    pub fn get_username(e: Env, h: BytesN<32>) -> Option<String> { Username::get_username(e, h) }
 
     /// Gets the registration ledger timestamp for a commitment. See [registration::Registration::get_created_at].
     pub fn get_created_at(e: Env, h: BytesN<32>) -> Option<u64> { Registration::get_created_at(e, h) }
@@ -165,9 +351,33 @@ impl Contract {
     /// Gets the blockchain address for a commitment. See [address_manager::AddressManager::get_chain_address].
     pub fn get_chain_address(e: Env, h: BytesN<32>, t: ChainType) -> Option<Bytes> { AddressManager::get_chain_address(e, h, t) }
 
+    /// Adds a signature-verified blockchain address. See [address_manager::AddressManager::add_verified_chain_address].
+    pub fn add_verified_chain_address(e: Env, c: Address, h: BytesN<32>, t: ChainType, a: Bytes, g: Bytes) { AddressManager::add_verified_chain_address(e, c, h, t, a, g); }
+
+    /// Gets the blockchain address only if signature-verified. See [address_manager::AddressManager::get_verified_chain_address].
+    pub fn get_verified_chain_address(e: Env, h: BytesN<32>, t: ChainType) -> Option<Bytes> { AddressManager::get_verified_chain_address(e, h, t) }
+
+    /// Gets the message to sign for the next verified link. See [address_manager::AddressManager::chain_link_message].
+    pub fn chain_link_message(e: Env, h: BytesN<32>, t: ChainType) -> Bytes { AddressManager::chain_link_message(e, h, t) }
+
+    /// Gets the nonce for the next verified link. See [address_manager::AddressManager::get_link_nonce].
+    pub fn get_link_nonce(e: Env, h: BytesN<32>) -> u64 { AddressManager::get_link_nonce(e, h) }
+
     /// Removes a blockchain address for a commitment. See [address_manager::AddressManager::remove_chain_address].
     pub fn remove_chain_address(e: Env, c: Address, h: BytesN<32>, t: ChainType) { AddressManager::remove_chain_address(e, c, h, t); }
 
+    /// Lists the addresses linked for a chain. See [address_manager::AddressManager::list_chain_addresses].
+    pub fn list_chain_addresses(e: Env, h: BytesN<32>, t: ChainType, s: u32, l: u32) -> soroban_sdk::Vec<ChainAddressRecord> { AddressManager::list_chain_addresses(e, h, t, s, l) }
+
+    /// Sets the primary address for a chain. See [address_manager::AddressManager::set_primary_chain_address].
+    pub fn set_primary_chain_address(e: Env, c: Address, h: BytesN<32>, t: ChainType, a: Bytes) { AddressManager::set_primary_chain_address(e, c, h, t, a); }
+
+    /// Sets the label of a chain address. See [address_manager::AddressManager::set_chain_address_label].
+    pub fn set_chain_address_label(e: Env, c: Address, h: BytesN<32>, t: ChainType, a: Bytes, m: AddressMetadata) { AddressManager::set_chain_address_label(e, c, h, t, a, m); }
+
+    /// Removes a single chain address. See [address_manager::AddressManager::remove_chain_address_entry].
+    pub fn remove_chain_address_entry(e: Env, c: Address, h: BytesN<32>, t: ChainType, a: Bytes) { AddressManager::remove_chain_address_entry(e, c, h, t, a); }
+
     /// Adds a Stellar address for a commitment. See [address_manager::AddressManager::add_stellar_address].
     pub fn add_stellar_address(e: Env, c: Address, h: BytesN<32>, a: Address) { AddressManager::add_stellar_address(e, c, h, a); }
 
@@ -177,14 +387,116 @@ impl Contract {
     /// Gets all Stellar addresses for a commitment. See [address_manager::AddressManager::get_stellar_addresses].
     pub fn get_stellar_addresses(e: Env, h: BytesN<32>) -> soroban_sdk::Vec<Address> { AddressManager::get_stellar_addresses(e, h) }
 
+    /// Sets the primary Stellar address. See [address_manager::AddressManager::set_primary_stellar_address].
+    pub fn set_primary_stellar_address(e: Env, c: Address, h: BytesN<32>, a: Address) { AddressManager::set_primary_stellar_address(e, c, h, a); }
+
+    /// Sets the label of a Stellar address. See [address_manager::AddressManager::set_stellar_address_label].
+    pub fn set_stellar_address_label(e: Env, c: Address, h: BytesN<32>, a: Address, m: AddressMetadata) { AddressManager::set_stellar_address_label(e, c, h, a, m); }
+
+    /// Lists the labeled Stellar addresses for a commitment. See [address_manager::AddressManager::list_stellar_addresses].
+    pub fn list_stellar_addresses(e: Env, h: BytesN<32>, s: u32, l: u32) -> soroban_sdk::Vec<StellarAddressEntry> { AddressManager::list_stellar_addresses(e, h, s, l) }
+
     /// Resolves a commitment to its Stellar address. See [address_manager::AddressManager::resolve_stellar].
     pub fn resolve_stellar(e: Env, h: BytesN<32>) -> Address { AddressManager::resolve_stellar(e, h) }
 
+    /// Resolves a batch of commitments to Stellar addresses with a status per entry. See [address_manager::AddressManager::resolve_stellar_many].
+    pub fn resolve_stellar_many(e: Env, h: soroban_sdk::Vec<BytesN<32>>) -> soroban_sdk::Vec<ResolveStatus> { AddressManager::resolve_stellar_many(e, h) }
+
     /// Transfers username ownership. See [transfer::Transfer::transfer_ownership].
-    pub fn transfer_ownership(e: Env, c: Address, h: BytesN<32>, n: Address) { Transfer::transfer_ownership(e, c, h, n); }
+    pub fn transfer_ownership(e: Env, c: Address, h: BytesN<32>, n: Address, r: RecordPolicy) { Transfer::transfer_ownership(e, c, h, n, r); }
+
+    /// Proposes a two-step ownership transfer. See [transfer::Transfer::propose_transfer].
+    pub fn propose_transfer(e: Env, c: Address, h: BytesN<32>, n: Address, x: u64, r: RecordPolicy) { Transfer::propose_transfer(e, c, h, n, x, r); }
+
+    /// Accepts a pending ownership transfer. See [transfer::Transfer::accept_transfer].
+    pub fn accept_transfer(e: Env, c: Address, h: BytesN<32>) { Transfer::accept_transfer(e, c, h); }
+
+    /// Cancels a pending ownership transfer. See [transfer::Transfer::cancel_transfer].
+    pub fn cancel_transfer(e: Env, c: Address, h: BytesN<32>) { Transfer::cancel_transfer(e, c, h); }
+
+    /// Gets the pending ownership transfer. See [transfer::Transfer::get_pending_transfer].
+    pub fn get_pending_transfer(e: Env, h: BytesN<32>) -> Option<PendingTransfer> { Transfer::get_pending_transfer(e, h) }
+
+    /// Lists the resolution change history. See [history::History::history].
+    pub fn history(e: Env, h: BytesN<32>, c: u32, l: u32) -> soroban_sdk::Vec<HistoryEntry> { History::history(e, h, c, l) }
+
+    /// Returns the resolution state at a past timestamp. See [history::History::resolve_at].
+    pub fn resolve_at(e: Env, h: BytesN<32>, t: u64) -> Option<HistoryEntry> { History::resolve_at(e, h, t) }
+
+    /// Registers a stealth meta-address. See [stealth::Stealth::set_stealth_meta_address].
+    pub fn set_stealth_meta_address(e: Env, c: Address, h: BytesN<32>, m: StealthMetaAddress) { Stealth::set_stealth_meta_address(e, c, h, m); }
+
+    /// Removes the stealth meta-address. See [stealth::Stealth::remove_stealth_meta_address].
+    pub fn remove_stealth_meta_address(e: Env, c: Address, h: BytesN<32>) { Stealth::remove_stealth_meta_address(e, c, h); }
+
+    /// Gets the stealth meta-address. See [stealth::Stealth::get_stealth_meta_address].
+    pub fn get_stealth_meta_address(e: Env, h: BytesN<32>) -> Option<StealthMetaAddress> { Stealth::get_stealth_meta_address(e, h) }
+
+    /// Announces a stealth payment. See [stealth::Stealth::announce].
+    pub fn announce(e: Env, c: Address, s: u32, a: Bytes, k: Bytes, v: u32, m: Bytes) { Stealth::announce(e, c, s, a, k, v, m); }
+
+    /// Stores encrypted address records. See [encrypted_records::EncryptedRecords::set_encrypted_record].
+    pub fn set_encrypted_record(e: Env, c: Address, h: BytesN<32>, x: Bytes, g: soroban_sdk::Vec<RecordGrant>) { EncryptedRecords::set_encrypted_record(e, c, h, x, g); }
+
+    /// Grants a viewing key access to encrypted records. See [encrypted_records::EncryptedRecords::grant_record_access].
+    pub fn grant_record_access(e: Env, c: Address, h: BytesN<32>, g: RecordGrant) { EncryptedRecords::grant_record_access(e, c, h, g); }
+
+    /// Revokes a viewing key's access to encrypted records. See [encrypted_records::EncryptedRecords::revoke_record_access].
+    pub fn revoke_record_access(e: Env, c: Address, h: BytesN<32>, v: BytesN<32>) { EncryptedRecords::revoke_record_access(e, c, h, v); }
+
+    /// Removes encrypted address records. See [encrypted_records::EncryptedRecords::remove_encrypted_record].
+    pub fn remove_encrypted_record(e: Env, c: Address, h: BytesN<32>) { EncryptedRecords::remove_encrypted_record(e, c, h); }
+
+    /// Gets encrypted address records. See [encrypted_records::EncryptedRecords::get_encrypted_record].
+    pub fn get_encrypted_record(e: Env, h: BytesN<32>) -> Option<EncryptedRecord> { EncryptedRecords::get_encrypted_record(e, h) }
+
+    /// Irreversibly makes a username non-transferable. See [transfer::Transfer::lock_transfers].
+    pub fn lock_transfers(e: Env, c: Address, h: BytesN<32>) { Transfer::lock_transfers(e, c, h); }
+
+    /// Returns whether a username is non-transferable. See [transfer::Transfer::is_transfer_locked].
+    pub fn is_transfer_locked(e: Env, h: BytesN<32>) -> bool { Transfer::is_transfer_locked(e, h) }
+
+    /// Returns when the post-transfer freeze ends. See [transfer::Transfer::locked_until].
+    pub fn locked_until(e: Env, h: BytesN<32>) -> u64 { Transfer::locked_until(e, h) }
+
+    /// Configures recovery guardians. See [recovery::Recovery::set_guardians].
+    pub fn set_guardians(e: Env, c: Address, h: BytesN<32>, g: soroban_sdk::Vec<Address>, t: u32, d: u64) { Recovery::set_guardians(e, c, h, g, t, d); }
+
+    /// Approves guardian recovery to a new owner. See [recovery::Recovery::recover].
+    pub fn recover(e: Env, g: Address, h: BytesN<32>, n: Address) { Recovery::recover(e, g, h, n); }
+
+    /// Executes a timelocked recovery. See [recovery::Recovery::execute_recovery].
+    pub fn execute_recovery(e: Env, h: BytesN<32>) { Recovery::execute_recovery(e, h); }
+
+    /// Vetoes an open recovery request. See [recovery::Recovery::cancel_recovery].
+    pub fn cancel_recovery(e: Env, c: Address, h: BytesN<32>) { Recovery::cancel_recovery(e, c, h); }
+
+    /// Gets the guardian configuration. See [recovery::Recovery::get_guardians].
+    pub fn get_guardians(e: Env, h: BytesN<32>) -> Option<GuardianConfig> { Recovery::get_guardians(e, h) }
+
+    /// Gets the open recovery request. See [recovery::Recovery::get_recovery].
+    pub fn get_recovery(e: Env, h: BytesN<32>) -> Option<RecoveryRequest> { Recovery::get_recovery(e, h) }
+
+    /// Hands a username to a threshold signer group. See [multisig::Multisig::set_multisig].
+    pub fn set_multisig(e: Env, c: Address, h: BytesN<32>, s: soroban_sdk::Vec<Address>, t: u32) { Multisig::set_multisig(e, c, h, s, t) }
+
+    /// Executes a group action signed by enough signers at once. See [multisig::Multisig::execute_multisig].
+    pub fn execute_multisig(e: Env, s: soroban_sdk::Vec<Address>, h: BytesN<32>, a: MultisigAction) { Multisig::execute_multisig(e, s, h, a) }
+
+    /// Opens a group proposal. See [multisig::Multisig::propose_multisig].
+    pub fn propose_multisig(e: Env, s: Address, h: BytesN<32>, a: MultisigAction) { Multisig::propose_multisig(e, s, h, a) }
+
+    /// Approves the open group proposal. See [multisig::Multisig::approve_multisig].
+    pub fn approve_multisig(e: Env, s: Address, h: BytesN<32>, a: MultisigAction) { Multisig::approve_multisig(e, s, h, a) }
+
+    /// Gets the signer group owning a username. See [multisig::Multisig::get_multisig].
+    pub fn get_multisig(e: Env, h: BytesN<32>) -> Option<MultisigConfig> { Multisig::get_multisig(e, h) }
+
+    /// Gets the signer group's open proposal. See [multisig::Multisig::get_multisig_proposal].
+    pub fn get_multisig_proposal(e: Env, h: BytesN<32>) -> Option<MultisigProposal> { Multisig::get_multisig_proposal(e, h) }
 
     /// Transfers username ownership with ZK proof. See [transfer::Transfer::transfer].
-    pub fn transfer(e: Env, c: Address, h: BytesN<32>, n: Address, p: Proof, s: PublicSignals) { Transfer::transfer(e, c, h, n, p, s); }
+    pub fn transfer(e: Env, c: Address, h: BytesN<32>, n: Address, p: Proof, s: PublicSignals, r: RecordPolicy) { Transfer::transfer(e, c, h, n, p, s, r); }
 
     /// Adds a shielded address for a commitment. See [address_manager::AddressManager::add_shielded_address].
     pub fn add_shielded_address(e: Env, c: Address, h: BytesN<32>, a: BytesN<32>) { AddressManager::add_shielded_address(e, c, h, a); }
@@ -194,4 +506,25 @@ impl Contract {
 
     /// Checks if a commitment has a shielded address. See [address_manager::AddressManager::is_shielded].
     pub fn is_shielded(e: Env, h: BytesN<32>) -> bool { AddressManager::is_shielded(e, h) }
+
+    /// Replaces the current shielded address, keeping the old one in history. See [address_manager::AddressManager::rotate_shielded_address].
+    pub fn rotate_shielded_address(e: Env, c: Address, h: BytesN<32>, a: BytesN<32>) { AddressManager::rotate_shielded_address(e, c, h, a); }
+
+    /// Removes the current shielded address. See [address_manager::AddressManager::remove_shielded_address].
+    pub fn remove_shielded_address(e: Env, c: Address, h: BytesN<32>) { AddressManager::remove_shielded_address(e, c, h); }
+
+    /// Lists recent shielded addresses with validity windows. See [address_manager::AddressManager::shielded_address_history].
+    pub fn shielded_address_history(e: Env, h: BytesN<32>) -> soroban_sdk::Vec<ShieldedRecord> { AddressManager::shielded_address_history(e, h) }
+
+    /// Checks a shielded address was current at a timestamp. See [address_manager::AddressManager::was_shielded_address_valid].
+    pub fn was_shielded_address_valid(e: Env, h: BytesN<32>, a: BytesN<32>, t: u64) -> bool { AddressManager::was_shielded_address_valid(e, h, a, t) }
+
+    /// Grants a delegate role on a commitment. See [delegates::Delegates::grant_delegate].
+    pub fn grant_delegate(e: Env, c: Address, h: BytesN<32>, d: Address, r: DelegateRole, x: Option<u64>) { Delegates::grant_delegate(e, c, h, d, r, x); }
+
+    /// Revokes a delegate role on a commitment. See [delegates::Delegates::revoke_delegate].
+    pub fn revoke_delegate(e: Env, c: Address, h: BytesN<32>, d: Address, r: DelegateRole) { Delegates::revoke_delegate(e, c, h, d, r); }
+
+    /// Gets the active delegate grants for a commitment. See [delegates::Delegates::get_delegates].
+    pub fn get_delegates(e: Env, h: BytesN<32>) -> soroban_sdk::Vec<DelegateGrant> { Delegates::get_delegates(e, h) }
 }
@@ -0,0 +1,204 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::types::ChainType;
+
+/// Domain tag prepended to every chain-link message so signatures cannot be
+/// replayed against any other protocol or message format.
+const LINK_DOMAIN: &[u8] = b"ALIEN_GATEWAY_CHAIN_LINK_V1";
+
+/// EIP-191 `personal_sign` prefix used by EVM wallets.
+const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// The ed25519 group order `L`, little-endian. A signature's `S` half must be below it.
+const ED25519_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Base58 alphabet used by Solana addresses.
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+pub struct LinkVerifier;
+
+impl LinkVerifier {
+    /// Builds the domain-separated message an external key must sign to prove
+    /// control of an address linked to `username_hash`.
+    ///
+    /// Layout: `LINK_DOMAIN || contract_id (XDR) || username_hash || chain (u32 BE) || nonce (u64 BE)`.
+    pub fn link_message(
+        env: &Env,
+        username_hash: &BytesN<32>,
+        chain: &ChainType,
+        nonce: u64,
+    ) -> Bytes {
+        let mut message = Bytes::from_slice(env, LINK_DOMAIN);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &username_hash.to_array()));
+        message.extend_from_array(&Self::chain_tag(chain).to_be_bytes());
+        message.extend_from_array(&nonce.to_be_bytes());
+        message
+    }
+
+    /// Verifies an EVM `personal_sign` signature over `message`.
+    ///
+    /// `signature` must be 65 bytes (`r || s || v`) with `v` in `{0, 1, 27, 28}`.
+    /// `address` is the `0x`-prefixed hex address as stored on the commitment.
+    /// Returns `true` only when the recovered signer matches `address`.
+    pub fn verify_evm(env: &Env, address: &Bytes, message: &Bytes, signature: &Bytes) -> bool {
+        if signature.len() != 65 {
+            return false;
+        }
+        let expected = match Self::decode_evm_address(address) {
+            Some(addr) => addr,
+            None => return false,
+        };
+
+        let recovery_id = match signature.get(64) {
+            Some(v @ 0..=1) => v as u32,
+            Some(v @ 27..=28) => (v - 27) as u32,
+            _ => return false,
+        };
+        let mut rs = [0u8; 64];
+        signature.slice(0..64).copy_into_slice(&mut rs);
+
+        let mut prefixed = Bytes::from_slice(env, EIP191_PREFIX);
+        Self::append_decimal(&mut prefixed, message.len());
+        prefixed.append(message);
+        let digest = env.crypto().keccak256(&prefixed);
+
+        let public_key =
+            env.crypto()
+                .secp256k1_recover(&digest, &BytesN::from_array(env, &rs), recovery_id);
+        // Ethereum address = last 20 bytes of keccak256(X || Y), skipping the 0x04 prefix.
+        let key_hash = env
+            .crypto()
+            .keccak256(&Bytes::from_array(env, &public_key.to_array()).slice(1..65))
+            .to_array();
+
+        key_hash[12..] == expected
+    }
+
+    /// Verifies a Solana ed25519 signature over `message`.
+    ///
+    /// `signature` must be 64 bytes with a canonical `S` half and `address` the
+    /// base58-encoded public key as stored on the commitment. Returns `false` for such
+    /// malformed input. The host has no non-trapping ed25519 check, so a well-formed
+    /// signature that does not verify aborts the invocation instead of returning.
+    pub fn verify_solana(env: &Env, address: &Bytes, message: &Bytes, signature: &Bytes) -> bool {
+        if signature.len() != 64 {
+            return false;
+        }
+        let public_key = match Self::decode_base58_pubkey(address) {
+            Some(pk) => pk,
+            None => return false,
+        };
+        let mut sig = [0u8; 64];
+        signature.copy_into_slice(&mut sig);
+        if !Self::is_canonical_scalar(&sig[32..]) {
+            return false;
+        }
+
+        env.crypto().ed25519_verify(
+            &BytesN::from_array(env, &public_key),
+            message,
+            &BytesN::from_array(env, &sig),
+        );
+        true
+    }
+
+    /// (Internal) Returns whether a little-endian 32-byte scalar is below the ed25519
+    /// group order, as RFC 8032 requires of a signature's `S` half.
+    fn is_canonical_scalar(scalar: &[u8]) -> bool {
+        for (byte, order) in scalar.iter().zip(ED25519_ORDER.iter()).rev() {
+            if byte != order {
+                return byte < order;
+            }
+        }
+        false
+    }
+
+    /// (Internal) Stable numeric tag for a chain used inside the signed message.
+    fn chain_tag(chain: &ChainType) -> u32 {
+        match chain {
+            ChainType::Evm => 0,
+            ChainType::Bitcoin => 1,
+            ChainType::Solana => 2,
+            ChainType::Cosmos => 3,
+        }
+    }
+
+    /// (Internal) Appends the ASCII decimal representation of `value` to `out`.
+    fn append_decimal(out: &mut Bytes, value: u32) {
+        let mut digits = [0u8; 10];
+        let mut len = 0;
+        let mut n = value;
+        loop {
+            digits[len] = b'0' + (n % 10) as u8;
+            len += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for i in (0..len).rev() {
+            out.push_back(digits[i]);
+        }
+    }
+
+    /// (Internal) Decodes a `0x`-prefixed, 40-hex-character EVM address into 20 bytes.
+    fn decode_evm_address(address: &Bytes) -> Option<[u8; 20]> {
+        if address.len() != 42 {
+            return None;
+        }
+        let mut out = [0u8; 20];
+        for (i, byte) in out.iter_mut().enumerate() {
+            let hi = Self::hex_value(address.get(2 + 2 * i as u32)?)?;
+            let lo = Self::hex_value(address.get(3 + 2 * i as u32)?)?;
+            *byte = (hi << 4) | lo;
+        }
+        Some(out)
+    }
+
+    /// (Internal) Maps an ASCII hex digit to its value.
+    fn hex_value(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    /// (Internal) Decodes a base58 string into exactly 32 bytes.
+    ///
+    /// Rejects characters outside the alphabet, values that overflow 32 bytes, and
+    /// encodings whose leading `1`s do not match the leading zero bytes.
+    fn decode_base58_pubkey(address: &Bytes) -> Option<[u8; 32]> {
+        let mut out = [0u8; 32];
+        let mut leading_ones = 0usize;
+        let mut seen_non_one = false;
+        for c in address.iter() {
+            let digit = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+            if digit == 0 && !seen_non_one {
+                leading_ones += 1;
+            } else {
+                seen_non_one = true;
+            }
+            let mut carry = digit;
+            for byte in out.iter_mut().rev() {
+                carry += (*byte as u32) * 58;
+                *byte = (carry & 0xff) as u8;
+                carry >>= 8;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        let leading_zeros = out.iter().take_while(|&&b| b == 0).count();
+        if leading_zeros != leading_ones {
+            return None;
+        }
+        Some(out)
+    }
+}
@@ -0,0 +1,303 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{
+    multisig_approved_event, multisig_executed_event, multisig_proposed_event, multisig_set_event,
+    TRANSFER_EVENT,
+};
+use crate::recovery::Recovery;
+use crate::registration::Registration;
+use crate::storage::{self, MAX_SIGNERS};
+use crate::transfer::Transfer;
+use crate::types::{MultisigAction, MultisigConfig, MultisigProposal, RecordPolicy};
+use crate::username::Username;
+
+pub struct Multisig;
+
+impl Multisig {
+    /// Hands a commitment to a threshold group of signers.
+    ///
+    /// While group-owned, the commitment's recorded owner is this contract's own address.
+    /// No external caller can authorize as it, so every single-owner entrypoint is closed
+    /// and the group acts only through [`MultisigAction`]s approved by `threshold`
+    /// signers. Direct and ZK transfers have no action and reject group-owned names.
+    /// Delegates the group grants keep working as usual. The conversion is an
+    /// ownership change: guardians are cleared, linked records are kept and the transfer
+    /// cooldown starts.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The current single owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `signers`: Distinct signer addresses, at most `MAX_SIGNERS`.
+    /// - `threshold`: Number of signers required to act, `1..=signers.len()`.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `InvalidMultisigConfig`: If the signer set or threshold is invalid.
+    /// - `TransfersLocked`: If the owner has made the username non-transferable.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `MULTISIG_SET` event with (commitment, signers, threshold).
+    /// - Emits `TRANSFER_EVENT` with (commitment, old_owner, contract_address).
+    pub fn set_multisig(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::validate(&env, &signers, threshold);
+        Transfer::require_unlocked(&env, &commitment);
+        Transfer::require_not_frozen(&env, &commitment);
+
+        let group = env.current_contract_address();
+        Transfer::hand_over(&env, &commitment, &group, RecordPolicy::Keep);
+        Self::store(&env, &commitment, signers, threshold);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((TRANSFER_EVENT,), (commitment, caller, group));
+    }
+
+    /// Executes a group action authorized by enough signers in a single invocation.
+    ///
+    /// Every address in `signers` must be a distinct group member and must authorize
+    /// this call.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `signers`: The authorizing group members. Each must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `action`: The owner-level operation to perform.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not group-owned.
+    /// - `Unauthorized`: If an address is not a signer or is listed twice.
+    /// - `InsufficientSigners`: If fewer than `threshold` signers are listed.
+    /// - Any error of the executed action.
+    ///
+    /// ### Events
+    /// - Emits `MULTISIG_EXECUTED` event with (commitment, action), plus the action's own events.
+    pub fn execute_multisig(
+        env: Env,
+        signers: Vec<Address>,
+        commitment: BytesN<32>,
+        action: MultisigAction,
+    ) {
+        let config = Self::require_group(&env, &commitment);
+        for (i, signer) in signers.iter().enumerate() {
+            if !config.signers.contains(&signer)
+                || signers.first_index_of(&signer) != Some(i as u32)
+            {
+                panic_with_error!(&env, CoreError::Unauthorized);
+            }
+            signer.require_auth();
+        }
+        if signers.len() < config.threshold {
+            panic_with_error!(&env, CoreError::InsufficientSigners);
+        }
+        Self::apply(&env, &commitment, action);
+    }
+
+    /// Opens a group proposal, counting the proposer's approval.
+    ///
+    /// Replaces any open proposal and its approvals. The action executes as soon as
+    /// `threshold` approvals are collected, which is immediately for a 1-of-N group.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `signer`: A group member. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `action`: The owner-level operation to propose.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not group-owned.
+    /// - `Unauthorized`: If the caller is not a signer.
+    ///
+    /// ### Events
+    /// - Emits `MULTISIG_PROPOSED` event with (commitment, signer, action).
+    pub fn propose_multisig(
+        env: Env,
+        signer: Address,
+        commitment: BytesN<32>,
+        action: MultisigAction,
+    ) {
+        let config = Self::require_signer(&env, &signer, &commitment);
+        let proposal = MultisigProposal {
+            action: action.clone(),
+            approvals: Vec::from_array(&env, [signer.clone()]),
+        };
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (multisig_proposed_event(&env),),
+            (commitment.clone(), signer, action),
+        );
+        Self::approve_or_execute(&env, &commitment, &config, proposal);
+    }
+
+    /// Approves the open group proposal.
+    ///
+    /// `action` must match the open proposal, so an approval can never be applied to a
+    /// proposal that replaced the one the signer reviewed.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `signer`: A group member. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `action`: The action being approved.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not group-owned, or no open proposal has this action.
+    /// - `Unauthorized`: If the caller is not a signer.
+    /// - `AlreadyApproved`: If the signer already approved the proposal.
+    ///
+    /// ### Events
+    /// - Emits `MULTISIG_APPROVED` event with (commitment, signer, approvals).
+    pub fn approve_multisig(
+        env: Env,
+        signer: Address,
+        commitment: BytesN<32>,
+        action: MultisigAction,
+    ) {
+        let config = Self::require_signer(&env, &signer, &commitment);
+        let mut proposal = storage::get_multisig_proposal(&env, &commitment)
+            .filter(|p| p.action == action)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if proposal.approvals.contains(&signer) {
+            panic_with_error!(&env, CoreError::AlreadyApproved);
+        }
+        proposal.approvals.push_back(signer.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (multisig_approved_event(&env),),
+            (commitment.clone(), signer, proposal.approvals.len()),
+        );
+        Self::approve_or_execute(&env, &commitment, &config, proposal);
+    }
+
+    /// Returns the signer group owning a commitment, if any.
+    pub fn get_multisig(env: Env, commitment: BytesN<32>) -> Option<MultisigConfig> {
+        storage::get_multisig(&env, &commitment)
+    }
+
+    /// Returns the signer group's open proposal, if any.
+    pub fn get_multisig_proposal(env: Env, commitment: BytesN<32>) -> Option<MultisigProposal> {
+        storage::get_multisig_proposal(&env, &commitment)
+    }
+
+    /// (Internal) Stores `proposal`, or executes it once it has `threshold` approvals.
+    fn approve_or_execute(
+        env: &Env,
+        commitment: &BytesN<32>,
+        config: &MultisigConfig,
+        proposal: MultisigProposal,
+    ) {
+        if proposal.approvals.len() < config.threshold {
+            storage::set_multisig_proposal(env, commitment, &proposal);
+            return;
+        }
+        storage::remove_multisig_proposal(env, commitment);
+        Self::apply(env, commitment, proposal.action);
+    }
+
+    /// (Internal) Performs an approved group action.
+    fn apply(env: &Env, commitment: &BytesN<32>, action: MultisigAction) {
+        match action.clone() {
+            MultisigAction::SetSigners(signers, threshold) => {
+                Self::validate(env, &signers, threshold);
+                storage::remove_multisig_proposal(env, commitment);
+                Self::store(env, commitment, signers, threshold);
+            }
+            MultisigAction::ProposeTransfer(new_owner, expires_at, policy) => {
+                Transfer::propose(
+                    env,
+                    commitment,
+                    env.current_contract_address(),
+                    new_owner,
+                    expires_at,
+                    policy,
+                );
+            }
+            MultisigAction::GrantDelegate(delegate, role, expires_at) => {
+                Delegates::grant(env, commitment, delegate, role, expires_at);
+            }
+            MultisigAction::RevokeDelegate(delegate, role) => {
+                Delegates::revoke(env, commitment, delegate, role);
+            }
+            MultisigAction::CancelTransfer => Transfer::cancel(env, commitment),
+            MultisigAction::LockTransfers => {
+                Transfer::lock(env, commitment, env.current_contract_address());
+            }
+            MultisigAction::SetGuardians(guardians, threshold, delay) => {
+                let group = env.current_contract_address();
+                Recovery::configure(env, commitment, &group, guardians, threshold, delay);
+            }
+            MultisigAction::CancelRecovery => Recovery::veto(env, commitment),
+            MultisigAction::PublishUsername(username) => {
+                Username::publish(env, commitment, username);
+            }
+            MultisigAction::UnpublishUsername => Username::unpublish(env, commitment),
+            MultisigAction::Burn => {
+                Registration::destroy(env, commitment, env.current_contract_address());
+            }
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (multisig_executed_event(env),),
+            (commitment.clone(), action),
+        );
+    }
+
+    /// (Internal) Writes the signer group and emits `MULTISIG_SET`.
+    fn store(env: &Env, commitment: &BytesN<32>, signers: Vec<Address>, threshold: u32) {
+        let config = MultisigConfig {
+            signers: signers.clone(),
+            threshold,
+        };
+        storage::set_multisig(env, commitment, &config);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (multisig_set_event(env),),
+            (commitment.clone(), signers, threshold),
+        );
+    }
+
+    /// (Internal) Panics with `InvalidMultisigConfig` unless `signers` is a non-empty set of
+    /// at most `MAX_SIGNERS` distinct external addresses and `threshold` is in range.
+    fn validate(env: &Env, signers: &Vec<Address>, threshold: u32) {
+        if signers.len() > MAX_SIGNERS || threshold == 0 || threshold > signers.len() {
+            panic_with_error!(env, CoreError::InvalidMultisigConfig);
+        }
+        let this = env.current_contract_address();
+        for (i, signer) in signers.iter().enumerate() {
+            if signer == this || signers.first_index_of(&signer) != Some(i as u32) {
+                panic_with_error!(env, CoreError::InvalidMultisigConfig);
+            }
+        }
+    }
+
+    /// (Internal) Returns the signer group, panicking if the commitment is not group-owned.
+    fn require_group(env: &Env, commitment: &BytesN<32>) -> MultisigConfig {
+        storage::get_multisig(env, commitment)
+            .unwrap_or_else(|| panic_with_error!(env, CoreError::NotFound))
+    }
+
+    /// (Internal) Requires `signer` auth and that it belongs to the commitment's group.
+    fn require_signer(env: &Env, signer: &Address, commitment: &BytesN<32>) -> MultisigConfig {
+        signer.require_auth();
+        let config = Self::require_group(env, commitment);
+        if !config.signers.contains(signer) {
+            panic_with_error!(env, CoreError::Unauthorized);
+        }
+        config
+    }
+}
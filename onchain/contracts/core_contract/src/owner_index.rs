@@ -0,0 +1,71 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::storage::{self, MAX_PAGE_SIZE};
+
+pub struct OwnerIndex;
+
+impl OwnerIndex {
+    /// Lists the commitments owned by an address, one page at a time.
+    ///
+    /// Entries are kept in slots: removing one moves the last slot into its place, so
+    /// the order is not stable across ownership changes.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `owner`: The owner to list.
+    /// - `cursor`: Index of the first entry to return.
+    /// - `limit`: Maximum number of entries to return, capped at `MAX_PAGE_SIZE`.
+    ///
+    /// ### Returns
+    /// The commitments in `[cursor, cursor + limit)`, empty past the end.
+    pub fn owned_by(env: Env, owner: Address, cursor: u32, limit: u32) -> Vec<BytesN<32>> {
+        let count = storage::get_owned_count(&env, &owner);
+        let start = cursor.min(count);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+        let mut page = Vec::new(&env);
+        for slot in start..end {
+            if let Some(commitment) = storage::get_owned_at(&env, &owner, slot) {
+                page.push_back(commitment);
+            }
+        }
+        page
+    }
+
+    /// Returns the number of commitments owned by an address.
+    pub fn owned_count(env: Env, owner: Address) -> u32 {
+        storage::get_owned_count(&env, &owner)
+    }
+
+    /// (Internal) Appends `commitment` to the index of `owner`.
+    ///
+    /// Group-owned commitments are indexed under this contract's own address, the owner
+    /// [`Registration::get_owner`](crate::registration::Registration::get_owner) reports
+    /// for them.
+    pub(crate) fn add(env: &Env, owner: &Address, commitment: &BytesN<32>) {
+        let count = storage::get_owned_count(env, owner);
+        storage::set_owned_at(env, owner, count, commitment);
+        storage::set_owned_index(env, commitment, count);
+        storage::set_owned_count(env, owner, count + 1);
+    }
+
+    /// (Internal) Removes `commitment` from the index of `owner`, moving the last slot
+    /// into the freed one. Commitments that were never indexed are ignored.
+    pub(crate) fn remove(env: &Env, owner: &Address, commitment: &BytesN<32>) {
+        let Some(slot) = storage::get_owned_index(env, commitment) else {
+            return;
+        };
+        if storage::get_owned_at(env, owner, slot).as_ref() != Some(commitment) {
+            return;
+        }
+        let last = storage::get_owned_count(env, owner) - 1;
+        if slot != last {
+            if let Some(moved) = storage::get_owned_at(env, owner, last) {
+                storage::set_owned_at(env, owner, slot, &moved);
+                storage::set_owned_index(env, &moved, slot);
+            }
+        }
+        storage::remove_owned_at(env, owner, last);
+        storage::remove_owned_index(env, commitment);
+        storage::set_owned_count(env, owner, last);
+    }
+}
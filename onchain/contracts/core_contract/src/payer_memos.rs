@@ -0,0 +1,161 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{payer_memo_assigned_event, payer_memo_commitment_set_event};
+use crate::privacy::Privacy;
+use crate::registration::Registration;
+use crate::resolver::Resolver;
+use crate::storage;
+use crate::transfer::Transfer;
+use crate::types::{DelegateRole, Visibility};
+
+pub struct PayerMemos;
+
+impl PayerMemos {
+    /// Sets or clears the merchant secret commitment that payer memos are derived from.
+    ///
+    /// The merchant keeps its secret off-chain and submits only `sha256(secret)`, so the
+    /// secret never appears in storage or transaction arguments. With a commitment, a new
+    /// payer's memo is the first 8 bytes of `sha256(sha256(secret) || payer (XDR))`, which
+    /// the merchant recomputes from its secret. Without one, memos are allocated from a
+    /// per-commitment counter starting at 1. Memos already assigned are kept either way.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `secret_commitment`: `sha256(secret)`, or `None` to switch to the counter.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `PAYER_COMMIT` event with (commitment, derived) where `derived` tells
+    ///   whether a secret commitment is now set.
+    pub fn set_payer_memo_commitment(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        secret_commitment: Option<BytesN<32>>,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &commitment);
+        let derived = secret_commitment.is_some();
+        match secret_commitment {
+            Some(secret_commitment) => {
+                storage::set_payer_memo_commitment(&env, &commitment, &secret_commitment)
+            }
+            None => storage::remove_payer_memo_commitment(&env, &commitment),
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (payer_memo_commitment_set_event(&env),),
+            (commitment, derived),
+        );
+    }
+
+    /// Resolves a commitment for a specific payer: the primary wallet plus the payer's
+    /// deposit memo, assigned on first use and returned unchanged afterwards.
+    ///
+    /// The wallet follows [`Resolver::resolve`]. The memo is returned to the
+    /// authenticated payer whatever the memo visibility, since it identifies the payer
+    /// only to the merchant.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `payer`: The paying account. Must be authorized.
+    ///
+    /// ### Returns
+    /// A tuple of `(Address, u64)` with the wallet and the payer's memo.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment does not resolve.
+    ///
+    /// ### Events
+    /// - Emits `PAYER_MEMO` event with (commitment, memo) when a memo is first assigned.
+    pub fn resolve_for_payer(env: Env, commitment: BytesN<32>, payer: Address) -> (Address, u64) {
+        payer.require_auth();
+        let (wallet, _) = Resolver::resolve(env.clone(), commitment.clone());
+        let epoch = storage::get_payer_memo_epoch(&env, &commitment);
+        if let Some(memo) = storage::get_payer_memo(&env, &commitment, epoch, &payer) {
+            return (wallet, memo);
+        }
+
+        let memo = Self::next_memo(&env, &commitment, epoch, &payer);
+        storage::set_payer_memo(&env, &commitment, epoch, &payer, memo);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((payer_memo_assigned_event(&env),), (commitment, memo));
+        (wallet, memo)
+    }
+
+    /// Returns the payer a memo was assigned to, for reconciling incoming payments.
+    ///
+    /// Anyone may look up memos while the memo field is `Public`; otherwise the owner
+    /// must authorize the call.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `memo`: A memo returned by [`Self::resolve_for_payer`].
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    pub fn memo_payer(env: Env, commitment: BytesN<32>, memo: u64) -> Option<Address> {
+        let owner = Registration::get_owner(env.clone(), commitment.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if Privacy::policy(&env, &commitment).memo != Visibility::Public {
+            owner.require_auth();
+        }
+        let epoch = storage::get_payer_memo_epoch(&env, &commitment);
+        storage::get_memo_payer(&env, &commitment, epoch, memo)
+    }
+
+    /// (Internal) Invalidates every payer memo of a commitment and its secret commitment,
+    /// so a new owner never inherits the previous owner's customers.
+    pub(crate) fn clear(env: &Env, commitment: &BytesN<32>) {
+        let epoch = storage::get_payer_memo_epoch(env, commitment);
+        storage::set_payer_memo_epoch(env, commitment, epoch.wrapping_add(1));
+        storage::remove_payer_memo_commitment(env, commitment);
+        storage::remove_payer_memo_counter(env, commitment);
+    }
+
+    /// (Internal) Picks the first unassigned memo for `payer`, derived from the secret
+    /// commitment when one is set and taken from the counter otherwise.
+    fn next_memo(env: &Env, commitment: &BytesN<32>, epoch: u32, payer: &Address) -> u64 {
+        let taken = |memo: u64| storage::get_memo_payer(env, commitment, epoch, memo).is_some();
+        match storage::get_payer_memo_commitment(env, commitment) {
+            Some(secret_commitment) => {
+                let mut seed = Bytes::from_array(env, &secret_commitment.to_array());
+                seed.append(&payer.clone().to_xdr(env));
+                loop {
+                    let digest = env.crypto().sha256(&seed).to_array();
+                    let mut head = [0u8; 8];
+                    head.copy_from_slice(&digest[..8]);
+                    let memo = u64::from_be_bytes(head);
+                    if !taken(memo) {
+                        return memo;
+                    }
+                    seed = Bytes::from_array(env, &digest);
+                }
+            }
+            None => {
+                let mut memo = storage::get_payer_memo_counter(env, commitment);
+                loop {
+                    memo += 1;
+                    if !taken(memo) {
+                        storage::set_payer_memo_counter(env, commitment, memo);
+                        return memo;
+                    }
+                }
+            }
+        }
+    }
+}
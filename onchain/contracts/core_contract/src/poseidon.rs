@@ -0,0 +1,3644 @@
+//! Poseidon hash over the BN254 scalar field, matching circomlib's `Poseidon(n)`.
+//!
+//! Soroban has no Poseidon or BN254 host functions, so the field arithmetic is done in
+//! the contract. Only the widths used by `zk/circuits/username_hash.circom` are
+//! supported: `Poseidon(2)` (`t = 3`, `R_P = 57`) and `Poseidon(4)` (`t = 5`, `R_P = 60`),
+//! both with `R_F = 8` and the `x^5` S-box.
+//!
+//! The constants come from the reference Grain LFSR parameter generation and equal
+//! circomlib's. They are stored in Montgomery form (`x * 2^256 mod r`).
+
+/// The BN254 scalar field modulus `r`, little-endian limbs.
+const MODULUS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// `-r^-1 mod 2^64`, for Montgomery reduction.
+const INV: u64 = 0xc2e1f593efffffff;
+
+/// `2^512 mod r`, for converting into Montgomery form.
+const R2: Fr = Fr([
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+]);
+
+/// Number of full rounds, split evenly before and after the partial rounds.
+const FULL_ROUNDS: usize = 8;
+
+/// A BN254 scalar field element in Montgomery form, little-endian limbs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Fr([u64; 4]);
+
+impl Fr {
+    /// The additive identity.
+    const ZERO: Fr = Fr([0; 4]);
+
+    /// Converts a small integer into the field.
+    pub(crate) fn from_u64(value: u64) -> Fr {
+        Fr([value, 0, 0, 0]).mul(R2)
+    }
+
+    /// Encodes the element as 32 big-endian bytes of its canonical value.
+    pub(crate) fn to_bytes_be(self) -> [u8; 32] {
+        let canonical = self.mul(Fr([1, 0, 0, 0])).0;
+        let mut out = [0u8; 32];
+        for (i, limb) in canonical.iter().rev().enumerate() {
+            out[i * 8..i * 8 + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    /// Returns `self + rhs`.
+    fn add(self, rhs: Fr) -> Fr {
+        let mut out = [0u64; 4];
+        let mut carry = 0u128;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let sum = u128::from(self.0[i]) + u128::from(rhs.0[i]) + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        // Both inputs are below r < 2^254, so the sum never carries out of 256 bits.
+        Fr(out).reduce()
+    }
+
+    /// Returns `self * rhs` using CIOS Montgomery multiplication.
+    fn mul(self, rhs: Fr) -> Fr {
+        let mut t = [0u64; 6];
+        for &b in rhs.0.iter() {
+            let mut carry = 0u128;
+            for (j, &a) in self.0.iter().enumerate() {
+                let v = u128::from(t[j]) + u128::from(a) * u128::from(b) + carry;
+                t[j] = v as u64;
+                carry = v >> 64;
+            }
+            let v = u128::from(t[4]) + carry;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let m = t[0].wrapping_mul(INV);
+            let mut carry = (u128::from(t[0]) + u128::from(m) * u128::from(MODULUS[0])) >> 64;
+            for j in 1..4 {
+                let v = u128::from(t[j]) + u128::from(m) * u128::from(MODULUS[j]) + carry;
+                t[j - 1] = v as u64;
+                carry = v >> 64;
+            }
+            let v = u128::from(t[4]) + carry;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+        Fr([t[0], t[1], t[2], t[3]]).reduce()
+    }
+
+    /// Returns `self^5`, the Poseidon S-box.
+    fn pow5(self) -> Fr {
+        let sq = self.mul(self);
+        sq.mul(sq).mul(self)
+    }
+
+    /// Subtracts the modulus once if `self >= r`.
+    fn reduce(self) -> Fr {
+        let below = self
+            .0
+            .iter()
+            .rev()
+            .zip(MODULUS.iter().rev())
+            .find(|(a, m)| a != m)
+            .is_some_and(|(a, m)| a < m);
+        if below {
+            return self;
+        }
+        let mut out = [0u64; 4];
+        let mut borrow = false;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let (d, b1) = self.0[i].overflowing_sub(MODULUS[i]);
+            let (d, b2) = d.overflowing_sub(u64::from(borrow));
+            *limb = d;
+            borrow = b1 || b2;
+        }
+        Fr(out)
+    }
+}
+
+/// Hashes two field elements, as circomlib's `Poseidon(2)`.
+pub(crate) fn hash2(inputs: [Fr; 2]) -> Fr {
+    permute(
+        [Fr::ZERO, inputs[0], inputs[1]],
+        &ROUND_CONSTANTS_3,
+        &MDS_3,
+        57,
+    )
+}
+
+/// Hashes four field elements, as circomlib's `Poseidon(4)`.
+pub(crate) fn hash4(inputs: [Fr; 4]) -> Fr {
+    permute(
+        [Fr::ZERO, inputs[0], inputs[1], inputs[2], inputs[3]],
+        &ROUND_CONSTANTS_5,
+        &MDS_5,
+        60,
+    )
+}
+
+/// Computes the username commitment of `zk/circuits/username_hash.circom`.
+///
+/// `username` holds one character per byte, zero-padded to 32 bytes. Returns the
+/// commitment as 32 big-endian bytes.
+pub(crate) fn username_hash(username: &[u8; 32]) -> [u8; 32] {
+    let mut chunks = [Fr::ZERO; 8];
+    for (chunk, bytes) in chunks.iter_mut().zip(username.chunks_exact(4)) {
+        *chunk = hash4([
+            Fr::from_u64(u64::from(bytes[0])),
+            Fr::from_u64(u64::from(bytes[1])),
+            Fr::from_u64(u64::from(bytes[2])),
+            Fr::from_u64(u64::from(bytes[3])),
+        ]);
+    }
+    let left = hash4([chunks[0], chunks[1], chunks[2], chunks[3]]);
+    let right = hash4([chunks[4], chunks[5], chunks[6], chunks[7]]);
+    hash2([left, right]).to_bytes_be()
+}
+
+/// (Internal) Runs the permutation on `state` and returns its first element.
+fn permute<const T: usize>(
+    mut state: [Fr; T],
+    round_constants: &[Fr],
+    mds: &[[Fr; T]; T],
+    partial_rounds: usize,
+) -> Fr {
+    let half = FULL_ROUNDS / 2;
+    for (round, constants) in round_constants
+        .chunks_exact(T)
+        .take(FULL_ROUNDS + partial_rounds)
+        .enumerate()
+    {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x = x.add(*c);
+        }
+        if round < half || round >= half + partial_rounds {
+            for x in state.iter_mut() {
+                *x = x.pow5();
+            }
+        } else {
+            state[0] = state[0].pow5();
+        }
+        let mut mixed = [Fr::ZERO; T];
+        for (out, row) in mixed.iter_mut().zip(mds) {
+            for (m, x) in row.iter().zip(state.iter()) {
+                *out = out.add(m.mul(*x));
+            }
+        }
+        state = mixed;
+    }
+    state[0]
+}
+
+/// Round constants for `t = 3`, `(R_F + R_P) * t` of them in round order.
+const ROUND_CONSTANTS_3: [Fr; 195] = [
+    Fr([
+        0x83bbbac36534a858,
+        0x0971619601250a07,
+        0x27a219050e23910c,
+        0x1638bf18c8507442,
+    ]),
+    Fr([
+        0x5e2bb0e6bb25631a,
+        0x56b042b8def5875e,
+        0x01bc3e10a8e9b451,
+        0x17bfe16092ef1d7e,
+    ]),
+    Fr([
+        0xa4fef9b954508d89,
+        0xafeb6b7d4ee4c69e,
+        0xd8ebe8a7fc301c39,
+        0x1840976e07ad780c,
+    ]),
+    Fr([
+        0x18b8e553a04b72cb,
+        0x4d654fb8ec90df10,
+        0x3f8dafc39d1b9658,
+        0x1a72c6a50e4e6a8a,
+    ]),
+    Fr([
+        0x44f18fdd12c7177a,
+        0x43d28a9b520bd0ee,
+        0x440b83130192f008,
+        0x13c53f3b277b2fde,
+    ]),
+    Fr([
+        0xfc116b6c9e33b756,
+        0x933e67d042eb5320,
+        0xabc0d9f2c9d9bdcf,
+        0x23b0bd1fabcb64b0,
+    ]),
+    Fr([
+        0xde8ebdf8535243af,
+        0x1e851377b3d81387,
+        0xdfa4b107ef127fb9,
+        0x1e12da839a042314,
+    ]),
+    Fr([
+        0xe25ff0a9dd264f6b,
+        0x0215e509d49d08b2,
+        0xc2cf093542425093,
+        0x0baaf53d85cb02e0,
+    ]),
+    Fr([
+        0x6eaa462f90cf8115,
+        0xaf07e5d221dd2e13,
+        0x9a0b65abec88aa63,
+        0x1fcea175624b262f,
+    ]),
+    Fr([
+        0x99aa70f04fe89c1e,
+        0xdc11129d80826695,
+        0x1a68006fcc353aee,
+        0x299ea7eabe5d4996,
+    ]),
+    Fr([
+        0x413df2c8c5541138,
+        0xeb469618c9de6bb3,
+        0x1a668e52b76fe275,
+        0x0fed7116657d8da5,
+    ]),
+    Fr([
+        0x4c5cd4085911f6df,
+        0xa69c3b9c95ae98e6,
+        0xf308d88c93d19608,
+        0x079c79c9cffd5ad4,
+    ]),
+    Fr([
+        0xdd5d50a6115b6ae6,
+        0x3e2079ce31994482,
+        0x0beac0a93142d7a6,
+        0x29838a07888323b4,
+    ]),
+    Fr([
+        0x3031a7c21bf4f9f8,
+        0xf093c1cc653a991a,
+        0x092a0cd128aac7c2,
+        0x1e230e4aa7c4c266,
+    ]),
+    Fr([
+        0x2a72b06d29407105,
+        0x281dbf7148ac7771,
+        0x175c5083bc22a44d,
+        0x08c912947cf53b92,
+    ]),
+    Fr([
+        0x7eb180a889ee3c2d,
+        0xd38343a986d44c8c,
+        0x286bdbe0472b1d0e,
+        0x24f6be203117cc53,
+    ]),
+    Fr([
+        0xe221229550b8eb66,
+        0x9ffe52fabfc796eb,
+        0x1b9eef2ed361ed14,
+        0x0c575104fa7034f4,
+    ]),
+    Fr([
+        0xf9b28f842a2e4643,
+        0x4d5c080bd2ca1317,
+        0xcd76ae812c271eb9,
+        0x0ea4878f3b96e943,
+    ]),
+    Fr([
+        0x1b12a0ead7ee00cd,
+        0x794461ec0d8acd32,
+        0x3abb3e24c75e20a7,
+        0x27cb85854f2c16db,
+    ]),
+    Fr([
+        0xc34a0ce29e5c844a,
+        0xbae3900198f23f61,
+        0xf582e09985e6d0c4,
+        0x055c6d26a35cf2de,
+    ]),
+    Fr([
+        0x33e1751f74f74c21,
+        0xdef83f5279f2ec1a,
+        0x3ce7d0bd374490ba,
+        0x0eecc29342805119,
+    ]),
+    Fr([
+        0xe45772c6259d3e58,
+        0x4c683f93ebeab683,
+        0xfb8c13b607a4642e,
+        0x2e3e8ef03eeaed41,
+    ]),
+    Fr([
+        0xd197c44c630c5eb4,
+        0x053857ab70186b79,
+        0x18ee44c7bdb0d26a,
+        0x18b94e2583a0557b,
+    ]),
+    Fr([
+        0xd27736b000acdc77,
+        0x258b4b227cbcb7f5,
+        0x40df2e84269de616,
+        0x209aa4bbc966b500,
+    ]),
+    Fr([
+        0x5aafe5dc0720254f,
+        0x0e91a580f18d1016,
+        0x11a00abff154d5b7,
+        0x28083140319b0d09,
+    ]),
+    Fr([
+        0x624ed13efd7f2bca,
+        0xcc9e53bcf2c28b13,
+        0x7ca39c04d885ccfa,
+        0x032c7e9c1ed0d643,
+    ]),
+    Fr([
+        0xbee575ac208c3258,
+        0x8976d09923790363,
+        0xeac3c4d39b58eaaf,
+        0x28585586ade6e3d5,
+    ]),
+    Fr([
+        0x275e64927c987248,
+        0x1023453306381ce9,
+        0xd90511a7cb2159f6,
+        0x2997881a59a4151b,
+    ]),
+    Fr([
+        0x96ea7b645b7ee005,
+        0x12e49ee4759a80d5,
+        0x3ff0710c0c7c209c,
+        0x1207d4f65a29a973,
+    ]),
+    Fr([
+        0x225570d6a943095f,
+        0xa11ab5832d54aa69,
+        0x5ed544b9dc72feac,
+        0x16ba83b29fa0dfde,
+    ]),
+    Fr([
+        0xd35fe784498f8347,
+        0x448e2c74dc5c310b,
+        0x8bc35d4158df81d3,
+        0x11a01d27b058a3b8,
+    ]),
+    Fr([
+        0xcf4dac5594af2d83,
+        0xc6f6531b8cc21243,
+        0x6e65adc157b9548f,
+        0x082a1067f00d6dfb,
+    ]),
+    Fr([
+        0x173fc30b103a6dcf,
+        0xc91b63e498d301c3,
+        0xb8f7ea4064eea1f2,
+        0x06548827d7df35e4,
+    ]),
+    Fr([
+        0xf1dcdd1866e91665,
+        0xaf44edb31bfcc76e,
+        0x69a2f851bc5fdeb1,
+        0x16a3add7fd6847e5,
+    ]),
+    Fr([
+        0x277fa19569af0ab3,
+        0x28e4a94421550cd3,
+        0x8b1893dc1510def2,
+        0x152b3eebf2e3b9af,
+    ]),
+    Fr([
+        0x82a43eaade461743,
+        0x4bd307501a719bfd,
+        0x94e914f0f35fb5ea,
+        0x2d45e6c07093f3ec,
+    ]),
+    Fr([
+        0x1307c7dfcdec9d68,
+        0x569ed7de9138f494,
+        0x3345109c6374a0b6,
+        0x21b5a1904b9d9fa1,
+    ]),
+    Fr([
+        0xeb775b5909a98dc7,
+        0x54a57335128a23bf,
+        0x5b11e52888015162,
+        0x212a6cfdd608fde8,
+    ]),
+    Fr([
+        0x4c04265f18d146f1,
+        0x10d10bc4c7853d4e,
+        0xa8e2a51db14f2f2c,
+        0x0f5fd95645106055,
+    ]),
+    Fr([
+        0x68dd43d7b51c37dd,
+        0x1e46476089e1d073,
+        0x58e04cf38f805754,
+        0x0b0bbf5b8688b08b,
+    ]),
+    Fr([
+        0x1b14f57c4122a309,
+        0xe02913572e132548,
+        0x8d3c17048b7c32db,
+        0x22434cd0616dbf37,
+    ]),
+    Fr([
+        0xa0a5d482a6154db8,
+        0xd1c8cb01ba422f45,
+        0xb8a57919e05a3d45,
+        0x285a24a238af96cb,
+    ]),
+    Fr([
+        0x1aaa1405fafbd364,
+        0xb8d8b685230034b7,
+        0x0b7aef992fcc0854,
+        0x28d85f4b9f36af66,
+    ]),
+    Fr([
+        0x21fc148d2efde136,
+        0xc0a3008d29eeb0a8,
+        0xf3914ca16745a3c9,
+        0x0856523236dd2341,
+    ]),
+    Fr([
+        0xa14a336112fbf9f3,
+        0xb1ff289ba2eae970,
+        0x32a2feccaf57f78d,
+        0x003726ecc0267f6c,
+    ]),
+    Fr([
+        0x65e4051e623a8231,
+        0x3de6d47d48c406d3,
+        0xa9bbd406bdb8ead2,
+        0x0d600d159a165a0c,
+    ]),
+    Fr([
+        0x50648feb424d5e04,
+        0x78694de6ef267969,
+        0x5aa8b3580718f950,
+        0x1b044d4e05de9b70,
+    ]),
+    Fr([
+        0x2a796e5ea8f4e119,
+        0x946bbb44d739483a,
+        0xe21604d22b7ff1bb,
+        0x0c4327027869e663,
+    ]),
+    Fr([
+        0xe85e117615b09542,
+        0x96ce582e170967b3,
+        0x065b39840af36d54,
+        0x11b11f0e477f05b0,
+    ]),
+    Fr([
+        0x0d74f0994cb254bf,
+        0x4f4e4871fc63bd6d,
+        0x89d7b727ebdad559,
+        0x2cf20283390eb822,
+    ]),
+    Fr([
+        0xa30b526823bdb058,
+        0x886bb7b6f8eccd0d,
+        0x1f437e6dbc295914,
+        0x29ad2879e6833325,
+    ]),
+    Fr([
+        0xec3b39410fe43a5d,
+        0x3fb9d90ddcfa176a,
+        0x5fcaa229a47556d0,
+        0x07a583162e51b6d8,
+    ]),
+    Fr([
+        0x5b3fe2630608396e,
+        0x1e4496112e954403,
+        0x855a9daaa37c42a9,
+        0x10f643f4b4fa5128,
+    ]),
+    Fr([
+        0x28590d0004f98e35,
+        0xe13a159fc2cc8cf6,
+        0x8d22be85be68317a,
+        0x085a637b8618db96,
+    ]),
+    Fr([
+        0x5588c6954188d4d5,
+        0x28bdcaed360f0373,
+        0x67cc7a411974cf44,
+        0x27ab756e2cc849ac,
+    ]),
+    Fr([
+        0x32a0c18f088e99ad,
+        0x22795c50dd9cc1f3,
+        0x39c0855c6dc26a51,
+        0x12388feb2e428a82,
+    ]),
+    Fr([
+        0xaddbd56d19cb859c,
+        0x2f7ceda49032fb62,
+        0xebdf3719874c459e,
+        0x036d7aa8bc44149d,
+    ]),
+    Fr([
+        0x2caa41d785b941c8,
+        0x971e4e4c154f2c6b,
+        0x7874a8d468e0e6c8,
+        0x07b4735d54f5d234,
+    ]),
+    Fr([
+        0x01f51a3aa044c8f2,
+        0x7a636b4bfcab2c29,
+        0xb31eb377a96a00ce,
+        0x0f0c53f00bb104ac,
+    ]),
+    Fr([
+        0xd79328003e9566e7,
+        0xed5f36c07d3074ba,
+        0x8a2da6c60a9e4cb8,
+        0x28bd60c78f4c27c2,
+    ]),
+    Fr([
+        0xfffebd8720631bbc,
+        0x8efe2a4219d8aba5,
+        0x6bdf4ded7ef1b62d,
+        0x1ff6c9e06d6cf68e,
+    ]),
+    Fr([
+        0x22a696d457b8106d,
+        0x8b98ffed5d4905f8,
+        0x5edf9329bbef0232,
+        0x1392824c46ea4c39,
+    ]),
+    Fr([
+        0xab107cfef787c8a0,
+        0x278e7c4207fb3230,
+        0x99b9ea6ce46d8256,
+        0x0d682fd0fb31a00e,
+    ]),
+    Fr([
+        0x4ecef90fb11c850c,
+        0x84e45781c9eec3a9,
+        0x8ec25128e2f1f4f8,
+        0x2a2c1a8412a70cce,
+    ]),
+    Fr([
+        0x8f369a4d332e2cc7,
+        0xb22c513234251590,
+        0x706ac3aeb811231f,
+        0x19740e5e8ade8979,
+    ]),
+    Fr([
+        0x221080f029f28b75,
+        0xa437450974cb1e94,
+        0x87926f33c69f3da2,
+        0x029cf616ee2ad166,
+    ]),
+    Fr([
+        0x02442c4fee11e91d,
+        0x5dc410e07fdcbe17,
+        0x1a867232b63d9457,
+        0x1a1c9a07b2782748,
+    ]),
+    Fr([
+        0x1edb82dae474f114,
+        0xd3bc7df0735a0a00,
+        0x03b3817d280da899,
+        0x0701ed4f517f4c79,
+    ]),
+    Fr([
+        0xe14d649a6bf08497,
+        0xa3ea3ec2fba171b1,
+        0x8f61083e65c736bd,
+        0x1ac1a9fdf38c7ebb,
+    ]),
+    Fr([
+        0xcf49198274ef25d7,
+        0x562c05e01036644e,
+        0x3f41976e276e258c,
+        0x1f5be530194ef994,
+    ]),
+    Fr([
+        0x82c0529d3b0c6560,
+        0xce8537e7cb3d788c,
+        0x92494f9d3bd8e8bc,
+        0x132c81f43fd33e53,
+    ]),
+    Fr([
+        0x3f40cc1629cd3111,
+        0xb9f9665b8d257da8,
+        0x185f61f8e8e16198,
+        0x021adba7bd33ab21,
+    ]),
+    Fr([
+        0x342f2a113fd36130,
+        0x9ceccbd5349c530a,
+        0x617bd57533444841,
+        0x1648cdf733eee1d3,
+    ]),
+    Fr([
+        0x9efbd552827fcf73,
+        0xfaebeb1c6ab69375,
+        0x464172e7be8e88a8,
+        0x0847931e0d042c0f,
+    ]),
+    Fr([
+        0x120193648a28dc6c,
+        0x988a6302e7370526,
+        0xa78edb0d97474b0c,
+        0x19959641a1b547fa,
+    ]),
+    Fr([
+        0x8e6dfb112427c2d2,
+        0x20c25819e588e384,
+        0x1d88377d30897cb6,
+        0x2a508af373b9729a,
+    ]),
+    Fr([
+        0x1d14c873ddc51680,
+        0x0e0da86908009656,
+        0xbf4ec9ba1340d6a4,
+        0x0098bdf65a0afb9c,
+    ]),
+    Fr([
+        0x50d73614f3243e60,
+        0x45209e69e50c537e,
+        0xbf44d99fc759be13,
+        0x279f34df0724a583,
+    ]),
+    Fr([
+        0x219cffe63c3aa682,
+        0xa6dd59c7425acf48,
+        0x444cb706acccec8f,
+        0x2e8c17deff5b9dd4,
+    ]),
+    Fr([
+        0x9997c91436bf5d0b,
+        0x1629fb48677678be,
+        0x49c4d97bdb9eb29a,
+        0x2035dd09daa0d61e,
+    ]),
+    Fr([
+        0x10168e7c8e51546b,
+        0x507ec1516ab22d0c,
+        0x1a194fcaa4bf09c0,
+        0x0c637dc86849f4fd,
+    ]),
+    Fr([
+        0x57fe04691ea6f531,
+        0x2190682e353d037b,
+        0x72e47ef9e904d3b9,
+        0x211f475dfb3c75ed,
+    ]),
+    Fr([
+        0x7e838b86503617d0,
+        0xe6bad1948ae1955e,
+        0x707e58fad5b30483,
+        0x0a0972982b764b31,
+    ]),
+    Fr([
+        0x03bb8a08fc34185c,
+        0x7697ad2d4d810bfd,
+        0xfd12136fc816f501,
+        0x120d076c88279b56,
+    ]),
+    Fr([
+        0xee24105eeb22800c,
+        0xb7cbe7af82283d5e,
+        0x6191e1e38cd71bb5,
+        0x1aeaf2e491efd9a9,
+    ]),
+    Fr([
+        0xa04e07018cc9237a,
+        0x5361733b3151e0b6,
+        0x40e61ee38ddf97c3,
+        0x227af916f8b30fd4,
+    ]),
+    Fr([
+        0x3a89c2121dbc8729,
+        0xbc8fbdb3d8db1a87,
+        0xe86e48af8d840b86,
+        0x2ce7a4cb0acf31c8,
+    ]),
+    Fr([
+        0x5034a090dc3af464,
+        0x6ed46f82617008aa,
+        0x358e71ffd819f7e3,
+        0x26c8080ae116e13b,
+    ]),
+    Fr([
+        0x788952cfb8b52e37,
+        0xefc72a5be7fb7fbc,
+        0xc77bac4f9459447a,
+        0x001040af8a6d261e,
+    ]),
+    Fr([
+        0x16e6d600d2ff7426,
+        0xca5f5816acfc9401,
+        0xe38beefe9a0e0072,
+        0x21938b191a7bc331,
+    ]),
+    Fr([
+        0xd5b44d21a21f9297,
+        0x86167973aa7d0743,
+        0x79bd6f4550d4e54f,
+        0x03cfdb40ac6940ca,
+    ]),
+    Fr([
+        0x9c929ba1d69d55fc,
+        0x87d64dfc151271ae,
+        0x2d27e3179fac9536,
+        0x1a90b4df9101e879,
+    ]),
+    Fr([
+        0x7499664fc0ebe7ec,
+        0x09716f8e84931303,
+        0x4cc0dccb94634b45,
+        0x1a32f5ff8519e33c,
+    ]),
+    Fr([
+        0x870877b73b9bcaa9,
+        0x1881870f75379e4f,
+        0x9f3f06e451a7e672,
+        0x1151fe3bb41dcb29,
+    ]),
+    Fr([
+        0x0b7cb1a81cac0430,
+        0x271f481c441f88f9,
+        0xeda16949984a6294,
+        0x073cc8fe043bb91c,
+    ]),
+    Fr([
+        0x5d4c4bfe348cbfe4,
+        0xcfd654c4476c9489,
+        0xdd48f53cf0b1efcd,
+        0x06c975ea69621011,
+    ]),
+    Fr([
+        0x39fb6c74184c9024,
+        0x80e79b39a143fe21,
+        0xb1253d509d48cbfc,
+        0x1a4900be8c52124d,
+    ]),
+    Fr([
+        0x190a59d14d51b5c0,
+        0xa444e21ad8bdb73a,
+        0xb2259807fe061798,
+        0x271a2e9bbfcf2f6a,
+    ]),
+    Fr([
+        0xc0af13dc96417133,
+        0x1cb1c8979c88420d,
+        0x353f8e5673b9f841,
+        0x0ad2874818ce1183,
+    ]),
+    Fr([
+        0x5eae10d5fd0f7d29,
+        0x7da0cc2beaa853e4,
+        0xb844b04ad2a3b6e2,
+        0x0b184f0b40c038ee,
+    ]),
+    Fr([
+        0xe5e995b4b1d54591,
+        0x19548f28b12e5099,
+        0xe0da5976f40f1c72,
+        0x1c55359d2d014456,
+    ]),
+    Fr([
+        0x68c411465f6f87d8,
+        0xa3a18882c980c957,
+        0x5c79518c4fabcf20,
+        0x26edc29497bc5cb3,
+    ]),
+    Fr([
+        0xb50f63c3948b6732,
+        0x9c160bd71ae1dbb4,
+        0x24cfd9385abff66a,
+        0x02063a7ceced9ea6,
+    ]),
+    Fr([
+        0xdb0cbc35d22245b0,
+        0xfe86319a55232e6d,
+        0x6105fbfdf3add538,
+        0x2c2dc27c38d94255,
+    ]),
+    Fr([
+        0xf1895d988a8c8995,
+        0xdedefbad0c1b5a82,
+        0x5f3fb8bc0bd416d8,
+        0x0e2a4c7d21d78dc2,
+    ]),
+    Fr([
+        0x72f59f40b87cd518,
+        0x619b20e02d8b3ee3,
+        0xd6ebb896f7d9329f,
+        0x211c1fdf6141dd3a,
+    ]),
+    Fr([
+        0xd6e372c64f54c992,
+        0x99827b2bf785abe0,
+        0xb67492d94fbeae14,
+        0x0288174a915f1cb7,
+    ]),
+    Fr([
+        0x0cee89601a4ad1c5,
+        0x66dee7acd570e8b4,
+        0x237331b25471fc2c,
+        0x0f79a3dc068020d0,
+    ]),
+    Fr([
+        0x898fee0b8f31bc12,
+        0x5c8954aa2da1961c,
+        0x77326678c3da12af,
+        0x144313217a7f389f,
+    ]),
+    Fr([
+        0x05f342cad3b4864a,
+        0xe2c7d39d0656a010,
+        0x61b8bef5da62a23f,
+        0x195806ad7b6696ef,
+    ]),
+    Fr([
+        0x76c6fbf50cbdef02,
+        0xf535ea975a6b40f4,
+        0x0c5fe2383fe86bce,
+        0x117abeb34b8688b9,
+    ]),
+    Fr([
+        0x722fcd9132e35924,
+        0x8d991c0f2fa40665,
+        0xab060365a33bc385,
+        0x0b61468d5f2060aa,
+    ]),
+    Fr([
+        0xf37de77a8f1be22a,
+        0x427159a97577781e,
+        0x14e99813266148d9,
+        0x138e8d0f0e5be0c4,
+    ]),
+    Fr([
+        0xe1543733c9bb6fc7,
+        0xd8c9704535e21c8c,
+        0xd433edc282de438c,
+        0x11466ae0f81b3763,
+    ]),
+    Fr([
+        0x2cbd8cdb39524874,
+        0xf9855b644afce00e,
+        0xb290cb62abfbd66c,
+        0x25acd478b1084494,
+    ]),
+    Fr([
+        0x8ebc6672b41dcee8,
+        0x606f0745849196a1,
+        0xe55ae090bd88d82a,
+        0x009470554167249d,
+    ]),
+    Fr([
+        0x062094bcc7a6418b,
+        0x2803495b8583c637,
+        0x53eb48b97437c010,
+        0x0c7a1222e9138165,
+    ]),
+    Fr([
+        0x27eab79c36018a4e,
+        0xbd1864c1d0c4068d,
+        0xf902113a25a237e3,
+        0x128d87abeb1e9c1f,
+    ]),
+    Fr([
+        0xdb7fc417f7652d14,
+        0x58bd4f3aaac09e67,
+        0x728f58664d60e85c,
+        0x0f85a634b96fdda0,
+    ]),
+    Fr([
+        0xe32fe000e302eadc,
+        0x7d23c3dd3c7df083,
+        0x5bd45107b443a012,
+        0x2acca27428d1a73c,
+    ]),
+    Fr([
+        0xbf727ded10944264,
+        0x4ebf249fe7df1f42,
+        0x9ae7e1ea5448317c,
+        0x098478d1386f06d1,
+    ]),
+    Fr([
+        0x41702faeb62c6d90,
+        0x5dea60565d31eda6,
+        0x99144c98f362ec45,
+        0x0666d88648f73f04,
+    ]),
+    Fr([
+        0x83b3ca7e199d699c,
+        0x32ebc3b0c43d5f81,
+        0x14a61edd1a1d5f6b,
+        0x2cb5c8fea1103978,
+    ]),
+    Fr([
+        0x5d05e3ae54a68bc4,
+        0x4faa9d463460a6f2,
+        0x7f3ed5cd074f3aaf,
+        0x2d280f20dae67de3,
+    ]),
+    Fr([
+        0xd2efe4ca6dada240,
+        0x85c3424c91e6e450,
+        0x67ded928e819912f,
+        0x2a47110115ad187e,
+    ]),
+    Fr([
+        0xfd689c88325914ba,
+        0x217ccba0c4f678a8,
+        0x775c3aa0caac332b,
+        0x0237eeffadb3dc0b,
+    ]),
+    Fr([
+        0x951cba230d48ab5a,
+        0xac60fef56f7dcd80,
+        0xee0e118c667a107a,
+        0x286963de12abebeb,
+    ]),
+    Fr([
+        0xd5bf474c37545102,
+        0x8df0210724a7bbf7,
+        0x2869e00fd06cee5c,
+        0x10a5a9a9e9679a80,
+    ]),
+    Fr([
+        0xb756d548a7c1fc31,
+        0x91a8ff87ec937a72,
+        0xc052832f337e9900,
+        0x0529844a975226c0,
+    ]),
+    Fr([
+        0xd6bd6219ea723ea7,
+        0xb15ef75a47a73dbc,
+        0xce0d24f3bcdba423,
+        0x00f2c9c00b75da95,
+    ]),
+    Fr([
+        0x8839b8e43934561b,
+        0xeed5c479997614af,
+        0x8e13af4ce37ec975,
+        0x1c11b1381a0da7d1,
+    ]),
+    Fr([
+        0xf38903221b52a70d,
+        0x57941bad5616f0b4,
+        0x79e0e60ac24d695a,
+        0x28196a6e4dc51202,
+    ]),
+    Fr([
+        0x064ebe0c3dd24eb6,
+        0x3802fd84feb04f9d,
+        0x86b2d2b0907fa843,
+        0x2c574ff686c617aa,
+    ]),
+    Fr([
+        0x4350bfc9d26b3c37,
+        0x21c07f7c582555a7,
+        0xc26f0a1a39e61d98,
+        0x23e04b5b20773885,
+    ]),
+    Fr([
+        0x9ecdf0801c4960f1,
+        0x90455ea0aa5c29bd,
+        0x7a4f7128a78bbda6,
+        0x08a72f2200068a14,
+    ]),
+    Fr([
+        0x8e78423055561239,
+        0xc68657f8b6a9b0c3,
+        0x3c1f30023e9a9859,
+        0x020a5307957c386b,
+    ]),
+    Fr([
+        0xc2b2ec89df413870,
+        0x698417646a3cbfa5,
+        0x85b88d3108e46241,
+        0x1153cf64cd3c1731,
+    ]),
+    Fr([
+        0xd3c742f41b1facfd,
+        0x48cc11f7ce5600d8,
+        0xd924a20425ad06ca,
+        0x2b92d896b64a36f0,
+    ]),
+    Fr([
+        0x47e3641e16232e80,
+        0xb5b83d38f4118ce6,
+        0x9c757d818e9fe957,
+        0x0f040021849b01ec,
+    ]),
+    Fr([
+        0xbf3cd76e39709602,
+        0xe2d93e6cf717615f,
+        0x4e5f8607aa4aa483,
+        0x1fcceb2264ae4e31,
+    ]),
+    Fr([
+        0xcad7b25f437a38d9,
+        0xf66dcd4743bd617a,
+        0x75627910ca985f41,
+        0x1c2d962253a27827,
+    ]),
+    Fr([
+        0x3eca0791994cce65,
+        0x059234b17e8476d9,
+        0x8403f0cd92adc262,
+        0x2116c5244c9f73b9,
+    ]),
+    Fr([
+        0x7ebcbcd0ece065e3,
+        0xc1a06905a0139ff9,
+        0xf0e0a214eafcce74,
+        0x102221a24bb3f1ad,
+    ]),
+    Fr([
+        0xc73946d2fd0793c6,
+        0xd684e968a7714ca9,
+        0x4516892656a0badd,
+        0x2bb1a1d769bcf7e8,
+    ]),
+    Fr([
+        0x6b45148d7b52e3c9,
+        0x030bfb6edf8c0734,
+        0x78374b448edd38c4,
+        0x05ba2d1f46a5b689,
+    ]),
+    Fr([
+        0x96a850062bca34d6,
+        0x1af76c923852014e,
+        0xad93ce5cfda5d027,
+        0x1c592caf1ab3d348,
+    ]),
+    Fr([
+        0xafbb1743f0bb4932,
+        0x6a7696e10bda8c0c,
+        0x07a07831609bf58e,
+        0x2d35e80598271115,
+    ]),
+    Fr([
+        0xdc24c33ffdafcabc,
+        0xf4dd4a38331852d4,
+        0x5ed1b79ac97e8d89,
+        0x07fcc7dca8d2fa83,
+    ]),
+    Fr([
+        0xe41041004d1b83f0,
+        0x09933bdb3497d06a,
+        0xa3a268100bbdd441,
+        0x2dacd4f6ccf638f4,
+    ]),
+    Fr([
+        0x41e818cce281e4e9,
+        0xdacb05da4c9f0b3c,
+        0x9c98e80797123b9e,
+        0x05c2785171cf9893,
+    ]),
+    Fr([
+        0x8bb70713661dd3ef,
+        0xa3219b41e1e866e4,
+        0x4841aeae94dbafcb,
+        0x1f04ab075b343c2e,
+    ]),
+    Fr([
+        0x09877b99da1ed4d8,
+        0x3e1b13bebb562ab7,
+        0xfd0a453abc796707,
+        0x1b08caf18fe542b6,
+    ]),
+    Fr([
+        0x8772776c35ed4ddb,
+        0xd1e483c46dca8f20,
+        0x3d6ef5b0ece3cb77,
+        0x04350d60ac3cdeda,
+    ]),
+    Fr([
+        0xd59789d52dfd828d,
+        0xd0d4f5c7c7c66b69,
+        0x4184bf91b5309734,
+        0x0e09dedd81137365,
+    ]),
+    Fr([
+        0x3c46a5ad6730c5ce,
+        0x5c7bd37bda525007,
+        0xd57d1e170a707411,
+        0x13e189b521197bdd,
+    ]),
+    Fr([
+        0xa6343ee1f304a26f,
+        0xca33c793d2ba7228,
+        0x25dad12500d4d448,
+        0x09cf827625852c46,
+    ]),
+    Fr([
+        0x797a8602c63a54d9,
+        0x1ac2bc433aa0e827,
+        0x7c3fbe7f580b7789,
+        0x03174635b882d894,
+    ]),
+    Fr([
+        0x4f16dab5318908d8,
+        0x618709ae9bb33e9b,
+        0x79f6ce9d1fcccdb9,
+        0x2a42c258663e5559,
+    ]),
+    Fr([
+        0x9352c66f8db85bed,
+        0xf76a6cfc91216ae2,
+        0xecadbbeeab6ddeba,
+        0x0501860d019acbef,
+    ]),
+    Fr([
+        0x33bbf7559b633bea,
+        0x59bc392c36c8c252,
+        0x13a0563e80b1aebc,
+        0x15fa97680663b761,
+    ]),
+    Fr([
+        0xd34b76cc7ce56662,
+        0x4637562052f0c797,
+        0x121bc99292d6f09e,
+        0x0a694fce552ddc2d,
+    ]),
+    Fr([
+        0x2c91b8df8e961db5,
+        0xe0847837f14b5eb8,
+        0xdfa9bfc0600b988a,
+        0x0e88c05fa2dd61ec,
+    ]),
+    Fr([
+        0x062b0126d1594ed2,
+        0xf6b8ef949360c8f3,
+        0x88685c33681f9b19,
+        0x057ca2cedc6147f2,
+    ]),
+    Fr([
+        0xbc4cb8237d1d6988,
+        0x40a88f0ac02628ed,
+        0x38d89898d686d929,
+        0x2333cd11b0515c31,
+    ]),
+    Fr([
+        0xb242031f673208b5,
+        0x144ef3dff309ec58,
+        0x74d56678e4dc599f,
+        0x2ca99301804984ef,
+    ]),
+    Fr([
+        0x5131dba233a4bed1,
+        0x8a8e1b36d8400bac,
+        0x21e59e8865901cd2,
+        0x0e5c8ec6f3fab0a5,
+    ]),
+    Fr([
+        0x2a4bb5fd88edc047,
+        0x5b86f3ebc68d0c2a,
+        0x54882f2a5688b04f,
+        0x05a94e75507cbe33,
+    ]),
+    Fr([
+        0x2b1caac2f8ecdb92,
+        0x1fc9ccb8dee0c00d,
+        0x132b98f93676a1fc,
+        0x26683390479088d2,
+    ]),
+    Fr([
+        0x35366d726512589d,
+        0x1a3e6b999132d200,
+        0xde087c496782aa42,
+        0x19bd0fcb4d53dd00,
+    ]),
+    Fr([
+        0x047cb750ec6c0762,
+        0x24d6a46e5dabb47b,
+        0xc919256c1ba14cab,
+        0x273396a78c4b62c4,
+    ]),
+    Fr([
+        0xf0b98c2b57687d8e,
+        0x80147c912063365e,
+        0x59b461ea91ba1607,
+        0x28c3fc150c32ff50,
+    ]),
+    Fr([
+        0xf5eccf1c3d434232,
+        0x473330d9dd3d1536,
+        0xade583c6c46d86b2,
+        0x2c598c961ec58e70,
+    ]),
+    Fr([
+        0x03b800160def6797,
+        0xc4cf4ab9a943177e,
+        0xcde7b62ac1588f3c,
+        0x06eecbd051cf7c34,
+    ]),
+    Fr([
+        0x0369b2583c6bd1fd,
+        0x0dbdc1a076d43177,
+        0xcb0dd179adcedd3d,
+        0x0bbe0370470abd29,
+    ]),
+    Fr([
+        0x7cef46f570a500c7,
+        0xd5c89d2b7ae0671e,
+        0x32a472d2e25b5d5d,
+        0x1819574a6430cd10,
+    ]),
+    Fr([
+        0x6f7de7d7f2113dc2,
+        0x9bad7676e26494cc,
+        0x59b77b7b4f7cb546,
+        0x2624ebdd096c1e37,
+    ]),
+    Fr([
+        0x11a5cdddfca66b49,
+        0x768bcc294debebe3,
+        0x852080c87fbfda18,
+        0x1c7d91a619b633ee,
+    ]),
+    Fr([
+        0xaae5e35d8145efad,
+        0xce1d50cae0bbe5f3,
+        0x36970cefb2134e9f,
+        0x1280d61543c46cfe,
+    ]),
+    Fr([
+        0xbaaef2d36b5468c6,
+        0x498f7feb859e85a0,
+        0xfbbe4f4fef131eac,
+        0x0b321fd2bfd3b824,
+    ]),
+    Fr([
+        0x73cf2dd7fb1b7a9a,
+        0xfa91136de4c46704,
+        0x7477293dd0a4ef08,
+        0x1b2e2f421e0c4760,
+    ]),
+    Fr([
+        0xb4af5805db788bf2,
+        0x4c06654190ce9257,
+        0x3c0942be862b0413,
+        0x19358aa7ecb1ffdc,
+    ]),
+    Fr([
+        0x1a5f2289be6e42d5,
+        0xb8558e89c40d8a7a,
+        0x6db403e0d417290a,
+        0x0df8c12d84359369,
+    ]),
+    Fr([
+        0x2c1623378fa50e7b,
+        0x334709d38676a013,
+        0x424966dfeb1a23e8,
+        0x13f728b84aee75c8,
+    ]),
+    Fr([
+        0x58cf088ebda25f34,
+        0x7a5d6a37c20a05fc,
+        0xc3e67e0e8ef2478f,
+        0x28cf15d813041154,
+    ]),
+    Fr([
+        0xcd9b371660e26854,
+        0xa798e006bde7c2a5,
+        0xa589cbbf8f0cde4e,
+        0x0cc83461e6654fc6,
+    ]),
+    Fr([
+        0x5aa5df881cf08b7a,
+        0x854536b686b10afa,
+        0x54fbdc7acbaf9ae0,
+        0x2267e5792b131883,
+    ]),
+    Fr([
+        0x6800c90673d40313,
+        0x370cc2be065355bb,
+        0x48b361af98b894c4,
+        0x1574397ef2d5da4d,
+    ]),
+    Fr([
+        0x980463e7193ce68c,
+        0xae87dc9d1a4abf45,
+        0x038a72f6f99cdb5e,
+        0x169da4f52a8f168c,
+    ]),
+    Fr([
+        0xc837d64534683bb9,
+        0xa1c5ad4757898b12,
+        0xc4eb6597a3d9aa3f,
+        0x10551f64e930629a,
+    ]),
+    Fr([
+        0xc276f1f23fa3dfda,
+        0x07be179d0f4e390f,
+        0xef2cc368f6a7932d,
+        0x169888fd256ea6c8,
+    ]),
+    Fr([
+        0x0933f9e69fdb4920,
+        0x757798d2cb552ac2,
+        0x44617531aff8a6e8,
+        0x1c88dc66ae439dee,
+    ]),
+    Fr([
+        0x84d6faa1219d011a,
+        0x6c621fc73f57d413,
+        0x06661776bd78cbf4,
+        0x0d40569eac57478d,
+    ]),
+    Fr([
+        0x65a764cb33746490,
+        0xd857618b6d3d133b,
+        0x597d95e1bcb82d7c,
+        0x1999b22a5b250c0d,
+    ]),
+    Fr([
+        0x09913f64ed0f55eb,
+        0x71f4b894b1d49b95,
+        0xe64f2d869782046b,
+        0x2ac51c6d3b362aaa,
+    ]),
+    Fr([
+        0x4e1f1fb6f3864524,
+        0x183aa6e52734a295,
+        0x7a585dc7414a772d,
+        0x0e2e7f46b2e96900,
+    ]),
+];
+
+/// MDS matrix for `t = 3`, row-major.
+const MDS_3: [[Fr; 3]; 3] = [
+    [
+        Fr([
+            0xf2e8909a56fcf3d7,
+            0x8019ce3145ed8c1d,
+            0xdda896a228616418,
+            0x0e5ed723ffc885e1,
+        ]),
+        Fr([
+            0x3158f311d66c0469,
+            0x9511d96f69f040a0,
+            0xbc6996e5b22127bf,
+            0x07e69e17a7c9122a,
+        ]),
+        Fr([
+            0x28f45876169969b0,
+            0x3d6ded69e30a7649,
+            0x79aed6124c9b23dd,
+            0x03cf3048ffadf517,
+        ]),
+    ],
+    [
+        Fr([
+            0x670d8bd946474dd5,
+            0x56daed800bf07bae,
+            0x5c98d51ecca20e6d,
+            0x1a3491eda18b0028,
+        ]),
+        Fr([
+            0xf0193e572ba79c47,
+            0x5fb2e46a6ee2dac5,
+            0x6892f0d5b6ffb984,
+            0x0df1dabd49661413,
+        ]),
+        Fr([
+            0x3293bffccaab272d,
+            0x85cbae38b11c4e1f,
+            0x67208956c8757b3c,
+            0x17ca537ab6c9d981,
+        ]),
+    ],
+    [
+        Fr([
+            0xcc226561d2802757,
+            0xfcfbd22f5bb9f4ed,
+            0xc8ef58acce2b8678,
+            0x05984bb41bae9c88,
+        ]),
+        Fr([
+            0x17561a5176bfeefd,
+            0x1cd5d7be100061af,
+            0x714cefb2dce7646c,
+            0x0043bf61f2173fe9,
+        ]),
+        Fr([
+            0x4c72e3c51c729128,
+            0xd35b9fd9170d616c,
+            0x4d095dc74ab700a6,
+            0x1282bdf76dc5d39b,
+        ]),
+    ],
+];
+
+/// Round constants for `t = 5`, `(R_F + R_P) * t` of them in round order.
+const ROUND_CONSTANTS_5: [Fr; 340] = [
+    Fr([
+        0x878a9569334498e4,
+        0x4641e4a29d08274f,
+        0xf2713820fea6f0c4,
+        0x0898c94bd2c76331,
+    ]),
+    Fr([
+        0xd6dec67b3646bdbc,
+        0x626a9e071b154f27,
+        0x71a61cb1f9d90cbe,
+        0x134dd09bc5dffaa7,
+    ]),
+    Fr([
+        0xc24d9503f8682c8c,
+        0x9cf5f5abe19fedff,
+        0x125f8816cdb2d9f1,
+        0x05954a7a4436fd78,
+    ]),
+    Fr([
+        0xc306f8ed4ba6732d,
+        0x5b187030689573d0,
+        0xb0a9df5b5120771d,
+        0x05513e9e64511461,
+    ]),
+    Fr([
+        0x84b301dccd446ff0,
+        0x59d0332079fd0d4c,
+        0xcb69fbff03ebf775,
+        0x1582477fe7736802,
+    ]),
+    Fr([
+        0x5362fc5c9a7c42f4,
+        0xb8b364f0155ab1f0,
+        0xb30654c42656c7c1,
+        0x2c5a02372db008fc,
+    ]),
+    Fr([
+        0x96b72aad17e84cc8,
+        0x951de8b3af900172,
+        0x019e8182aa706e63,
+        0x2698ae703c1abe1a,
+    ]),
+    Fr([
+        0x00d42e1221436b0e,
+        0xf2664ef30c6cd002,
+        0xedf50acc3d8616ae,
+        0x0f0f24531734899b,
+    ]),
+    Fr([
+        0x8b729e9643b2f886,
+        0x0d9d69a737bec5d9,
+        0x13f8477d2a2dc7df,
+        0x2f801ef1125e22ee,
+    ]),
+    Fr([
+        0x48e414c85ea0124d,
+        0xadf36e25585ad3b6,
+        0x01fd905aa908ce96,
+        0x12ae1d5c33dd8585,
+    ]),
+    Fr([
+        0xd9400c663f46fb1c,
+        0xc367fbfcb74e14e9,
+        0x263a31f53539a9a8,
+        0x2c14ea3e70ece3d6,
+    ]),
+    Fr([
+        0x8205091fcff4185e,
+        0x15b16c04f93c6171,
+        0xe81d72eaa617fc83,
+        0x1188c21d9ce9974d,
+    ]),
+    Fr([
+        0x8eac4395aa681654,
+        0x164ca0ba11b427eb,
+        0x35554a8d4dd43c78,
+        0x25884483e90fd08c,
+    ]),
+    Fr([
+        0xe7263d68cd48cf9a,
+        0xdfbbd8be5db5ba79,
+        0xda0fce3e869b03f1,
+        0x06cddd417a334e35,
+    ]),
+    Fr([
+        0xa0c5bf320595fc16,
+        0xf49f2260daad1d81,
+        0xb75bb40c6c4ad890,
+        0x0eed000c77ed4842,
+    ]),
+    Fr([
+        0xaecd15b6a4000350,
+        0x87fac0171084f2b6,
+        0x8d5c807f13f5c80c,
+        0x2af2a0fdc8846217,
+    ]),
+    Fr([
+        0x2864cfd614d0d590,
+        0xb408241676c9080a,
+        0x9080fa2f363b966f,
+        0x0af9bc0066eeb20a,
+    ]),
+    Fr([
+        0x0f99ec2d9c7f5c46,
+        0xc68c8a74c8fa9784,
+        0x082b8e29d87f91cc,
+        0x251594e3ce0b5ebd,
+    ]),
+    Fr([
+        0x406e8b6a120194da,
+        0x115b890c7f641047,
+        0x6752259870a05fa0,
+        0x0a4c706bb8417105,
+    ]),
+    Fr([
+        0x149a1b98952f0443,
+        0x7c9198b7d88b08da,
+        0xb342f127f00655a8,
+        0x248b7b9b0c55787a,
+    ]),
+    Fr([
+        0x3af8d053046780e4,
+        0x3614ef0c253cec2e,
+        0xce3861bb8d06d02a,
+        0x0c1f2be6c18f032d,
+    ]),
+    Fr([
+        0x6fcf69d0a67c195a,
+        0x39d597ee0bae3c31,
+        0xd5442c99bb0750e4,
+        0x2032ec38d1dc6c70,
+    ]),
+    Fr([
+        0x36c3b5b1458e56f2,
+        0xa6ab19d26e8c77be,
+        0x7ea17893f365b20f,
+        0x10691bce5b521d2f,
+    ]),
+    Fr([
+        0x9b6b4c808a224350,
+        0xb1a995f0f8f7dfa1,
+        0xb11d550562133bf0,
+        0x2842fdf05ad8f43b,
+    ]),
+    Fr([
+        0x5d0bb8e16ee958e7,
+        0x9d0ee403d02451b5,
+        0xe06f678dabf8d1ca,
+        0x135df6826cb5631c,
+    ]),
+    Fr([
+        0xd1287d6e72805c15,
+        0xf50ffffa5807b974,
+        0xd1df630fb95ed3b8,
+        0x026ba33898a91d2f,
+    ]),
+    Fr([
+        0x0d3d274100f30799,
+        0x6049cdb06abe61ad,
+        0x88cf6be341686e54,
+        0x0865d2b1a2dce2b1,
+    ]),
+    Fr([
+        0xbc0489a9907d3cb0,
+        0x1d8e718238e40ae1,
+        0xb717b8c529e8aec8,
+        0x25326846f39943c0,
+    ]),
+    Fr([
+        0x9d8248d4155b0fc5,
+        0x87b60b0e75d3f8c7,
+        0xf31fe5dd564a286b,
+        0x1621a6bb722e8d7b,
+    ]),
+    Fr([
+        0x68b9aae2a328df44,
+        0x16c257f026b30af4,
+        0xf4fb6aeecd769eab,
+        0x017e173ffd31ceb4,
+    ]),
+    Fr([
+        0x4acfbb3a0ab1f3af,
+        0xcea57df3614f599d,
+        0xa690574499abf0f4,
+        0x1ac5b7f1fca6daed,
+    ]),
+    Fr([
+        0x31d90575e2d7eefa,
+        0xf980315c753fbd71,
+        0x8973af88e9860147,
+        0x1ae623170a16aaa6,
+    ]),
+    Fr([
+        0x4e035b8837167083,
+        0x7cb27b17484ebcf0,
+        0x106d9221bd85c992,
+        0x1311510bd5c3ffba,
+    ]),
+    Fr([
+        0xd0b913b89c8b591d,
+        0x283637c85de72e1d,
+        0x3ab46dca1ed10ecc,
+        0x0ebbc06fbbfb1a29,
+    ]),
+    Fr([
+        0x1cd247a161f6d784,
+        0xe9ec0ef890468906,
+        0x4503b30d08dfb071,
+        0x271a41fee8f62a99,
+    ]),
+    Fr([
+        0xb95ab597bbc45967,
+        0x383fa8dba9e16989,
+        0x5295c5835c17d12c,
+        0x1f61f338ec158b0a,
+    ]),
+    Fr([
+        0x8b8a25bbccf10b14,
+        0x1ed6268b11ceb7ea,
+        0xd380344a54ef979f,
+        0x1eead5bc0fd5924e,
+    ]),
+    Fr([
+        0xceabe5b248a077e3,
+        0xcd6be0ebbec2326b,
+        0x24b760e4bce6ce62,
+        0x141a908a6d27f698,
+    ]),
+    Fr([
+        0x62e7ce178b30c88a,
+        0x835b2f0c43c4852f,
+        0xef27746250bd7876,
+        0x300dcbcb6f2aa657,
+    ]),
+    Fr([
+        0x2e1ad2dbb8e83c27,
+        0xc2860d2eabcec4f6,
+        0x3be3fa4cc0b160a8,
+        0x2bf92f3fe61f1232,
+    ]),
+    Fr([
+        0x47719788cbccebe2,
+        0x4edb4bb6db9f29e2,
+        0x21f92d8f1fb7eb63,
+        0x28deb83e98fa211c,
+    ]),
+    Fr([
+        0xd9a0180c0b1cdbaf,
+        0x8863c3c471f69245,
+        0x7f11fe3ad3fee8c2,
+        0x160fdc2b4558c21c,
+    ]),
+    Fr([
+        0x1c77bf07af072cab,
+        0x91991afbbd12154d,
+        0x0dde94cb7c0d5a92,
+        0x24eb431b12db1b0e,
+    ]),
+    Fr([
+        0x4e9f1258182ba056,
+        0xf953557b7083b6ca,
+        0xe7940dacb035f381,
+        0x2f76b4408948eb23,
+    ]),
+    Fr([
+        0xfcce96724694244f,
+        0x109488ef26c6bf00,
+        0xe6d8fd8554c907ed,
+        0x2a1aa167b3c1d370,
+    ]),
+    Fr([
+        0xe3db4323e596559d,
+        0xcb243fa371987f27,
+        0x61d4edf5855d81b3,
+        0x15da7b124ced0fe3,
+    ]),
+    Fr([
+        0x7108addbc90c712a,
+        0x72abefae6e8506fc,
+        0x8613f0c133c634cf,
+        0x053679f8c0431334,
+    ]),
+    Fr([
+        0x3cf98afc0fcfcf56,
+        0xad18915e610c8a89,
+        0xc352625b7b74018f,
+        0x1338a38250a2eae2,
+    ]),
+    Fr([
+        0x1dea74549e83599b,
+        0x7e92f66af4254a88,
+        0x70c1b47bd2703132,
+        0x19ed37df27b27592,
+    ]),
+    Fr([
+        0x228607bcc1a41f16,
+        0x0abf12885201bc36,
+        0x1fcf698e7d85860c,
+        0x2507b3680c3d5c60,
+    ]),
+    Fr([
+        0x68dff76afcbebb94,
+        0x115a3fbe38d8798d,
+        0x2411ecadb9b26692,
+        0x19f31c5c19cee1ed,
+    ]),
+    Fr([
+        0xefa41cc0bcf817a9,
+        0x58244d1d6978224b,
+        0xde20ee01ef3a3ab6,
+        0x0c32dac0cf3d1e0e,
+    ]),
+    Fr([
+        0x1ab51e2b39f5d7b6,
+        0xfecbb5939b623af7,
+        0x655bd9dfa4c6cbb1,
+        0x0a3f8bc7a082f5cf,
+    ]),
+    Fr([
+        0x49e7c4e977a85aa2,
+        0x0f334d5ff12aa382,
+        0x96c8bb2c420e3b98,
+        0x2c4cbffa8acdbdeb,
+    ]),
+    Fr([
+        0x825cb2f28a8c1e7c,
+        0xb19867377b71bd69,
+        0x7d1106032b26bd25,
+        0x30263798ec8c94b3,
+    ]),
+    Fr([
+        0x7e9a787542333054,
+        0x9ed2026bcce5daef,
+        0xb94cfda3b19aa18a,
+        0x2aef388e6fde243e,
+    ]),
+    Fr([
+        0xdec165892c94bfb4,
+        0x329cf08d419fd17d,
+        0xc782558cef565f52,
+        0x107e2bd0a01a9bd1,
+    ]),
+    Fr([
+        0x1e3617c341f737dd,
+        0xeec05d4004fc56ff,
+        0x9fcb2eec469e1def,
+        0x17d7ef155407534c,
+    ]),
+    Fr([
+        0xbc2e3c78ddc790a7,
+        0x4f2c8974fe39df1e,
+        0xc1fa27471562cd24,
+        0x03c3abd8dd76e013,
+    ]),
+    Fr([
+        0xb6718921c68b5bf3,
+        0xba4407209c0e858c,
+        0xaff2f22be243e66d,
+        0x1597dc6965c1d817,
+    ]),
+    Fr([
+        0x72e23890fd41a8b1,
+        0x20ae67fc3e5054c5,
+        0x0b17c6ddeea6f4bc,
+        0x0b484f64ff2eb9ee,
+    ]),
+    Fr([
+        0x1ba1ed783ebc3ccd,
+        0xf8ec74a93556259a,
+        0xf6b8df50e3999e23,
+        0x0a575b9987bce9da,
+    ]),
+    Fr([
+        0x6eb7507d2f380c81,
+        0x706bff2d37993e4b,
+        0x5490dd35553df267,
+        0x1b4ce24fbfd5173d,
+    ]),
+    Fr([
+        0xef208235df8e5c80,
+        0xd3018f9a778b1ba5,
+        0x0567e27bbb6a94e3,
+        0x07e1962013fac5ab,
+    ]),
+    Fr([
+        0x2bef9b8d26453de8,
+        0x783a4aedb77217d1,
+        0xd1d0204bbe4363c3,
+        0x252aed5a7f967a2a,
+    ]),
+    Fr([
+        0x767e910fd7b1d113,
+        0x64d3025621dc2bf8,
+        0x014848e166fb75ce,
+        0x1e1878fa93dd581b,
+    ]),
+    Fr([
+        0x74b547568b0b9ca6,
+        0x820752b8883e6af5,
+        0x571dc27a11f58de2,
+        0x0782ebb59653fc84,
+    ]),
+    Fr([
+        0xe24f568e1423a243,
+        0xa2c0c8cbb0b437a2,
+        0x90173ce12fdbf453,
+        0x1bb605b963e6819e,
+    ]),
+    Fr([
+        0x424258a0395d5c6a,
+        0x2e3b982b53d799f3,
+        0xe1caebe15898eb96,
+        0x2ecc7aa78cab1cf2,
+    ]),
+    Fr([
+        0xbe609bfb2d77d073,
+        0xc2e8b49a0fb971be,
+        0xfc3f29f72e7c6a02,
+        0x0a2b4f397bcf9583,
+    ]),
+    Fr([
+        0xa5b2d83c372a5ee4,
+        0xce669dfa91c515ba,
+        0x3d6952ba735dd19b,
+        0x142cd30377108060,
+    ]),
+    Fr([
+        0x60c5069959733fe5,
+        0xea5b12d915a075ac,
+        0x5894d4b277436a2b,
+        0x1ae3719bd175bd2e,
+    ]),
+    Fr([
+        0xeebda1b5058baed7,
+        0xf4e9c9c5064fa02c,
+        0x3e2fb0e622e4ab3d,
+        0x118ebc22997e996f,
+    ]),
+    Fr([
+        0x81aab5decfde8a1f,
+        0xfaee7498bf1f1ee2,
+        0xc80649d2d0ce7c07,
+        0x229c38125a51f2ea,
+    ]),
+    Fr([
+        0x985798acf36f6dfa,
+        0x9e2748a7fd62be49,
+        0x6778706b49970da2,
+        0x018c1e02e85993a6,
+    ]),
+    Fr([
+        0x8efe1a26d380e662,
+        0xde5aaa7b41a784f4,
+        0x1556052098327318,
+        0x17f9f1318cea6698,
+    ]),
+    Fr([
+        0xa2f259bc5d1fae3d,
+        0x659ef5c9d71cad42,
+        0xe923b54fa8d0258b,
+        0x03671edf5785b875,
+    ]),
+    Fr([
+        0x522adc4e474f6465,
+        0xa61be19bb4af4bab,
+        0x63fbcaccdd0624b3,
+        0x19d2382d5d50ec5a,
+    ]),
+    Fr([
+        0xe4b8176417c5b011,
+        0xe9111b9c9116f47c,
+        0x31fec42826b9b737,
+        0x15815cf4d4c29970,
+    ]),
+    Fr([
+        0x2d3df64d4cc2af8b,
+        0x8df6d640ad061121,
+        0xee745dc93e0abc89,
+        0x1c470d0e1d502236,
+    ]),
+    Fr([
+        0xc61d1549f24f7aa6,
+        0xb0b9980daf650c2d,
+        0xc7e378f87562d8ad,
+        0x13fc40a1e5880c11,
+    ]),
+    Fr([
+        0x67d132dbfc543847,
+        0x2acb1a2d09cd8b3a,
+        0x2cc8ac43f65c39fc,
+        0x00e539944f8812f9,
+    ]),
+    Fr([
+        0x2a650c1e112d61d5,
+        0xa094d7a998570d6e,
+        0x3fb61809d34fa3f6,
+        0x1ccfb9311d38da8c,
+    ]),
+    Fr([
+        0xedf9c6538dd77409,
+        0x2ff942bf1d7857ed,
+        0x65fd59c9d45c0db2,
+        0x2aa40867a469c80b,
+    ]),
+    Fr([
+        0xd427338021a545dd,
+        0x7da6580b3362643c,
+        0x980a22b76f9889db,
+        0x123988c2835a9a24,
+    ]),
+    Fr([
+        0x8b6cc56fc4a76a71,
+        0xadb2f3e02fd063a2,
+        0x0bedaba09c4dd557,
+        0x0b1608caab628954,
+    ]),
+    Fr([
+        0x456b34ab0915b1b6,
+        0xcb35fb5dfe479b6d,
+        0xbe5affaee0f02ffb,
+        0x04ab07cefb144ee3,
+    ]),
+    Fr([
+        0x78dd414ae4f7b61a,
+        0xa501d3572ef1cd14,
+        0xbc6d4580db92fdf4,
+        0x0faabe5df613978a,
+    ]),
+    Fr([
+        0xddb52cf62323987d,
+        0xb973b8fe44d14732,
+        0xfa94838f95c33f24,
+        0x071c6d0b09104a8c,
+    ]),
+    Fr([
+        0x556cb74f80958c8c,
+        0x1326cf75aea004da,
+        0x73ea834fa867569b,
+        0x1a7a1d35e343d6d6,
+    ]),
+    Fr([
+        0x3fd1e5a02371b5af,
+        0x9c7c08f0a0acab1b,
+        0xcfc13ca10c1a429c,
+        0x1c7ed01fe7a7cc99,
+    ]),
+    Fr([
+        0x65077690ae397f68,
+        0x9a0a194dc1f39b21,
+        0x0d402bdffec6708b,
+        0x06dfcdc908172898,
+    ]),
+    Fr([
+        0x594057b7e3efdd33,
+        0x1bcff8f9b420c2eb,
+        0x75d85b723a6772d4,
+        0x0ab0c47938e78af9,
+    ]),
+    Fr([
+        0xf12f6fd0f545630b,
+        0x39271bd6d2eea9a4,
+        0x879f22b58867f2d2,
+        0x02f399407f57ee41,
+    ]),
+    Fr([
+        0xe90c8bc7b3a4ca9b,
+        0x0e9451f3c6a47db4,
+        0xc1bfff8303becb17,
+        0x1c89980423c0e7fd,
+    ]),
+    Fr([
+        0x850277e063c37b0d,
+        0x5a1ec8cf872088e9,
+        0xa9187636ff4e7c1d,
+        0x11bf93e921c76865,
+    ]),
+    Fr([
+        0xca727e287aa107f3,
+        0x8ad0fd863f4a2994,
+        0xb81f12b15680ff26,
+        0x2f3b02faa9e2283f,
+    ]),
+    Fr([
+        0xe315d409289a3102,
+        0xd2af0f05b44e14d0,
+        0x37192d8727f960b5,
+        0x23f59ad717371256,
+    ]),
+    Fr([
+        0x703f2e5faaa00b28,
+        0x79a9177e081b6014,
+        0xbbab285beca97a99,
+        0x0f60e5597a6e59f0,
+    ]),
+    Fr([
+        0xebf3648071aa12f6,
+        0xa4f544819636d793,
+        0xbb1904ff84234d4a,
+        0x0d1a7c76aaa9d653,
+    ]),
+    Fr([
+        0xb4f61ddb5efe775c,
+        0xbcbab6f0fbbd58e5,
+        0x2ae426a9a2be2018,
+        0x24dd801cf2f45716,
+    ]),
+    Fr([
+        0x91c7251108bb269e,
+        0xecc3dcaf2a43e282,
+        0x89e159517c2874db,
+        0x1b77fa98c8e136cb,
+    ]),
+    Fr([
+        0xa76625f84556a6d2,
+        0x0ddf47e35bf922cf,
+        0x894b9011e1427fe1,
+        0x0ce0209cf5321212,
+    ]),
+    Fr([
+        0x0a261aa52b3455f0,
+        0x1408302a90e94238,
+        0x70469145b20d69a1,
+        0x0aabb98394429573,
+    ]),
+    Fr([
+        0x0a5dd9f92266bbd6,
+        0x9b4ff705a29d1f79,
+        0x88bb67e33444fce0,
+        0x05ccb7329777c838,
+    ]),
+    Fr([
+        0xc5b0b815fec4067e,
+        0xc2bf2ecf135b8265,
+        0xe422a048e71a2a9e,
+        0x1e86da97264a4809,
+    ]),
+    Fr([
+        0x77896a472d57c5d7,
+        0x4c01659d6cb7307d,
+        0x6d604465d6ae4195,
+        0x259a279222caf245,
+    ]),
+    Fr([
+        0xeec7c8d3c9d05669,
+        0x403cac10f52e5ed3,
+        0x7d286f43634fc4a8,
+        0x170ccf1409884506,
+    ]),
+    Fr([
+        0xbf45ec883a64a1e0,
+        0xe656c3c04fcdd3ee,
+        0x4da3e25c02f0cd07,
+        0x2e92f647578260ce,
+    ]),
+    Fr([
+        0xf07c35524b1c69b3,
+        0xdcbea469cffa2bea,
+        0x3319b3e7626434d2,
+        0x050473b25d9627f6,
+    ]),
+    Fr([
+        0x71ae812c01df78cd,
+        0x8e42ac99ea7b9de8,
+        0xa2b891b1fe42553b,
+        0x16df9032ff3b678d,
+    ]),
+    Fr([
+        0x7be10854d92f366c,
+        0x88d02eee3ebb1717,
+        0x1de04ba845924d19,
+        0x1672eb7cb2f7cea2,
+    ]),
+    Fr([
+        0xd53686face6d1a94,
+        0x57a2abe2fb9bdb6f,
+        0xd4466126890d3ff8,
+        0x169e5c32fcb12a1a,
+    ]),
+    Fr([
+        0x2b8ffe0e028fab58,
+        0x79f2259ad4a7598f,
+        0xae9e1e6d37ec58a0,
+        0x0360de92f5d0fcf4,
+    ]),
+    Fr([
+        0xf9c12f1a674e0eef,
+        0x925dd455a1ce50d8,
+        0x0f565f5cda9cc8e0,
+        0x08b17aece2905e79,
+    ]),
+    Fr([
+        0x21fb8e0647d40d5d,
+        0xd8dc830623a71f77,
+        0x17c0551c8acf8aae,
+        0x2694844861621ce8,
+    ]),
+    Fr([
+        0x68748cd83770f6d4,
+        0x3eed11b90479facc,
+        0x4e06a8320f30819b,
+        0x2f5decf20bea441c,
+    ]),
+    Fr([
+        0xd3087fa57e280102,
+        0xe99c6d809a79d218,
+        0xfe0e285307b369a7,
+        0x1ff7d670edb42335,
+    ]),
+    Fr([
+        0xe42ea6ade883578a,
+        0xdb841d736aacdc59,
+        0xafbc39f661961241,
+        0x2e286d586bf36f73,
+    ]),
+    Fr([
+        0xc082fd6317b120b4,
+        0xe23011069f300b47,
+        0x0b782e5541f3c64a,
+        0x0735bfaa4e96d0fb,
+    ]),
+    Fr([
+        0xa989f12ac7ea4110,
+        0x1d2ab07c09772de4,
+        0x93bab326db527582,
+        0x09102ec7ba7ea44e,
+    ]),
+    Fr([
+        0x98d3ff67f305789e,
+        0x3127595225299aee,
+        0x57254e4d4484c7e0,
+        0x0c1e884b0fcc2e31,
+    ]),
+    Fr([
+        0xf134b08e02024ff3,
+        0x0a4ab6f42842828f,
+        0x634e64a526bbf3a6,
+        0x1100e0613865f4ba,
+    ]),
+    Fr([
+        0xa3a6ccbf0d49f756,
+        0x345fc8f27a5d2387,
+        0x2ddea52cd3969df3,
+        0x2a6b21ff4c95d015,
+    ]),
+    Fr([
+        0xb98c608ff29f2f56,
+        0xadbd9a24ce238e22,
+        0x5ad7533cf5ea087b,
+        0x04990bdd0180fa57,
+    ]),
+    Fr([
+        0xd69dd26465503121,
+        0x1fa76a9b49d89e55,
+        0xe9688595f7287d0a,
+        0x13b473bc49f7e9e7,
+    ]),
+    Fr([
+        0x5fff47d6bf555f70,
+        0x3a3a538f4403d5d4,
+        0x4ea8fb4169059e80,
+        0x301c263212c77179,
+    ]),
+    Fr([
+        0x9d73e39e1bc8207b,
+        0xc66f499f87d7361c,
+        0xeb9b233b4498afda,
+        0x1202e9aff9a9f48a,
+    ]),
+    Fr([
+        0x29ad13e72c99554f,
+        0x37e732f4608bd0ed,
+        0x450fa35fcad3e8cf,
+        0x267498bd3dd14816,
+    ]),
+    Fr([
+        0x78dd745921cdaa06,
+        0xc2fa4e760b0bd002,
+        0x730c68208a40352f,
+        0x03109476f0109626,
+    ]),
+    Fr([
+        0x41cbcd71f3abb777,
+        0xe582dd40dda04995,
+        0x980eeba3de5a793d,
+        0x081ee281e92f0ce0,
+    ]),
+    Fr([
+        0x794523182d81b2bf,
+        0xfec9c95df8c569bf,
+        0x1a378b90323612ed,
+        0x22c10df10deab182,
+    ]),
+    Fr([
+        0x1863e0beab9e8707,
+        0x3fb9357d4ca3c3e0,
+        0x8be1de3a9ad6ffb6,
+        0x24b05d41abf0bd40,
+    ]),
+    Fr([
+        0x8e9d429d5dd0d532,
+        0xd95b801bbc973739,
+        0x868c85e9888db57b,
+        0x0575ee9d3a474eef,
+    ]),
+    Fr([
+        0xceeaff93c7c0c95a,
+        0xbcde7b2f12bc3614,
+        0xc41db9f28b38bf36,
+        0x184359a1ae0f4a47,
+    ]),
+    Fr([
+        0x4fc998ed1ddcec25,
+        0xb089fdab4f657f4c,
+        0x30404c2d33ecd21c,
+        0x11b2163a0a90992d,
+    ]),
+    Fr([
+        0x508f2637f6bcdeb6,
+        0x68e03688139a2b75,
+        0x18343368e4fa64d3,
+        0x188c9ae0cf76d95a,
+    ]),
+    Fr([
+        0xcc85ad5d5a797414,
+        0xa38c86fd60139313,
+        0x33e81695fd3026f5,
+        0x0a6ec3a211a3f60c,
+    ]),
+    Fr([
+        0x15422b856727d2ae,
+        0x09d77047a31659e2,
+        0xb0b1e7a613ce7b07,
+        0x0f38cf2c91abd244,
+    ]),
+    Fr([
+        0xdc6d4b011bfb6f33,
+        0x5a9de497fc11123f,
+        0x7ed4781a5f5baeb8,
+        0x0f556e267386b10a,
+    ]),
+    Fr([
+        0x3cb34674384c595f,
+        0xba0c202373c4cf6c,
+        0xf71cd2d7f9519111,
+        0x1880171d34dc6c21,
+    ]),
+    Fr([
+        0x7bbf92b5a94a78f0,
+        0x581bf9fca97b1ec0,
+        0xdf3167248b40cf46,
+        0x1d15590a903c2c26,
+    ]),
+    Fr([
+        0x64e8eb865d4b3b4c,
+        0xbd1894070189c394,
+        0x292eb0c7dde16552,
+        0x20d7fba49951d2cd,
+    ]),
+    Fr([
+        0xf93097bd8c92262f,
+        0xfac4e71bf44e6758,
+        0x3c524519a013b3c4,
+        0x094d37aaaa712f28,
+    ]),
+    Fr([
+        0x5677cfccbd612156,
+        0x5e480dc85c78f191,
+        0x0169787a39b70b0b,
+        0x2ef06707ee10a21d,
+    ]),
+    Fr([
+        0x81093a19b3a8f00c,
+        0x94622405b1d7ad2c,
+        0x818fa1001f728e48,
+        0x303e038300021a82,
+    ]),
+    Fr([
+        0x88aa978c0d927b95,
+        0x492d8d45d1404ba1,
+        0x35d4feb55eece5f9,
+        0x2f0b5c95669aedcc,
+    ]),
+    Fr([
+        0xea8f5ed5c8930283,
+        0xeb145895efcf1951,
+        0xdb9e7b46aa8688d3,
+        0x225532cc60ad4b46,
+    ]),
+    Fr([
+        0x4929a7791be7c62c,
+        0xb1d934da8e2d9f5e,
+        0x4f36e7b1bdcb6a49,
+        0x0f7557be447e4104,
+    ]),
+    Fr([
+        0xd380ae526f3af66f,
+        0x7a3397f39af4d548,
+        0xff67c0e77c175bfa,
+        0x13d7c200c3965533,
+    ]),
+    Fr([
+        0xbd2537401be3944b,
+        0x9861eaac6d10c465,
+        0xfa9dee9430a6a7fb,
+        0x22358c2f6e6c2aa7,
+    ]),
+    Fr([
+        0xfaba48cb92359344,
+        0x266c07e8cf543934,
+        0xff9976ec73924e29,
+        0x0d1c95ef146f2623,
+    ]),
+    Fr([
+        0xf319a92c36026b2e,
+        0xfd362bc33a7b315c,
+        0xdc161701b094e3e0,
+        0x29929e9ec76d5c61,
+    ]),
+    Fr([
+        0x7b78491dd4372788,
+        0x4ea3c88de6e59df1,
+        0x790bffaf3780a075,
+        0x158d3f8dc8a67644,
+    ]),
+    Fr([
+        0xcc4150c9c339d440,
+        0x4c5751913a379ad2,
+        0x2ee5998671e35e63,
+        0x09a6049e220e675a,
+    ]),
+    Fr([
+        0x67a512a6ff1703d0,
+        0xe82105cdaa2947db,
+        0x7d9011967988b49f,
+        0x0e3cc24e617010c4,
+    ]),
+    Fr([
+        0xfb71128b1194b909,
+        0x8fa44cc7b267f1e1,
+        0x309f309498ec27e3,
+        0x2ec64495af5e87cc,
+    ]),
+    Fr([
+        0x6db4056eda369d38,
+        0xdc88877d2b9a369a,
+        0x73bf6d4fe9463d40,
+        0x0746c3155c732288,
+    ]),
+    Fr([
+        0xd4028d93c9297edf,
+        0x693c98fcea914200,
+        0x0761c875db80d333,
+        0x1821ad905515cb71,
+    ]),
+    Fr([
+        0x1c90d17e3bc84dd0,
+        0x4fde4a7ff5982aa1,
+        0x98cd6ff33d171473,
+        0x2b49d4ced162dd8b,
+    ]),
+    Fr([
+        0x30ec25e262b067e1,
+        0x9038b7fbff41e895,
+        0x4a19965e4be86d7e,
+        0x29a1cbdfe71b5415,
+    ]),
+    Fr([
+        0x4e6e766ac76a82f6,
+        0xcd929231e383d9cb,
+        0xdd3b4a4a5bd5e446,
+        0x2982db83c32bdaa3,
+    ]),
+    Fr([
+        0x9f6304a26a4e91b3,
+        0xfdfd67fa807018c2,
+        0x66bb3561af772560,
+        0x0255932acf2b8d13,
+    ]),
+    Fr([
+        0x6850ed4937dabe3f,
+        0x5f87791fcfaf0f3d,
+        0x31995428bf67976b,
+        0x2f67ea937511e10e,
+    ]),
+    Fr([
+        0xf064519170724e46,
+        0x446d824e7deb3eff,
+        0xbc1a46d7038cc8b4,
+        0x0f86068edfb84115,
+    ]),
+    Fr([
+        0x9d8e409b4578cdbf,
+        0x537ecdcd756854a0,
+        0xfcb796dfd81b44fa,
+        0x010e8d93a7b7c6c8,
+    ]),
+    Fr([
+        0x7917621743eadb65,
+        0x1f34d499bb74967c,
+        0x92dab8898b4eacce,
+        0x1751b7025b63d0ad,
+    ]),
+    Fr([
+        0xde1e39a791209d23,
+        0x7f5492d709e02a13,
+        0x865c42b83fcfceef,
+        0x024c52c936cfbcf5,
+    ]),
+    Fr([
+        0x426677674a93c751,
+        0x0db3d43f3567928b,
+        0x4558af4be907ccc6,
+        0x05a68ca51c6db0ee,
+    ]),
+    Fr([
+        0xc42816e4180f84e5,
+        0x440f526cde08e797,
+        0xb76701ddd74e2901,
+        0x212c68b31e7bf3ae,
+    ]),
+    Fr([
+        0x73eab4e25344972a,
+        0x6f0bb36534de3297,
+        0x0d6a8049cb1ff7fb,
+        0x05e7a2e2157fedd2,
+    ]),
+    Fr([
+        0xfa45c9e2bd330a08,
+        0x54d20e2de3dbe05a,
+        0x84dd16705e296afd,
+        0x0ce2bd1420083dc1,
+    ]),
+    Fr([
+        0x2af12c96de27345b,
+        0xf675ccfb24fc5ff4,
+        0x9d37ed01d66e00cc,
+        0x1f90842016dffbb7,
+    ]),
+    Fr([
+        0x58d8746bcd3b1dcb,
+        0x08e8776132080686,
+        0x868e950c589220f7,
+        0x2265d5455c3add5a,
+    ]),
+    Fr([
+        0xbef008943d053a91,
+        0x5c23fa0a553b87ed,
+        0xa1c937f66324c375,
+        0x280528d263712122,
+    ]),
+    Fr([
+        0x1497a44949494c9e,
+        0x7b78eeda10b014fd,
+        0xa8481162cae2ec7d,
+        0x1b0aa6b2eaddd1f3,
+    ]),
+    Fr([
+        0x73c7db1a0144bc5c,
+        0x53a7d7fd26289015,
+        0xd03921c476159262,
+        0x232f83cbaf78e1dc,
+    ]),
+    Fr([
+        0xa2f148a72097229c,
+        0x6e81ff6ffcd9af00,
+        0x71b684b412aad11f,
+        0x181070f340e3b699,
+    ]),
+    Fr([
+        0xde64454e65be5f29,
+        0xd4e20a0599026de3,
+        0x6c01062e20b2dfa2,
+        0x170bf9a382b352c9,
+    ]),
+    Fr([
+        0xc2a6e9665da985b9,
+        0x8e0efc2dabbee109,
+        0x42d917c308c90e45,
+        0x14158062c6ed87bb,
+    ]),
+    Fr([
+        0x4df3f32864020c6d,
+        0xe6d76127ee72eeae,
+        0xdb6ee54466fa2460,
+        0x288dea8ff700de22,
+    ]),
+    Fr([
+        0xa95300e9d51de6b1,
+        0x530325ae77a05c64,
+        0x966957f059c669ee,
+        0x0477e3652f25c267,
+    ]),
+    Fr([
+        0xc202c7afbc4289fe,
+        0xb5c9f910b59b091f,
+        0x24cd78014ffb3b50,
+        0x22f6423e0a80cae8,
+    ]),
+    Fr([
+        0x8127837ffc28f3cf,
+        0xb4111183ee027141,
+        0xd0a1f3620cb1fd42,
+        0x2414bbb76b8af88d,
+    ]),
+    Fr([
+        0xb15d0da34874485e,
+        0xbfa2cf3f5486ace1,
+        0x5f6ec9c0cfca20f0,
+        0x2ac55c7ab544afe0,
+    ]),
+    Fr([
+        0xdc00e3613b460d33,
+        0x9364c74610ba0aba,
+        0x748086843f7e8a9a,
+        0x239e1a731a2ad6e7,
+    ]),
+    Fr([
+        0xfb378f8a793c155c,
+        0x6ed80c1bb06d561c,
+        0x54e80a06b72b0a19,
+        0x2f3e1771befe5224,
+    ]),
+    Fr([
+        0x933c5a890a290c2e,
+        0xbf369ede89e1f71d,
+        0x6a7fd4cca88f380a,
+        0x0c41993855ec6f4f,
+    ]),
+    Fr([
+        0x2cc26b3184259df1,
+        0xd212a9885fc96f3e,
+        0x842104ea2bdc5e1b,
+        0x1a6f60d275d0b8d8,
+    ]),
+    Fr([
+        0x89bd14b444ed5d3f,
+        0x1d297dda5aee9993,
+        0xc6a7789086b5a014,
+        0x0130fbd0445d73da,
+    ]),
+    Fr([
+        0xf291e795d978bf00,
+        0x90276e35d6a04981,
+        0x99d405aba0ba12fa,
+        0x055038290f0dbc34,
+    ]),
+    Fr([
+        0x7469e178c29c20ff,
+        0x8fd08072ce54d353,
+        0xe5e2cdbac1ddeb13,
+        0x0f82298fd67a4b90,
+    ]),
+    Fr([
+        0x9cf4d58354a8d392,
+        0x3eb76d20828afc5a,
+        0xb6d2d65cd9ccb7ba,
+        0x1c5414dab32784fc,
+    ]),
+    Fr([
+        0xf0a311f53014cd59,
+        0xa94f4a8c105e7c26,
+        0xf30c8f7c5be7fe4b,
+        0x2285dcaef23d05fd,
+    ]),
+    Fr([
+        0x16c5469ee7d3c837,
+        0x4a4d70772dbd3c6f,
+        0xfd17c91710973055,
+        0x06e0a97399ba2d58,
+    ]),
+    Fr([
+        0xcab2c41737fbb6dc,
+        0xa1e83754d9796129,
+        0xd2ee8a5f98d7bf9c,
+        0x17c24db40f1cec8c,
+    ]),
+    Fr([
+        0xcca2a5657c6e8da9,
+        0xafd57a506e2bca77,
+        0x9ca57834be2da4bc,
+        0x071a0ac54577b165,
+    ]),
+    Fr([
+        0xb3d9e4680bb4cb73,
+        0x4ed91d13001412cf,
+        0x514b6370d17996b3,
+        0x21eb0792b78ffeb6,
+    ]),
+    Fr([
+        0x3ba959d3c8732ea1,
+        0x952a702996e2b796,
+        0xc45f83663a80953c,
+        0x0c256cb16a91dce4,
+    ]),
+    Fr([
+        0x3d5877bce5f26fb9,
+        0x7cc1ddc68e0aa3ff,
+        0xbca5bbe57456431e,
+        0x0ce6b0593eb8151d,
+    ]),
+    Fr([
+        0xcd3bc337fa008fa1,
+        0x7601adf611acd524,
+        0xfd90abfc3d41d204,
+        0x282aa66a61509994,
+    ]),
+    Fr([
+        0x6ac40016f0326d83,
+        0x30a3fcf6c5132f31,
+        0x9c2a5997ad9ec4b7,
+        0x01500b51d22068bf,
+    ]),
+    Fr([
+        0x46c8df62256d60de,
+        0x482395a9f35ccf91,
+        0xce4a3f1bf8d781bd,
+        0x20a456f09a197de2,
+    ]),
+    Fr([
+        0x4de77f13a0f30c20,
+        0x239af94fa75e3a2c,
+        0x0ee9b5be860dacf1,
+        0x1c1ea86d4091a36b,
+    ]),
+    Fr([
+        0x5e902a596bb6d9df,
+        0x136da47e715822b9,
+        0x32525cef28ddc7bb,
+        0x150701cc4e7bc83a,
+    ]),
+    Fr([
+        0x7b7e0be926969bb4,
+        0xa10a1d8fa1d20b8a,
+        0xb75a24947f050d8b,
+        0x1c1ddbd69e2f266f,
+    ]),
+    Fr([
+        0xadadc9b28e3651de,
+        0xd6ed5d90d5185d08,
+        0x0518c9e48c734eee,
+        0x073ce38550e0418d,
+    ]),
+    Fr([
+        0xfc4d579035eb7492,
+        0xa18ba48559397708,
+        0x2191d2fa413e16ac,
+        0x0608af7178d9ddd5,
+    ]),
+    Fr([
+        0x5c77fbcdba4ad6b0,
+        0xc9930b0366a91213,
+        0xd9d5f670050425fc,
+        0x24f7c7ae8deb788a,
+    ]),
+    Fr([
+        0x869abf12402fc7ae,
+        0x7411020760a9d758,
+        0xd01295d913376448,
+        0x015329e8b4453b0b,
+    ]),
+    Fr([
+        0x74a1ed2a19e18e0e,
+        0xe762cf9b0e018a92,
+        0x4936b3fddc5e6d7a,
+        0x07607132809b8323,
+    ]),
+    Fr([
+        0xfa234593dedd1d05,
+        0x92e86e9b3ea5f7fc,
+        0x11c8a154aece5744,
+        0x0c550415d466454b,
+    ]),
+    Fr([
+        0x5892c1d31caff13a,
+        0x6a542fed580a85e0,
+        0xb56668ce6ccd57e2,
+        0x0e61f8e72756a987,
+    ]),
+    Fr([
+        0x71844594462c21b5,
+        0x488af1f2f931daf1,
+        0xadd4a5dddefe5888,
+        0x21636076cf956fc1,
+    ]),
+    Fr([
+        0x23e78fbbc6a32a1b,
+        0x6f5fa329cd0991d1,
+        0xbb4abb718dd8f455,
+        0x2bcfbfa5e6cd40cc,
+    ]),
+    Fr([
+        0x0808e0ce628624cb,
+        0xecd0e19a0a73bd8a,
+        0xbd7d87436139e967,
+        0x280b5a0cc2bb5816,
+    ]),
+    Fr([
+        0x3cff1714d68e8d72,
+        0x534bdf1d429bff8d,
+        0x5809ab0c50bc2ed1,
+        0x14d045b98ed5c329,
+    ]),
+    Fr([
+        0xb93e3c39f6a985ad,
+        0x9389555a40064049,
+        0xa8950c8cbb8ddfad,
+        0x173cd6bacf604287,
+    ]),
+    Fr([
+        0xaf9ac6edc684750f,
+        0x0846f4e627d89d37,
+        0x10e7b1743aa64625,
+        0x22b807f206ebed35,
+    ]),
+    Fr([
+        0x9908704fd85cfd9f,
+        0x59c69d60ac1a2fc7,
+        0x02fcbb185c51fd9b,
+        0x2a165b20fe194c1c,
+    ]),
+    Fr([
+        0xa99a2175e3244e6d,
+        0x9a6dab1e4d9ed982,
+        0xc06a275c7e9e49b5,
+        0x0b797dec795e19c3,
+    ]),
+    Fr([
+        0xb8d4c54c740aa4e0,
+        0xd3926f418db1c55d,
+        0x51b30e1a4a7aa4d0,
+        0x1b4fc743c0bf5454,
+    ]),
+    Fr([
+        0xf0b12bc56dd185b8,
+        0x82415e2c23c0315e,
+        0xaf6e94b09e59634c,
+        0x0ebdb449a73d43ef,
+    ]),
+    Fr([
+        0x35ec8659e800b04f,
+        0x43b48df13d76b59b,
+        0x4a88ad3cb7d7d4b7,
+        0x2c29e8429c50a35e,
+    ]),
+    Fr([
+        0xb22a0be1ff80c622,
+        0xad24c279a4b292f0,
+        0xdbace07b2b405ee5,
+        0x188dc2b37414f6e0,
+    ]),
+    Fr([
+        0x854fbedfc6812b82,
+        0xe114f6cae4f1be19,
+        0xb302c97771564a5b,
+        0x114df50adf15cace,
+    ]),
+    Fr([
+        0xe0790f0d2c2016f4,
+        0x9cbf563518ca6ca2,
+        0xb17084e5718902fc,
+        0x10a14bb19c5aa476,
+    ]),
+    Fr([
+        0x81366a956da0dedf,
+        0xa59d99f64cd45ca7,
+        0xe2b6cf4c4c75ffb4,
+        0x2019a466867c90b6,
+    ]),
+    Fr([
+        0x217a061c77d96cea,
+        0xd5757880bf412318,
+        0x4afd5c8e76865d25,
+        0x0e510f9fb5ce1807,
+    ]),
+    Fr([
+        0x4e80f1691625b1a2,
+        0x7272645cbd611728,
+        0x5d8b0ff1c4d732d0,
+        0x2108cff86c22c765,
+    ]),
+    Fr([
+        0x0852bbbc2ed04b76,
+        0x78586fe8ae649077,
+        0xaa2ef1e436f3bcb5,
+        0x269683e08d24af59,
+    ]),
+    Fr([
+        0x1f65272a50a5a9a9,
+        0x9014d4ef9e15a910,
+        0x2edfa637e91c7093,
+        0x0e97879fb3eb72be,
+    ]),
+    Fr([
+        0x2f90a84b9840f8e2,
+        0x1044b28500c767be,
+        0xee5859b5772a557c,
+        0x09f1c7e818f87272,
+    ]),
+    Fr([
+        0x5f77a4bcc8d0e3d2,
+        0xc0dc6053804c6117,
+        0xe788b42c22246e25,
+        0x02dc34c216472c4a,
+    ]),
+    Fr([
+        0x9321a802d463f3d5,
+        0x83bee316e6217264,
+        0x911899e8c28b626a,
+        0x10365af0f6bf6aed,
+    ]),
+    Fr([
+        0x7fec064766cd1a6e,
+        0x046138816210957b,
+        0x6a6f3fef61d89dee,
+        0x0ab910a8636eb60b,
+    ]),
+    Fr([
+        0x17a95ea5901d4b4b,
+        0xbf8e75143d9cbf69,
+        0x3b9223c11bc9a755,
+        0x0d7564c6c4ca54e1,
+    ]),
+    Fr([
+        0xbc5c42f07ef7a405,
+        0x7daca377d41381e1,
+        0xab8a7c54bc1afbc2,
+        0x1e0420480d3601db,
+    ]),
+    Fr([
+        0xa44af9e83e706a72,
+        0x30467cc6889c68af,
+        0x64fadc31a380f886,
+        0x0c6a44b133ce5468,
+    ]),
+    Fr([
+        0x419632d8447fbbac,
+        0x5b160f32939cb250,
+        0xee8943f1c95f0506,
+        0x040d52be69f2ba2e,
+    ]),
+    Fr([
+        0x9fb340c109120444,
+        0xa3b53a70cee1b541,
+        0xe178d19117964e79,
+        0x1c316b3637a2d117,
+    ]),
+    Fr([
+        0x228e93bfbfe7b701,
+        0xe600eb51f58b7173,
+        0x1780bb02c4a7a7a7,
+        0x17207b2240a0ed38,
+    ]),
+    Fr([
+        0xa136f0dc41a2c061,
+        0x5750fef1394380b1,
+        0xa85d8e513997747f,
+        0x0a9690842279da9d,
+    ]),
+    Fr([
+        0xadc82accb4da0421,
+        0xb2caad1b413968ed,
+        0xc8a3281e29135dd0,
+        0x2e4e84eccf8e640d,
+    ]),
+    Fr([
+        0x0fdec635e98bc3c4,
+        0x8ef9ac8744b1e318,
+        0xc79be46ad692d392,
+        0x07d4e90415338a67,
+    ]),
+    Fr([
+        0x24b234f059c39fa0,
+        0xcf2ae865ff76e7d5,
+        0x13987a15fbacb417,
+        0x0bcebec43aec0fe4,
+    ]),
+    Fr([
+        0x22d4ce4d385ef866,
+        0xd6506c3b58201178,
+        0x83c51106691dd37f,
+        0x084bea5184e9bfa4,
+    ]),
+    Fr([
+        0x9c77d747263244c1,
+        0x659019a11195b6a9,
+        0x9511a61ec902de91,
+        0x184048c60a83e06b,
+    ]),
+    Fr([
+        0xb68afb7d17ca7f74,
+        0x17ced1fe273051bd,
+        0x3351e20e1b303ffc,
+        0x1bf06a7ea1655033,
+    ]),
+    Fr([
+        0x33f3e61940f148c5,
+        0x45a1da3e22bbd5e6,
+        0x9f1b6cca298e2385,
+        0x1758fd7f5a703fd6,
+    ]),
+    Fr([
+        0x1a929fe79e54eabc,
+        0xeb7a4050f23efb0e,
+        0xe25079a2ef3a4399,
+        0x24377e4f8c161340,
+    ]),
+    Fr([
+        0xd7eaf8cd38c7e4c3,
+        0x9d688ad8e3b18d4f,
+        0x3059007f8e528db3,
+        0x2ba5812b9a603154,
+    ]),
+    Fr([
+        0x347eefb756ac1cd3,
+        0xcf154053cabb2dd3,
+        0x40662cdec5906a43,
+        0x24f8c0dfb5a57c88,
+    ]),
+    Fr([
+        0x83a8b78cdf45ae1d,
+        0xc35a68eddc447d67,
+        0x1e0631f731313cf5,
+        0x0695de707e5015b5,
+    ]),
+    Fr([
+        0x899501116739a0b9,
+        0xf41302caaabda74e,
+        0x9daf05fa51e2b577,
+        0x00a1823ed5cab6c3,
+    ]),
+    Fr([
+        0xcd573c0b9ddb7d23,
+        0xe2330b36994c8bc8,
+        0x3610a9a7975d8c5e,
+        0x03b957e376d3b540,
+    ]),
+    Fr([
+        0x1a9e14d51c7ca94d,
+        0x30bf943e1a14619e,
+        0x1417b62b1593a092,
+        0x24f07dcc09f05c17,
+    ]),
+    Fr([
+        0x3786ae29f089ee8f,
+        0x14aab5e99ed71801,
+        0x2af4f353a483b985,
+        0x29555d4ed1fe4fbd,
+    ]),
+    Fr([
+        0x8c7e6599c82266f5,
+        0x8450eefe131bfeeb,
+        0x2141aa50ae9c1560,
+        0x1ee3d5f34f25a97d,
+    ]),
+    Fr([
+        0x10939cbcf5f2ac91,
+        0x48fae156719c7ed6,
+        0x9bb952c265573087,
+        0x272673388c49a89f,
+    ]),
+    Fr([
+        0x1987c905ef781f7c,
+        0x8b0b57252a061c91,
+        0x8daef0652916aecc,
+        0x0d695d161817c12c,
+    ]),
+    Fr([
+        0x9b33ef9bd4d5d3a1,
+        0x06c73061272cbb8a,
+        0x54aeb39ec55c7d9e,
+        0x17302c913cef778e,
+    ]),
+    Fr([
+        0x7743f9d77edc84a9,
+        0x0837dea3a918265c,
+        0xe083feca62c33d42,
+        0x0838d05886b1128e,
+    ]),
+    Fr([
+        0x20248167321622af,
+        0x925b50a1c0ac0630,
+        0x4999b53dc984ba90,
+        0x11dd2d0e2bc5e00d,
+    ]),
+    Fr([
+        0x0d246427c28da3ee,
+        0x949eff9804df6a12,
+        0xdb5f49b2f04ff98e,
+        0x07419b83608220ba,
+    ]),
+    Fr([
+        0x18836051a3dcfd42,
+        0x59cbcb56f2750e1d,
+        0x414992bb17367459,
+        0x0f97423e94ff3f15,
+    ]),
+    Fr([
+        0xebdcda471a54f684,
+        0x0d5b255729f4bce8,
+        0x8da5fb1439c21843,
+        0x1e4fa6abc631b4d9,
+    ]),
+    Fr([
+        0xa34c7cabbf77bff3,
+        0x7ed19d45e0cbd265,
+        0x552569b3976abf9b,
+        0x2102a42d31e65219,
+    ]),
+    Fr([
+        0xa71ea9feb2976fe7,
+        0x0bcbbc71bdbf3efd,
+        0x7c1de527a92b6749,
+        0x10bba45bc3c2b257,
+    ]),
+    Fr([
+        0xf9d6194270590ec8,
+        0xe32ae87ab03c6133,
+        0x0a79ee024465ed7d,
+        0x13ad9a7d9b5c4e18,
+    ]),
+    Fr([
+        0x993e031c5692c315,
+        0x954559258ffdd98e,
+        0xae079dda309985d8,
+        0x2f2118e35c224d0b,
+    ]),
+    Fr([
+        0x6c0c4a3ec0f29525,
+        0x40d6a04330fb0272,
+        0x04277ef7f8310f89,
+        0x1be9af9d3f3cfee8,
+    ]),
+    Fr([
+        0x2ef36c1ed645075c,
+        0x75020dd17b209435,
+        0x46d2d88cf270ddaf,
+        0x092f58dea8634aab,
+    ]),
+    Fr([
+        0xd1d946bdebc3168a,
+        0x50c19dc2ebcd5a66,
+        0x2c1ff6a7048b8dfd,
+        0x20f11793efb546bf,
+    ]),
+    Fr([
+        0xe5fe88403a2eeb08,
+        0x7e137ccf641d5355,
+        0x3e07224f16e015b6,
+        0x051aa72bbb280bd0,
+    ]),
+    Fr([
+        0x4e9878750691087e,
+        0xfa3cca7a6703170b,
+        0xaedcef07fe48b4f7,
+        0x0ffca06f7ba1d9ad,
+    ]),
+    Fr([
+        0x046580ad1e787eb7,
+        0x821d475374e3caa0,
+        0x570d693273ce7432,
+        0x098bee97eec22e0c,
+    ]),
+    Fr([
+        0xee01be092608ca38,
+        0x9ae8db97ec7213f0,
+        0xcd94e9911498661f,
+        0x063cebc9d48c4b16,
+    ]),
+    Fr([
+        0xc9f0f4202f5302f1,
+        0x9f095124684e5590,
+        0x77f7877d1af0b7ae,
+        0x2102a686942ed886,
+    ]),
+    Fr([
+        0xa922f27f28abdbef,
+        0xce9d05a501c4a686,
+        0x93af2c647cd9f97e,
+        0x15122514d38426a7,
+    ]),
+    Fr([
+        0x40096998f6d0958e,
+        0x6c9067883a006f2c,
+        0x47c12fa2b3df349c,
+        0x01e1206fd7a499b7,
+    ]),
+    Fr([
+        0x09a576cfc3ed0135,
+        0xc2a8ec597cda2043,
+        0x1e71fabe8885be3f,
+        0x12ffc9a6438ca230,
+    ]),
+    Fr([
+        0x3dfe98919729d14e,
+        0x2d1a830be6a7d93a,
+        0xd4b50f79ac794e45,
+        0x1609b588e4b6255a,
+    ]),
+    Fr([
+        0xfe0f03062f910a79,
+        0x12b30f8c764aacb1,
+        0x071e359c68255f5c,
+        0x05ad045acb0a6de5,
+    ]),
+    Fr([
+        0x8cac1102b2cfcafe,
+        0xf8641260ba9ff7b4,
+        0x5d408afe6356b7fc,
+        0x1043e7f9b8c42e63,
+    ]),
+    Fr([
+        0x8553014b785010b7,
+        0xf5110e3a0be2fe70,
+        0x7525dcae2a87117e,
+        0x08b4b93f80c28df6,
+    ]),
+    Fr([
+        0x0351052268ae14a8,
+        0x2143bff152810d7e,
+        0x3ba34068873880e7,
+        0x1a27593714889c67,
+    ]),
+    Fr([
+        0x17a2b5bd2973e07c,
+        0xc2adb8e2cee7fa22,
+        0x310f8de36e110362,
+        0x297d94b76f76534e,
+    ]),
+    Fr([
+        0x51d81fecad91be7f,
+        0x91e296b75fed0f14,
+        0xffb2f2fe9fa9003b,
+        0x033fa4e84c6e92aa,
+    ]),
+    Fr([
+        0x2b1d49f32f0b0a2a,
+        0x684821a554f5a304,
+        0x7d5412dfa7668473,
+        0x190fb522bec2c138,
+    ]),
+    Fr([
+        0xae690bdf16c838a1,
+        0xe0739bf7c039a838,
+        0xd6a0b76d0f102e7a,
+        0x1796dc30c3f70ce5,
+    ]),
+    Fr([
+        0x1c7e2cddb2a6b28f,
+        0xb1f864b85b8532d0,
+        0x6625e36443287b36,
+        0x056506367354a3b7,
+    ]),
+    Fr([
+        0x2049bfbcfd6d6a28,
+        0x7bb4303d8fe81185,
+        0x127dffc4f0755310,
+        0x07d45d0757035ca3,
+    ]),
+    Fr([
+        0x9a97fc22bec1eaaf,
+        0x99b2914b133d0a3c,
+        0x0b3303d9730f13dc,
+        0x0a1a930ed357c473,
+    ]),
+    Fr([
+        0x20b55d2bcbdb3803,
+        0x8b6d6827e85599f3,
+        0x9cd20f39c515325e,
+        0x2347c266123dc523,
+    ]),
+    Fr([
+        0x9b51e3ba2ec67900,
+        0xc28fcb69601ce480,
+        0xddf62f3c201ec992,
+        0x1fa93e2f249f1654,
+    ]),
+    Fr([
+        0xe943d3aeb953c01d,
+        0x1f213d4759df3a59,
+        0x30120876a9a18dc5,
+        0x2e935d6c61eb0b0c,
+    ]),
+    Fr([
+        0x474a1da3de1f1503,
+        0x74548e96ca496b0c,
+        0x09d52791d4720de0,
+        0x287c6b0f41a01467,
+    ]),
+    Fr([
+        0xde16e2e8d1926b38,
+        0x64df4a6554e07f8e,
+        0xa1fb13776df1fe5d,
+        0x1df9d6c9bc67b3b5,
+    ]),
+    Fr([
+        0x290c14f0b6bbbb52,
+        0xec655f07fa1a6ab2,
+        0x17209b5800230840,
+        0x2bcafcaf1e5c23e8,
+    ]),
+    Fr([
+        0xc9692a287a3a4a41,
+        0x3f0236b75b1a5fd2,
+        0xd7a37108023f3112,
+        0x21dfaa273c3c49a9,
+    ]),
+    Fr([
+        0x22efb83e46a2c0d5,
+        0x81d6dda51c8cd637,
+        0xece59d1ed0b018fe,
+        0x1a2b0b4e5e72603b,
+    ]),
+    Fr([
+        0x6dc9066fa1149517,
+        0x0e99467d5b969478,
+        0x0932a4ccf44e25c6,
+        0x0229273fa914e12d,
+    ]),
+    Fr([
+        0xa8d254dbf311d474,
+        0xa1c9abd8fccc263f,
+        0x936223ae609ea10b,
+        0x25a33a3a18ad9b95,
+    ]),
+    Fr([
+        0x3ba57c39fe16e1d0,
+        0xaa9caacc92542499,
+        0x63b371a57ea9d439,
+        0x203a0099556072b4,
+    ]),
+    Fr([
+        0xbfd1e6093770dedc,
+        0x7c61f5d15aec7adc,
+        0xab8c467264b182b7,
+        0x0ac513dce3098f42,
+    ]),
+    Fr([
+        0x09556764d13dce43,
+        0x789b6b398ecf3ca2,
+        0x71e1625ae8cc5d65,
+        0x1b9b78909989620b,
+    ]),
+    Fr([
+        0xbaf9b099361f4566,
+        0xad09d1eb348485ec,
+        0xb47e86241e7fd8db,
+        0x07cecc143e7d6575,
+    ]),
+    Fr([
+        0x42a2d8bf759fa82c,
+        0xced734cd2133b372,
+        0xe0024cd88930e2ae,
+        0x22318af5182b6ca1,
+    ]),
+    Fr([
+        0xc354d05b1e3564e2,
+        0xfd7188670b9643cc,
+        0x14fd18729642b4a0,
+        0x0d51272bfa735857,
+    ]),
+    Fr([
+        0xb348063f00c99814,
+        0xa5412848daaee43c,
+        0xaf55ed76215373ce,
+        0x124e428f009d373e,
+    ]),
+    Fr([
+        0xe57c360aae3f34a8,
+        0xf42de83ed2de316b,
+        0x30897a6c5757d3b4,
+        0x04e995db28ad4d2a,
+    ]),
+    Fr([
+        0xe13e119bc37d6c8f,
+        0x02f61b5d76f759b0,
+        0xc6b47d236891e176,
+        0x0008b17e2a72a2ae,
+    ]),
+    Fr([
+        0x2fd8a24755180b46,
+        0x0a5ebff9fa6f6f10,
+        0x1dfc8b1c4773facc,
+        0x00d822599162dfdf,
+    ]),
+    Fr([
+        0xb0a0b5dcfa3b9c38,
+        0xc1e38c7e2e80c619,
+        0x30f65a8c3959e3c5,
+        0x21debd0d608920ba,
+    ]),
+    Fr([
+        0x9793629a20e98afa,
+        0xb0f9a2eaea103653,
+        0x2120c27bba6653b2,
+        0x2d12eee484a0036f,
+    ]),
+    Fr([
+        0xceeb2c202b1fbe1f,
+        0x2fe541acbf8c0498,
+        0x2aba3e57e10506a1,
+        0x08ba122fcb735020,
+    ]),
+    Fr([
+        0x9828204e8e2b6a9c,
+        0x5a3a6f15eeb867e0,
+        0x32454dd332b17a31,
+        0x0b31858317cb478c,
+    ]),
+    Fr([
+        0xd8b13dfb140e44ec,
+        0x96c29b6ffe448479,
+        0x9b635302d1a0c781,
+        0x086661ac08af1243,
+    ]),
+    Fr([
+        0x111a91f7ca2de5fe,
+        0xed1c30d4bc5f9da7,
+        0xace7a99a136179c6,
+        0x1af7af57638f7afc,
+    ]),
+    Fr([
+        0xf2ebc65a68301f70,
+        0x098438fc5cfb70fa,
+        0x1804b70dbc7519fa,
+        0x1d22f3c500139637,
+    ]),
+    Fr([
+        0x5448cb4bc2899170,
+        0xf7fe3031d6326ee3,
+        0x78948b2f4ee9574d,
+        0x1005a13172350117,
+    ]),
+    Fr([
+        0x682b542cad358ff3,
+        0x5724e9dd9fc8f36b,
+        0x2ba5e23128ed1f4a,
+        0x11c31ebae126fd4f,
+    ]),
+    Fr([
+        0x477f38901e5952b2,
+        0x9d1a3f4d707c35e0,
+        0x91302c538bb1afaa,
+        0x063894ace6abbc7e,
+    ]),
+    Fr([
+        0x6074b47ef0006e30,
+        0x5986d0b36ae50101,
+        0x20d19dafa473c211,
+        0x2f173440a4883865,
+    ]),
+    Fr([
+        0xaa20964deafe0b91,
+        0xe2a7e6ca5edd4a1f,
+        0x057ba486f576e66d,
+        0x205bf32d4e1481c5,
+    ]),
+    Fr([
+        0x8e742f9ec6841d0b,
+        0x31061ccfad5a5217,
+        0x7b71500b33057f2a,
+        0x16daea714103de31,
+    ]),
+    Fr([
+        0x11abbad157bd1752,
+        0x086b0bfa4ca1a650,
+        0x620544b4b2dc4a34,
+        0x0bc77c690c4727e9,
+    ]),
+    Fr([
+        0xd592b3bd0452454e,
+        0xfcb2a667b4f8a458,
+        0xb0591d7fa9013240,
+        0x181ad6d3181e4869,
+    ]),
+    Fr([
+        0x75df9ada1c6e61c7,
+        0xe7865ac098d96a8d,
+        0x4e79b52ca624a1bb,
+        0x2eb9734444d29531,
+    ]),
+    Fr([
+        0x56252281af9dd325,
+        0x2b56cd8ca643e6aa,
+        0x001bdcf70d097969,
+        0x050fcb4ec2ddeb58,
+    ]),
+    Fr([
+        0xc520cddd629cf7cf,
+        0x1dfe1f44cce3fc7f,
+        0x82e70c14357cc3ca,
+        0x1e529e7b2aad97ea,
+    ]),
+    Fr([
+        0xad0e31cb9df06818,
+        0x981d398bf7cbcb97,
+        0xb27a6760ea88d928,
+        0x29acb22cdc4819cc,
+    ]),
+    Fr([
+        0x018bb277e22b3484,
+        0x5112b64eddb72d72,
+        0xf78b3bcffa1ad009,
+        0x11829e68b5f4754b,
+    ]),
+    Fr([
+        0x7479e5e3057e0adf,
+        0xde6f62ade16366ac,
+        0x5dafb1e94b75a7a3,
+        0x05439e7c6850556d,
+    ]),
+    Fr([
+        0x429db1686714e521,
+        0x4069a1ff88a85207,
+        0xa771b0afd8bee2df,
+        0x03fce5510d8086a4,
+    ]),
+    Fr([
+        0x38b2bf4062f50734,
+        0xe9ce00492ef1cae8,
+        0x327429d62062a64a,
+        0x1cc78575b13144a3,
+    ]),
+    Fr([
+        0x8fe7bf8806045b99,
+        0x0eaf5157e1a05477,
+        0x509622ea5ead2b6e,
+        0x0348bc748879507c,
+    ]),
+    Fr([
+        0xa10163a768b038fb,
+        0x8c2d3eabdce9be3f,
+        0xb2bbe02028e58741,
+        0x20d27adef4ab0768,
+    ]),
+    Fr([
+        0x7233916fa65363e1,
+        0x67395b53fbf62a40,
+        0x877b9c7ee0adb055,
+        0x2260000302501716,
+    ]),
+];
+
+/// MDS matrix for `t = 5`, row-major.
+const MDS_5: [[Fr; 5]; 5] = [
+    [
+        Fr([
+            0x77464b55cd95efca,
+            0x68ba7a74ae0e5894,
+            0xbd4dc1c2266c359d,
+            0x2967c834940e37a0,
+        ]),
+        Fr([
+            0x9d7560eab0fe4046,
+            0x35aebb7e1cbabfde,
+            0x046f4c2b5ffaab98,
+            0x10c9d5b18c43b9ea,
+        ]),
+        Fr([
+            0xb866652e4f26da85,
+            0xb9e2d4c767608cb5,
+            0x7266982acf0812ff,
+            0x1075bbdae372b70d,
+        ]),
+        Fr([
+            0x6190b23770183886,
+            0x101d044302cb2858,
+            0xecd03dccfbeaf617,
+            0x0b084598422035a5,
+        ]),
+        Fr([
+            0x71d451ca47c3e06f,
+            0x1a4dc1da0d245f85,
+            0x4812497a20f7afce,
+            0x02d1c2ecb1969e4b,
+        ]),
+    ],
+    [
+        Fr([
+            0x6907e36200995439,
+            0xb9f80b5666c65169,
+            0x7ba328f07ebc2640,
+            0x152d921c334deb59,
+        ]),
+        Fr([
+            0x9de26ee0faaa6230,
+            0x8b3cedd3678272c4,
+            0xbf689106033676ec,
+            0x0a4f014b431ef663,
+        ]),
+        Fr([
+            0x0abe2754c2279be8,
+            0xf34d6acdb0ef8be1,
+            0x638c985fb12509f5,
+            0x0ce4a0756717cd0d,
+        ]),
+        Fr([
+            0x4ff66343628de773,
+            0x8669e3967283e9d5,
+            0xdbdb4492fd9478a1,
+            0x2a172f4971297058,
+        ]),
+        Fr([
+            0xa96b93484bd7274b,
+            0xb6ffb6120bbc6f39,
+            0x4f8cc3b20738a669,
+            0x26d0dab233956299,
+        ]),
+    ],
+    [
+        Fr([
+            0x235bc3071b88c57f,
+            0x1edd9e8b512a928b,
+            0x4eba9db9a285a5db,
+            0x208c85cecd6e86b2,
+        ]),
+        Fr([
+            0x8b7a04145ef1d11a,
+            0xed5ccb60d2f55df9,
+            0xc0463074d5d84b7c,
+            0x0fc883bdcf417770,
+        ]),
+        Fr([
+            0x16ef19d92023860d,
+            0x97313a990cdaa693,
+            0xfa536002a38deb76,
+            0x157c584bf12b5fc2,
+        ]),
+        Fr([
+            0x26b36d6f81141445,
+            0x46db4e5f5c0c0592,
+            0x1c8ff6641950ef7f,
+            0x03831bb3c0404ec0,
+        ]),
+        Fr([
+            0xe693b6e9a4a622a4,
+            0xd3c7b489ce3e9706,
+            0x97a65d65e20440eb,
+            0x01c50a5a391d3e7f,
+        ]),
+    ],
+    [
+        Fr([
+            0xd7e96fada4cc7131,
+            0xe05eeb104bdd4f26,
+            0xd629a31acc8b39c6,
+            0x292e987009256cb4,
+        ]),
+        Fr([
+            0xc7a0f540e19091eb,
+            0xd6b9fc0427f1efb4,
+            0xd709082fce71505b,
+            0x2c2f39bf3fb689c1,
+        ]),
+        Fr([
+            0x32ec79c4fa39b5e0,
+            0x07e1d8f6dc66882f,
+            0xdafcf6f32b1b7f1f,
+            0x0b80626e4af5efe5,
+        ]),
+        Fr([
+            0x48268958c0294633,
+            0xe32eaddae7cd0cfb,
+            0x83f515af535c5f73,
+            0x0eb68faa42851083,
+        ]),
+        Fr([
+            0xde28a4428ec83e3a,
+            0xc302d6eb2a211388,
+            0x78e5ca7195aeb86e,
+            0x1f159c9528951410,
+        ]),
+    ],
+    [
+        Fr([
+            0x9337ce2160d27631,
+            0xb7603b2e38f0d93e,
+            0xba04b96b55dfec38,
+            0x25c45b9bb527b189,
+        ]),
+        Fr([
+            0x570517f8d7bf3625,
+            0x06f64bcced634daf,
+            0x85747cad8e788981,
+            0x240f49cb93d117d5,
+        ]),
+        Fr([
+            0x74572ba3822678b6,
+            0x1178400143204c5f,
+            0x46e8e28cd12c3a6f,
+            0x10b1d99213e5666e,
+        ]),
+        Fr([
+            0x1c641486ade67a7a,
+            0x4b50719a5e10222c,
+            0x9f5dd44f4cc1d827,
+            0x01b5b9eef181679f,
+        ]),
+        Fr([
+            0xfeb302a5110d9eb0,
+            0xc251af52f6c4abc6,
+            0xff454cd9ef575da7,
+            0x1ab6f8eace913fdb,
+        ]),
+    ],
+];
@@ -0,0 +1,158 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Map, Vec};
+
+use crate::address_manager::{AddressManager, ChainAddrKey};
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::privacy_policy_set_event;
+use crate::registration::Registration;
+use crate::storage;
+use crate::types::{
+    ChainType, DelegateRole, PrivacyMode, PrivacyPolicy, RecordView, ResolveData, Visibility,
+};
+
+pub struct Privacy;
+
+impl Privacy {
+    /// Sets who may read each class of a commitment's resolution fields.
+    ///
+    /// Public getters (`resolve`, `resolve_stellar`, the chain, Stellar and shielded
+    /// address getters, and the change history) return only `Public` fields and treat
+    /// the rest as unset. The owner and, for `DelegatesOnly` fields, active delegates read
+    /// everything through [`Self::resolve_records`].
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Records` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `policy`: The per-field visibility.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Records` delegate.
+    ///
+    /// ### Events
+    /// - Emits `PRIVACY_POLICY` event with (commitment, policy).
+    pub fn set_privacy_policy(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        policy: PrivacyPolicy,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Records));
+        storage::set_privacy_policy(&env, &commitment, &policy);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((privacy_policy_set_event(&env),), (commitment, policy));
+    }
+
+    /// Returns the privacy policy of a commitment, all `Public` if never set.
+    pub fn get_privacy_policy(env: Env, commitment: BytesN<32>) -> PrivacyPolicy {
+        storage::get_privacy_policy(&env, &commitment).unwrap_or_else(|| Self::default_policy(&env))
+    }
+
+    /// Returns every resolution field `viewer` may read under the privacy policy.
+    ///
+    /// The owner reads all fields, active delegates of any role read `Public` and
+    /// `DelegatesOnly` fields, and anyone else reads `Public` fields only.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `viewer`: The reader. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    pub fn resolve_records(env: Env, viewer: Address, commitment: BytesN<32>) -> RecordView {
+        viewer.require_auth();
+        let owner = Registration::get_owner(env.clone(), commitment.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        let policy = Self::policy(&env, &commitment);
+        let readable = |visibility: Visibility| match visibility {
+            Visibility::Public => true,
+            Visibility::Hidden => viewer == owner,
+            Visibility::DelegatesOnly => Self::is_delegate(&env, &owner, &viewer, &commitment),
+        };
+
+        let resolver = env
+            .storage()
+            .persistent()
+            .get::<storage::DataKey, ResolveData>(&storage::DataKey::Resolver(commitment.clone()));
+        let mut chain_addresses = Map::new(&env);
+        for chain in [
+            ChainType::Evm,
+            ChainType::Bitcoin,
+            ChainType::Solana,
+            ChainType::Cosmos,
+        ] {
+            if !readable(Self::chain_visibility(&policy, &chain)) {
+                continue;
+            }
+            let key = ChainAddrKey::ChainAddress(commitment.clone(), chain.clone());
+            if let Some(address) = env.storage().persistent().get(&key) {
+                chain_addresses.set(chain, address);
+            }
+        }
+
+        RecordView {
+            wallet: resolver
+                .clone()
+                .filter(|_| readable(policy.primary))
+                .map(|data| data.wallet),
+            memo: resolver
+                .filter(|_| readable(policy.memo))
+                .and_then(|data| data.memo),
+            stellar_addresses: if readable(policy.stellar) {
+                AddressManager::stellar_addresses(&env, &commitment)
+            } else {
+                Vec::new(&env)
+            },
+            chain_addresses,
+            shielded_address: storage::get_shielded_address(&env, &commitment)
+                .filter(|_| readable(policy.shielded)),
+        }
+    }
+
+    /// (Internal) Returns the effective policy, with `PrivacyMode::Shielded` hiding a
+    /// public primary wallet.
+    pub(crate) fn policy(env: &Env, commitment: &BytesN<32>) -> PrivacyPolicy {
+        let mut policy = Self::get_privacy_policy(env.clone(), commitment.clone());
+        if policy.primary == Visibility::Public
+            && storage::get_privacy_mode(env, commitment) == PrivacyMode::Shielded
+        {
+            policy.primary = Visibility::Hidden;
+        }
+        policy
+    }
+
+    /// (Internal) Returns the visibility of one chain's addresses.
+    pub(crate) fn chain_visibility(policy: &PrivacyPolicy, chain: &ChainType) -> Visibility {
+        policy
+            .chains
+            .get(chain.clone())
+            .unwrap_or(Visibility::Public)
+    }
+
+    /// (Internal) Returns whether `viewer` is the owner or holds any active delegate grant.
+    fn is_delegate(env: &Env, owner: &Address, viewer: &Address, commitment: &BytesN<32>) -> bool {
+        [
+            DelegateRole::Records,
+            DelegateRole::Addresses,
+            DelegateRole::Payments,
+        ]
+        .into_iter()
+        .any(|role| Delegates::is_authorized(env, owner, viewer, commitment, role))
+    }
+
+    /// (Internal) Returns the policy with every field `Public`.
+    fn default_policy(env: &Env) -> PrivacyPolicy {
+        PrivacyPolicy {
+            primary: Visibility::Public,
+            stellar: Visibility::Public,
+            chains: Map::new(env),
+            memo: Visibility::Public,
+            shielded: Visibility::Public,
+            text: Visibility::Public,
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Map, Vec};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{
+    guardians_set_event, recovery_approved_event, recovery_executed_event, recovery_vetoed_event,
+    TRANSFER_EVENT,
+};
+use crate::registration::Registration;
+use crate::storage::{self, MAX_GUARDIANS};
+use crate::transfer::Transfer;
+use crate::types::{GuardianConfig, RecoveryRequest};
+
+pub struct Recovery;
+
+impl Recovery {
+    /// Configures the M-of-N guardian set able to recover a commitment.
+    ///
+    /// Replacing the configuration discards any in-flight recovery request, since
+    /// its approvals were collected under the old guardian set.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `guardians`: Distinct guardian addresses, at most `MAX_GUARDIANS`.
+    /// - `threshold`: Number of guardian approvals required, `1..=guardians.len()`.
+    /// - `delay`: Seconds between reaching the threshold and the recovery becoming executable.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `InvalidGuardianConfig`: If the guardian set or threshold is invalid.
+    ///
+    /// ### Events
+    /// - Emits `GUARDIANS_SET` event with (commitment, guardians, threshold, delay).
+    pub fn set_guardians(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        guardians: Vec<Address>,
+        threshold: u32,
+        delay: u64,
+    ) {
+        let owner = Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::configure(&env, &commitment, &owner, guardians, threshold, delay);
+    }
+
+    /// (Internal) Replaces the guardian set once `owner` has been authorized.
+    ///
+    /// Shared by [`Self::set_guardians`] and group-owned commitments.
+    pub(crate) fn configure(
+        env: &Env,
+        commitment: &BytesN<32>,
+        owner: &Address,
+        guardians: Vec<Address>,
+        threshold: u32,
+        delay: u64,
+    ) {
+        if guardians.len() > MAX_GUARDIANS || threshold == 0 || threshold > guardians.len() {
+            panic_with_error!(env, CoreError::InvalidGuardianConfig);
+        }
+        for (i, guardian) in guardians.iter().enumerate() {
+            if guardian == *owner || guardians.first_index_of(&guardian) != Some(i as u32) {
+                panic_with_error!(env, CoreError::InvalidGuardianConfig);
+            }
+        }
+
+        let config = GuardianConfig {
+            guardians: guardians.clone(),
+            threshold,
+            delay,
+        };
+        storage::set_guardians(env, commitment, &config);
+        storage::remove_recovery(env, commitment);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (guardians_set_event(env),),
+            (commitment.clone(), guardians, threshold, delay),
+        );
+    }
+
+    /// Approves recovery of a commitment to `new_owner`.
+    ///
+    /// The first approval opens the request. Until the request reaches `threshold`, a
+    /// guardian may back a different `new_owner` instead; once that owner has more
+    /// approvals than the open request it replaces it, so a single guardian cannot block
+    /// recovery by opening a request first. Each guardian approves once per request. Once
+    /// `threshold` approvals are collected the timelock starts and the request becomes
+    /// executable `delay` seconds later.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `guardian`: A configured guardian. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `new_owner`: The address that will own the commitment after recovery.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no guardians.
+    /// - `Unauthorized`: If the caller is not a guardian.
+    /// - `SameOwner`: If `new_owner` is the current owner.
+    /// - `RecoveryInProgress`: If the open request has reached its threshold and names a
+    ///   different `new_owner`.
+    /// - `AlreadyApproved`: If the guardian already approved any owner in the open request.
+    ///
+    /// ### Events
+    /// - Emits `RECOVERY_APPROVED` event with (commitment, guardian, new_owner, approvals, executable_at).
+    pub fn recover(env: Env, guardian: Address, commitment: BytesN<32>, new_owner: Address) {
+        guardian.require_auth();
+        let owner = Registration::get_owner(env.clone(), commitment.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        let config = storage::get_guardians(&env, &commitment)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if !config.guardians.contains(&guardian) {
+            panic_with_error!(&env, CoreError::Unauthorized);
+        }
+        if new_owner == owner {
+            panic_with_error!(&env, CoreError::SameOwner);
+        }
+
+        let mut request = storage::get_recovery(&env, &commitment).unwrap_or(RecoveryRequest {
+            new_owner: new_owner.clone(),
+            approvals: Vec::new(&env),
+            executable_at: None,
+            challengers: Map::new(&env),
+        });
+        if request.approvals.contains(&guardian)
+            || request
+                .challengers
+                .values()
+                .iter()
+                .any(|backers| backers.contains(&guardian))
+        {
+            panic_with_error!(&env, CoreError::AlreadyApproved);
+        }
+        let approvals = if request.new_owner == new_owner {
+            request.approvals.push_back(guardian.clone());
+            request.approvals.len()
+        } else {
+            if request.executable_at.is_some() {
+                panic_with_error!(&env, CoreError::RecoveryInProgress);
+            }
+            Self::challenge(&env, &mut request, &guardian, &new_owner)
+        };
+        if request.executable_at.is_none() && request.approvals.len() >= config.threshold {
+            request.executable_at = Some(env.ledger().timestamp().saturating_add(config.delay));
+        }
+        storage::set_recovery(&env, &commitment, &request);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (recovery_approved_event(&env),),
+            (
+                commitment,
+                guardian,
+                new_owner,
+                approvals,
+                request.executable_at,
+            ),
+        );
+    }
+
+    /// Executes a recovery request once its timelock has elapsed.
+    ///
+    /// Permissionless: the guardian approvals are the authorization. Linked records
+    /// are kept, since recovery returns the name to the same person under a new key.
+    /// For a non-transferable username the contract owner must also authorize.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NoRecoveryRequest`: If there is no open request.
+    /// - `RecoveryNotReady`: If the threshold is not met or the timelock has not elapsed.
+    /// - `NotFound`: If the username is non-transferable and no contract owner is set.
+    ///
+    /// ### Events
+    /// - Emits `RECOVERY_EXECUTED` event with (commitment, old_owner, new_owner).
+    /// - Emits `TRANSFER_EVENT` with (commitment, old_owner, new_owner).
+    pub fn execute_recovery(env: Env, commitment: BytesN<32>) {
+        let request = storage::get_recovery(&env, &commitment)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NoRecoveryRequest));
+        match request.executable_at {
+            Some(at) if env.ledger().timestamp() >= at => {}
+            _ => panic_with_error!(&env, CoreError::RecoveryNotReady),
+        }
+        Self::require_admin_if_locked(&env, &commitment);
+        let old_owner = Registration::get_owner(env.clone(), commitment.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+
+        Transfer::set_owner(&env, &commitment, &request.new_owner);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (recovery_executed_event(&env),),
+            (
+                commitment.clone(),
+                old_owner.clone(),
+                request.new_owner.clone(),
+            ),
+        );
+        #[allow(deprecated)]
+        env.events().publish(
+            (TRANSFER_EVENT,),
+            (commitment, old_owner, request.new_owner),
+        );
+    }
+
+    /// Vetoes the open recovery request. Only the current owner may veto.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the commitment owner.
+    /// - `NoRecoveryRequest`: If there is no open request.
+    ///
+    /// ### Events
+    /// - Emits `RECOVERY_VETOED` event with (commitment, proposed_new_owner).
+    pub fn cancel_recovery(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::veto(&env, &commitment);
+    }
+
+    /// (Internal) Drops the open recovery request once the owner has been authorized.
+    ///
+    /// Shared by [`Self::cancel_recovery`] and group-owned commitments.
+    pub(crate) fn veto(env: &Env, commitment: &BytesN<32>) {
+        let request = storage::get_recovery(env, commitment)
+            .unwrap_or_else(|| panic_with_error!(env, CoreError::NoRecoveryRequest));
+        storage::remove_recovery(env, commitment);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (recovery_vetoed_event(env),),
+            (commitment.clone(), request.new_owner),
+        );
+    }
+
+    /// Returns the guardian configuration for a commitment, if any.
+    pub fn get_guardians(env: Env, commitment: BytesN<32>) -> Option<GuardianConfig> {
+        storage::get_guardians(&env, &commitment)
+    }
+
+    /// Returns the open recovery request for a commitment, if any.
+    pub fn get_recovery(env: Env, commitment: BytesN<32>) -> Option<RecoveryRequest> {
+        storage::get_recovery(&env, &commitment)
+    }
+
+    /// (Internal) Records `guardian` backing `new_owner` against the open request, and
+    /// swaps the two when `new_owner` now has more approvals. Returns the number of
+    /// approvals `new_owner` has.
+    fn challenge(
+        env: &Env,
+        request: &mut RecoveryRequest,
+        guardian: &Address,
+        new_owner: &Address,
+    ) -> u32 {
+        let mut backers = request
+            .challengers
+            .get(new_owner.clone())
+            .unwrap_or(Vec::new(env));
+        backers.push_back(guardian.clone());
+        let count = backers.len();
+        if count > request.approvals.len() {
+            request.challengers.remove(new_owner.clone());
+            request
+                .challengers
+                .set(request.new_owner.clone(), request.approvals.clone());
+            request.new_owner = new_owner.clone();
+            request.approvals = backers;
+        } else {
+            request.challengers.set(new_owner.clone(), backers);
+        }
+        count
+    }
+
+    /// (Internal) Requires contract owner authorization when the username is
+    /// non-transferable, so a soulbound name only moves through admin-approved recovery.
+    fn require_admin_if_locked(env: &Env, commitment: &BytesN<32>) {
+        if storage::is_transfer_locked(env, commitment) {
+            let admin = storage::get_owner(env)
+                .unwrap_or_else(|| panic_with_error!(env, CoreError::NotFound));
+            admin.require_auth();
+        }
+    }
+}
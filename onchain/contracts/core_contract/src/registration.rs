@@ -1,7 +1,12 @@
+use crate::address_manager::AddressManager;
+use crate::delegates::Delegates;
 use crate::errors::CoreError;
-use crate::events::{username_registered_event, REGISTER_EVENT};
+use crate::events::{username_registered_event, BURN_EVENT, REGISTER_EVENT};
+use crate::history::History;
+use crate::owner_index::OwnerIndex;
+use crate::reserved::Reserved;
 use crate::storage::{self, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
-use crate::types::{Proof, PublicSignals};
+use crate::types::{HistoryField, Proof, PublicSignals, ResolveData};
 use crate::{smt_root, zk_verifier};
 use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env};
 
@@ -24,6 +29,7 @@ impl Registration {
         if env.storage().persistent().has(&key) {
             panic_with_error!(&env, CoreError::AlreadyRegistered);
         }
+        Reserved::require_claimable(&env, &commitment, &caller);
 
         let current_root = smt_root::SmtRoot::get_root(env.clone())
             .unwrap_or_else(|| panic_with_error!(&env, CoreError::RootNotSet));
@@ -35,14 +41,7 @@ impl Registration {
             panic_with_error!(&env, CoreError::InvalidProof);
         }
 
-        env.storage().persistent().set(&key, &caller);
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
-
-        storage::set_created_at(&env, &commitment, env.ledger().timestamp());
+        Self::record_owner(&env, &commitment, &caller);
         smt_root::SmtRoot::update_root(&env, public_signals.new_root);
 
         #[allow(deprecated)]
@@ -63,6 +62,7 @@ impl Registration {
     ///
     /// ### Errors
     /// - `AlreadyRegistered`: If the commitment has already been registered.
+    /// - `NameReserved`: If the commitment is reserved for someone else.
     ///
     /// ### Events
     /// - Emits `REGISTER_EVENT` with (commitment, owner).
@@ -75,17 +75,10 @@ impl Registration {
         if env.storage().persistent().has(&key) {
             panic_with_error!(&env, CoreError::AlreadyRegistered);
         }
+        Reserved::require_claimable(&env, &commitment, &caller);
 
-        // Store commitment -> address mapping
-        env.storage().persistent().set(&key, &caller);
-        env.storage().persistent().extend_ttl(
-            &key,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
-
-        // Store registration timestamp
-        storage::set_created_at(&env, &commitment, env.ledger().timestamp());
+        // Store commitment -> address mapping and registration timestamp
+        Self::record_owner(&env, &commitment, &caller);
 
         // Emit registration event
         #[allow(deprecated)]
@@ -93,6 +86,92 @@ impl Registration {
             .publish((REGISTER_EVENT,), (commitment, caller));
     }
 
+    /// Burns a username, removing every record associated with the commitment.
+    ///
+    /// Deletes the owner mapping, resolver data, all linked addresses, privacy mode,
+    /// creation time, delegates, guardians, any signer group and its proposal, the transfer
+    /// lock and cooldown, the resolution and shielded address histories, and any pending
+    /// transfer or recovery in a single invocation. The link nonce is kept so signatures made for the burned name can
+    /// never be replayed if it is registered again.
+    ///
+    /// The name stays in the SMT, so it can only be re-registered through `register`.
+    /// Removing its leaf needs a verified deletion proof, which waits for the phase-4
+    /// verifier.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The commitment owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment to burn.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is neither registered nor has resolver data.
+    /// - `Unauthorized`: If the caller is not the owner.
+    ///
+    /// ### Events
+    /// - Emits `BURN` with (commitment, owner).
+    pub fn burn(env: Env, caller: Address, commitment: BytesN<32>) {
+        caller.require_auth();
+
+        let resolver_key = storage::DataKey::Resolver(commitment.clone());
+        let owner = Self::get_owner(env.clone(), commitment.clone())
+            .or_else(|| {
+                env.storage()
+                    .persistent()
+                    .get::<storage::DataKey, ResolveData>(&resolver_key)
+                    .map(|data| data.wallet)
+            })
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if caller != owner {
+            panic_with_error!(&env, CoreError::Unauthorized);
+        }
+        Self::destroy(&env, &commitment, owner);
+    }
+
+    /// (Internal) Removes every record of the commitment once `owner` has been authorized.
+    ///
+    /// Shared by [`Self::burn`] and group-owned commitments.
+    pub(crate) fn destroy(env: &Env, commitment: &BytesN<32>, owner: Address) {
+        if let Some(registered) = Self::get_owner(env.clone(), commitment.clone()) {
+            OwnerIndex::remove(env, &registered, commitment);
+        }
+        let persistent = env.storage().persistent();
+        persistent.remove(&DataKey::Commitment(commitment.clone()));
+        persistent.remove(&storage::DataKey::Resolver(commitment.clone()));
+        persistent.remove(&storage::DataKey::PrivacyMode(commitment.clone()));
+        persistent.remove(&storage::DataKey::PrivacyPolicy(commitment.clone()));
+        persistent.remove(&storage::DataKey::CreatedAt(commitment.clone()));
+        AddressManager::clear_addresses(env, commitment);
+        Delegates::clear(env, commitment);
+        storage::remove_pending_transfer(env, commitment);
+        storage::remove_guardians(env, commitment);
+        storage::remove_recovery(env, commitment);
+        storage::remove_multisig(env, commitment);
+        storage::remove_multisig_proposal(env, commitment);
+        storage::remove_transfer_locked(env, commitment);
+        storage::remove_locked_until(env, commitment);
+        storage::remove_username(env, commitment);
+        storage::remove_history(env, commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((BURN_EVENT,), (commitment.clone(), owner));
+    }
+
+    /// (Internal) Stores the commitment -> owner mapping, indexes it under the owner and
+    /// records the registration timestamp.
+    pub(crate) fn record_owner(env: &Env, commitment: &BytesN<32>, owner: &Address) {
+        let key = DataKey::Commitment(commitment.clone());
+        env.storage().persistent().set(&key, owner);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        OwnerIndex::add(env, owner, commitment);
+        storage::set_created_at(env, commitment, env.ledger().timestamp());
+        History::record(env, commitment, HistoryField::Owner);
+    }
+
     /// Retrieves the owner address for a given commitment.
     ///
     /// Returns the wallet address associated with the commitment, or None if not yet registered.
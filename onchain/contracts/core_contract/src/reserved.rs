@@ -0,0 +1,121 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+
+use crate::errors::CoreError;
+use crate::events::{reserved_add_event, reserved_rem_event};
+use crate::storage::{self, MAX_RESERVED_BATCH};
+use crate::types::{ReservedName, ReservedReason};
+
+pub struct Reserved;
+
+impl Reserved {
+    /// Reserves a commitment so only its designated claimant (if any) can register it.
+    ///
+    /// Re-reserving an already reserved commitment replaces its reason and claimant.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment to reserve.
+    /// - `reason`: Why the name is reserved.
+    /// - `claimant`: Address allowed to register the name despite the reservation.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract owner has not been initialized.
+    ///
+    /// ### Events
+    /// - Emits `RESERVED_ADD` event with (commitment, reason, claimant).
+    pub fn reserve_name(
+        env: Env,
+        commitment: BytesN<32>,
+        reason: ReservedReason,
+        claimant: Option<Address>,
+    ) {
+        Self::require_admin(&env);
+        Self::store(
+            &env,
+            ReservedName {
+                commitment,
+                reason,
+                claimant,
+            },
+        );
+    }
+
+    /// Reserves many commitments in one call, e.g. for an initial brand list import.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `names`: The reservations to add, at most `MAX_RESERVED_BATCH`.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract owner has not been initialized.
+    /// - `BatchTooLarge`: If `names` holds more than `MAX_RESERVED_BATCH` entries.
+    ///
+    /// ### Events
+    /// - Emits one `RESERVED_ADD` event per entry.
+    pub fn reserve_names(env: Env, names: Vec<ReservedName>) {
+        Self::require_admin(&env);
+        if names.len() > MAX_RESERVED_BATCH {
+            panic_with_error!(&env, CoreError::BatchTooLarge);
+        }
+        for name in names.iter() {
+            Self::store(&env, name);
+        }
+    }
+
+    /// Removes a reservation.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment to release.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the contract is uninitialized or the commitment is not reserved.
+    ///
+    /// ### Events
+    /// - Emits `RESERVED_REM` event with the commitment.
+    pub fn unreserve_name(env: Env, commitment: BytesN<32>) {
+        Self::require_admin(&env);
+        if storage::get_reserved(&env, &commitment).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        storage::remove_reserved(&env, &commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((reserved_rem_event(&env),), commitment);
+    }
+
+    /// Returns the reservation for a commitment, if any.
+    pub fn get_reserved(env: Env, commitment: BytesN<32>) -> Option<ReservedName> {
+        storage::get_reserved(&env, &commitment)
+    }
+
+    /// Panics with `NameReserved` unless `commitment` is unreserved or `caller` is its claimant.
+    ///
+    /// The reservation is kept after a claim so the name returns to reserved if it is burned.
+    pub(crate) fn require_claimable(env: &Env, commitment: &BytesN<32>, caller: &Address) {
+        if let Some(reserved) = storage::get_reserved(env, commitment) {
+            if reserved.claimant.as_ref() != Some(caller) {
+                panic_with_error!(env, CoreError::NameReserved);
+            }
+        }
+    }
+
+    /// (Internal) Writes a reservation and emits its event.
+    fn store(env: &Env, name: ReservedName) {
+        storage::set_reserved(env, &name);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (reserved_add_event(env),),
+            (name.commitment, name.reason, name.claimant),
+        );
+    }
+
+    /// (Internal) Requires the contract owner's authorization.
+    fn require_admin(env: &Env) {
+        let owner =
+            storage::get_owner(env).unwrap_or_else(|| panic_with_error!(env, CoreError::NotFound));
+        owner.require_auth();
+    }
+}
@@ -1,12 +1,26 @@
-use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env, MuxedAddress, Vec};
 
+use crate::custom_resolver::CustomResolvers;
+use crate::delegates::Delegates;
 use crate::errors::CoreError;
 use crate::events::{privacy_set_event, REGISTER_EVENT};
+use crate::history::History;
+use crate::privacy::Privacy;
 use crate::registration::Registration;
-use crate::storage;
-use crate::types::{PrivacyMode, PublicSignals, ResolveData};
+use crate::reserved::Reserved;
+use crate::storage::{self, MAX_RESOLVE_BATCH};
+use crate::types::{
+    DelegateRole, HistoryField, PrivacyMode, PublicSignals, ResolveData, ResolveStatus, Visibility,
+};
 use crate::{smt_root, zk_verifier};
 
+/// XDR of `ScVal::Address(ScAddress::Account(PublicKey::Ed25519(_)))` before the key.
+const ACCOUNT_ADDRESS_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// XDR of `ScVal::Address(ScAddress::MuxedAccount(_))` before the id and key.
+const MUXED_ADDRESS_XDR_PREFIX: [u8; 8] = [0, 0, 0, 18, 0, 0, 0, 2];
+
 pub struct Resolver;
 
 impl Resolver {
@@ -25,6 +39,7 @@ impl Resolver {
     ///
     /// ### Errors
     /// - `DuplicateCommitment`: If the commitment is already registered.
+    /// - `NameReserved`: If the commitment is reserved for someone else.
     /// - `RootNotSet`: If the SMT root has not been initialized.
     /// - `StaleRoot`: If the proof's old_root doesn't match the current SMT root.
     /// - `InvalidProof`: If the ZK proof verification fails.
@@ -45,6 +60,7 @@ impl Resolver {
         if env.storage().persistent().has(&key) {
             panic_with_error!(&env, CoreError::DuplicateCommitment);
         }
+        Reserved::require_claimable(&env, &commitment, &caller);
 
         let current_root = smt_root::SmtRoot::get_root(env.clone())
             .unwrap_or_else(|| panic_with_error!(&env, CoreError::RootNotSet));
@@ -65,6 +81,7 @@ impl Resolver {
             memo: None,
         };
         env.storage().persistent().set(&key, &data);
+        History::record(&env, &commitment, HistoryField::Owner);
 
         smt_root::SmtRoot::update_root(&env, public_signals.new_root);
 
@@ -77,48 +94,68 @@ impl Resolver {
     ///
     /// Associates a 64-bit memo ID with a username commitment. The memo can be used to link
     /// external payment identifiers or metadata. This updates the resolver data for the commitment.
+    /// The caller must be the commitment owner (the resolver wallet when the commitment has no
+    /// registered owner) or a `Payments` delegate.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or delegate authorizing the change. Must be authorized.
     /// - `commitment`: The 32-byte username commitment.
     /// - `memo_id`: A 64-bit unsigned integer memo value.
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
-    pub fn set_memo(env: Env, commitment: BytesN<32>, memo_id: u64) {
+    /// - `Unauthorized`: If the caller is neither the owner nor an authorized delegate.
+    pub fn set_memo(env: Env, caller: Address, commitment: BytesN<32>, memo_id: u64) {
+        caller.require_auth();
+
         let mut data = env
             .storage()
             .persistent()
             .get::<storage::DataKey, ResolveData>(&storage::DataKey::Resolver(commitment.clone()))
             .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
 
+        let owner = Registration::get_owner(env.clone(), commitment.clone())
+            .unwrap_or_else(|| data.wallet.clone());
+        if !Delegates::is_authorized(&env, &owner, &caller, &commitment, DelegateRole::Payments) {
+            panic_with_error!(&env, CoreError::Unauthorized);
+        }
+
         data.memo = Some(memo_id);
         env.storage()
             .persistent()
-            .set(&storage::DataKey::Resolver(commitment), &data);
+            .set(&storage::DataKey::Resolver(commitment.clone()), &data);
+        History::record(&env, &commitment, HistoryField::Memo);
     }
 
     /// Sets the privacy mode for a commitment (Normal or Shielded).
     ///
     /// Determines whether the commitment resolves to the actual wallet address (Normal) or
-    /// to the contract address (Shielded). Only the commitment owner can authorize this change.
+    /// to the contract address (Shielded). Only the commitment owner or a `Records`
+    /// delegate can authorize this change.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or delegate authorizing the change. Must be authorized.
     /// - `username_hash`: The 32-byte username commitment.
     /// - `mode`: The privacy mode (`Normal` or `Shielded`).
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered or has no owner.
+    /// - `Unauthorized`: If the caller is neither the owner nor an authorized delegate.
     ///
     /// ### Events
     /// - Emits `PRIVACY_SET` event with (username_hash, mode).
-    pub fn set_privacy_mode(env: Env, username_hash: BytesN<32>, mode: PrivacyMode) {
-        let owner = Registration::get_owner(env.clone(), username_hash.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
-        owner.require_auth();
+    pub fn set_privacy_mode(
+        env: Env,
+        caller: Address,
+        username_hash: BytesN<32>,
+        mode: PrivacyMode,
+    ) {
+        Delegates::require_authorized(&env, &caller, &username_hash, Some(DelegateRole::Records));
 
         storage::set_privacy_mode(&env, &username_hash, &mode);
+        History::record(&env, &username_hash, HistoryField::PrivacyMode);
 
         #[allow(deprecated)]
         env.events()
@@ -143,7 +180,8 @@ impl Resolver {
     /// Resolves a commitment to a wallet address and optional memo.
     ///
     /// Returns the wallet associated with the commitment (or the contract address if shielded)
-    /// along with any associated memo. The privacy mode determines what address is returned.
+    /// along with any associated memo. A custom resolver, when set, is asked first and the
+    /// stored record is the fallback. The privacy mode and policy determine what is returned.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -151,25 +189,130 @@ impl Resolver {
     ///
     /// ### Returns
     /// A tuple of `(Address, Option<u64>)` where:
-    /// - `Address` is the resolved wallet (or contract address if shielded).
-    /// - `Option<u64>` is the associated memo, if any.
+    /// - `Address` is the resolved wallet (or contract address if the primary wallet is not public).
+    /// - `Option<u64>` is the associated memo, if any and public.
     ///
     /// ### Errors
-    /// - `NotFound`: If the commitment is not registered.
+    /// - `NotFound`: If neither the custom resolver nor the stored record resolves the
+    ///   commitment.
     pub fn resolve(env: Env, commitment: BytesN<32>) -> (Address, Option<u64>) {
-        match env
-            .storage()
-            .persistent()
-            .get::<storage::DataKey, ResolveData>(&storage::DataKey::Resolver(commitment.clone()))
-        {
-            Some(data) => {
-                if storage::get_privacy_mode(&env, &commitment) == PrivacyMode::Shielded {
-                    (env.current_contract_address(), data.memo)
-                } else {
-                    (data.wallet, data.memo)
+        let (wallet, memo) = Self::record(&env, &commitment)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+
+        let policy = Privacy::policy(&env, &commitment);
+        let wallet = if policy.primary == Visibility::Public {
+            wallet
+        } else {
+            env.current_contract_address()
+        };
+        (wallet, memo.filter(|_| policy.memo == Visibility::Public))
+    }
+
+    /// Resolves a batch of commitments, reporting a status per entry instead of
+    /// panicking on the first one that does not resolve.
+    ///
+    /// Each entry follows [`Self::resolve`], except that a non-public primary wallet is
+    /// reported as `Shielded` rather than replaced by the contract address.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitments`: The commitments to resolve, at most `MAX_RESOLVE_BATCH`, of which
+    ///   at most `MAX_RESOLVER_CALLS` may have a custom resolver.
+    ///
+    /// ### Returns
+    /// One `ResolveStatus` per commitment, in input order.
+    ///
+    /// ### Errors
+    /// - `BatchTooLarge`: If `commitments` holds more than `MAX_RESOLVE_BATCH` entries, or
+    ///   more than `MAX_RESOLVER_CALLS` entries with a custom resolver.
+    pub fn resolve_many(env: Env, commitments: Vec<BytesN<32>>) -> Vec<ResolveStatus> {
+        if commitments.len() > MAX_RESOLVE_BATCH {
+            panic_with_error!(&env, CoreError::BatchTooLarge);
+        }
+        let mut resolver_calls = 0;
+        let mut statuses = Vec::new(&env);
+        for commitment in commitments.iter() {
+            CustomResolvers::count_call(&env, &commitment, &mut resolver_calls);
+            let status = match Self::record(&env, &commitment) {
+                None if Registration::get_owner(env.clone(), commitment.clone()).is_some() => {
+                    ResolveStatus::NoAddress
                 }
-            }
-            None => panic_with_error!(&env, CoreError::NotFound),
+                None => ResolveStatus::NotFound,
+                Some((wallet, memo)) => {
+                    let policy = Privacy::policy(&env, &commitment);
+                    if policy.primary == Visibility::Public {
+                        ResolveStatus::Resolved(
+                            wallet,
+                            memo.filter(|_| policy.memo == Visibility::Public),
+                        )
+                    } else {
+                        ResolveStatus::Shielded
+                    }
+                }
+            };
+            statuses.push_back(status);
+        }
+        statuses
+    }
+
+    /// Resolves a commitment to a muxed account (M-address) combining wallet and memo.
+    ///
+    /// Exchanges and other custodians share one G-account between customers and tell
+    /// them apart by memo id; paying the muxed address credits the right customer
+    /// without the payer attaching the memo. Follows [`Self::resolve`], including the
+    /// custom resolver and the privacy policy.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Returns
+    /// The muxed address, or `None` if the resolved wallet is not a G-account or no
+    /// public memo is set.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment does not resolve.
+    pub fn resolve_muxed(env: Env, commitment: BytesN<32>) -> Option<MuxedAddress> {
+        let (wallet, memo) = Self::resolve(env.clone(), commitment);
+        let id = memo?;
+        let xdr = wallet.to_xdr(&env);
+        let prefix_len = ACCOUNT_ADDRESS_XDR_PREFIX.len() as u32;
+        if xdr.len() != prefix_len + 32
+            || xdr.slice(0..prefix_len) != Bytes::from_array(&env, &ACCOUNT_ADDRESS_XDR_PREFIX)
+        {
+            return None;
+        }
+        let mut muxed = Bytes::from_array(&env, &MUXED_ADDRESS_XDR_PREFIX);
+        muxed.extend_from_array(&id.to_be_bytes());
+        muxed.append(&xdr.slice(prefix_len..));
+        MuxedAddress::from_xdr(&env, &muxed).ok()
+    }
+
+    /// (Internal) Points the stored record at `wallet` and drops its memo, so the
+    /// commitment stops resolving to the previous owner. Commitments without a stored
+    /// record are left alone.
+    pub(crate) fn reset(env: &Env, commitment: &BytesN<32>, wallet: &Address) {
+        let key = storage::DataKey::Resolver(commitment.clone());
+        if !env.storage().persistent().has(&key) {
+            return;
         }
+        let data = ResolveData {
+            wallet: wallet.clone(),
+            memo: None,
+        };
+        env.storage().persistent().set(&key, &data);
+    }
+
+    /// (Internal) Returns the wallet and memo from the custom resolver, falling back to
+    /// the stored record, before any privacy filtering.
+    fn record(env: &Env, commitment: &BytesN<32>) -> Option<(Address, Option<u64>)> {
+        CustomResolvers::resolve(env, commitment).or_else(|| {
+            env.storage()
+                .persistent()
+                .get::<storage::DataKey, ResolveData>(&storage::DataKey::Resolver(
+                    commitment.clone(),
+                ))
+                .map(|data| (data.wallet, data.memo))
+        })
     }
 }
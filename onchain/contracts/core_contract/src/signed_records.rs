@@ -0,0 +1,132 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{gateway_signer_removed_event, gateway_signer_set_event};
+use crate::storage;
+use crate::transfer::Transfer;
+use crate::types::DelegateRole;
+
+/// Domain tag prepended to every signed record so gateway signatures cannot be
+/// replayed against any other protocol or message format.
+const SIGNED_RECORD_DOMAIN: &[u8] = b"ALIEN_GATEWAY_SIGNED_RECORD_V1";
+
+pub struct SignedRecords;
+
+impl SignedRecords {
+    /// Declares the ed25519 key of the off-chain gateway that serves a commitment's records.
+    ///
+    /// Records served by the gateway are never stored on-chain; readers pass them to
+    /// [`Self::resolve_with_signed_record`] to have the signature checked. Replaces any
+    /// signer already declared.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `signer`: The gateway's ed25519 public key.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `GATEWAY_SET` event with (commitment, signer).
+    pub fn set_gateway_signer(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        signer: BytesN<32>,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::set_gateway_signer(&env, &commitment, &signer);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((gateway_signer_set_event(&env),), (commitment, signer));
+    }
+
+    /// Removes the gateway signer, invalidating every record it signed.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no gateway signer.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `GATEWAY_REM` event with (commitment,).
+    pub fn remove_gateway_signer(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        if storage::get_gateway_signer(&env, &commitment).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::remove_gateway_signer(&env, &commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((gateway_signer_removed_event(&env),), (commitment,));
+    }
+
+    /// Returns the gateway signer of a commitment, if any.
+    pub fn get_gateway_signer(env: Env, commitment: BytesN<32>) -> Option<BytesN<32>> {
+        storage::get_gateway_signer(&env, &commitment)
+    }
+
+    /// Returns `record` once the commitment's gateway signature over it is verified.
+    ///
+    /// The record format is up to the gateway; the contract only authenticates it.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `record`: The record bytes served by the gateway.
+    /// - `signature`: The gateway's ed25519 signature over [`Self::signed_record_message`].
+    /// - `expiry`: Ledger timestamp after which the signature is no longer accepted.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment has no gateway signer.
+    /// - `SignedRecordExpired`: If the ledger timestamp is past `expiry`.
+    /// - An invalid signature traps in the host.
+    pub fn resolve_with_signed_record(
+        env: Env,
+        commitment: BytesN<32>,
+        record: Bytes,
+        signature: BytesN<64>,
+        expiry: u64,
+    ) -> Bytes {
+        let signer = storage::get_gateway_signer(&env, &commitment)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, CoreError::SignedRecordExpired);
+        }
+        let message = Self::signed_record_message(&env, &commitment, &record, expiry);
+        env.crypto().ed25519_verify(&signer, &message, &signature);
+        record
+    }
+
+    /// Builds the domain-separated message a gateway signs for a record.
+    ///
+    /// Layout: `SIGNED_RECORD_DOMAIN || contract_id (XDR) || commitment || expiry (u64 BE) || record`.
+    pub fn signed_record_message(
+        env: &Env,
+        commitment: &BytesN<32>,
+        record: &Bytes,
+        expiry: u64,
+    ) -> Bytes {
+        let mut message = Bytes::from_slice(env, SIGNED_RECORD_DOMAIN);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(&Bytes::from_array(env, &commitment.to_array()));
+        message.extend_from_array(&expiry.to_be_bytes());
+        message.append(record);
+        message
+    }
+}
@@ -0,0 +1,144 @@
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{stealth_meta_removed_event, stealth_meta_set_event, ANNOUNCE_EVENT};
+use crate::storage::{self, MAX_ANNOUNCE_METADATA};
+use crate::transfer::Transfer;
+use crate::types::{DelegateRole, StealthMetaAddress};
+
+/// Stealth scheme 1: secp256k1 keys with a one-byte view tag (ERC-5564 scheme 1).
+pub const SCHEME_SECP256K1: u32 = 1;
+
+pub struct Stealth;
+
+impl Stealth {
+    /// Registers the stealth meta-address senders use to derive one-time addresses.
+    ///
+    /// The contract never sees the derived addresses; senders compute them off-chain from
+    /// these public keys and publish an [`Stealth::announce`] so the recipient can find
+    /// the payment. Replaces any meta-address already registered.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `meta`: The scheme id and the spending and viewing public keys.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `InvalidStealthMetaAddress`: If the scheme is unknown or a key is malformed.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `STEALTH_META_SET` event with (commitment, meta).
+    pub fn set_stealth_meta_address(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        meta: StealthMetaAddress,
+    ) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        if meta.scheme_id != SCHEME_SECP256K1
+            || !Self::is_compressed_key(&meta.spending_pubkey)
+            || !Self::is_compressed_key(&meta.viewing_pubkey)
+        {
+            panic_with_error!(&env, CoreError::InvalidStealthMetaAddress);
+        }
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::set_stealth_meta(&env, &commitment, &meta);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((stealth_meta_set_event(&env),), (commitment, meta));
+    }
+
+    /// Removes the stealth meta-address from a commitment.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner or a `Payments` delegate. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no meta-address.
+    /// - `Unauthorized`: If the caller is neither the owner nor a `Payments` delegate.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `STEALTH_META_REM` event with (commitment,).
+    pub fn remove_stealth_meta_address(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, Some(DelegateRole::Payments));
+        if storage::get_stealth_meta(&env, &commitment).is_none() {
+            panic_with_error!(&env, CoreError::NotFound);
+        }
+        Transfer::require_not_frozen(&env, &commitment);
+        storage::remove_stealth_meta(&env, &commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((stealth_meta_removed_event(&env),), (commitment,));
+    }
+
+    /// Returns the stealth meta-address registered on a commitment, if any.
+    pub fn get_stealth_meta_address(
+        env: Env,
+        commitment: BytesN<32>,
+    ) -> Option<StealthMetaAddress> {
+        storage::get_stealth_meta(&env, &commitment)
+    }
+
+    /// Announces a payment to a stealth address so its recipient can discover it.
+    ///
+    /// Announcements are not tied to any commitment and store nothing; recipients scan
+    /// `ANNOUNCE` events, first filtering on the `view_tag` topic and then checking the
+    /// ephemeral key against their viewing key.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The announcing sender. Must be authorized.
+    /// - `scheme_id`: The stealth scheme used to derive `stealth_address`.
+    /// - `stealth_address`: The derived one-time address, in the scheme's encoding.
+    /// - `ephemeral_pubkey`: The sender's ephemeral public key.
+    /// - `view_tag`: The first byte of the hashed shared secret, `0..=255`.
+    /// - `metadata`: Opaque sender data, at most `MAX_ANNOUNCE_METADATA` bytes.
+    ///
+    /// ### Errors
+    /// - `InvalidAnnouncement`: If the scheme is unknown, the ephemeral key is malformed,
+    ///   the view tag is out of range, or the metadata is too large.
+    ///
+    /// ### Events
+    /// - Emits `ANNOUNCE` event with topics (scheme_id, view_tag) and data
+    ///   (stealth_address, caller, ephemeral_pubkey, metadata).
+    pub fn announce(
+        env: Env,
+        caller: Address,
+        scheme_id: u32,
+        stealth_address: Bytes,
+        ephemeral_pubkey: Bytes,
+        view_tag: u32,
+        metadata: Bytes,
+    ) {
+        caller.require_auth();
+        if scheme_id != SCHEME_SECP256K1
+            || !Self::is_compressed_key(&ephemeral_pubkey)
+            || stealth_address.is_empty()
+            || view_tag > u8::MAX as u32
+            || metadata.len() > MAX_ANNOUNCE_METADATA
+        {
+            panic_with_error!(&env, CoreError::InvalidAnnouncement);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (ANNOUNCE_EVENT, scheme_id, view_tag),
+            (stealth_address, caller, ephemeral_pubkey, metadata),
+        );
+    }
+
+    /// (Internal) Returns whether `key` is a 33-byte SEC1 compressed secp256k1 point encoding.
+    fn is_compressed_key(key: &Bytes) -> bool {
+        key.len() == 33 && matches!(key.get(0), Some(0x02) | Some(0x03))
+    }
+}
@@ -1,12 +1,46 @@
-use soroban_sdk::{contracttype, Address, BytesN, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
-use crate::types::PrivacyMode;
+use crate::types::{
+    ClaimRound, DelegateGrant, EncryptedRecord, GuardianConfig, HistoryEntry, MultisigConfig,
+    MultisigProposal, PendingTransfer, PrivacyMode, PrivacyPolicy, RecoveryRequest, ReservedName,
+    ShieldedRecord, StealthMetaAddress,
+};
 
 /// TTL constants for persistent storage entries.
 /// Bump amount: ~30 days (at ~5s per ledger close).
 pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 518_400;
 /// Lifetime threshold: ~7 days — entries are extended when remaining TTL drops below this.
 pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+/// Maximum number of addresses a commitment may link per chain (including Stellar).
+pub(crate) const MAX_ADDRESSES_PER_CHAIN: u32 = 10;
+/// Maximum number of delegate grants a commitment may hold.
+pub(crate) const MAX_DELEGATES: u32 = 10;
+/// Maximum number of entries returned by a single paginated listing.
+pub(crate) const MAX_PAGE_SIZE: u32 = 50;
+/// Maximum number of recovery guardians a commitment may configure.
+pub(crate) const MAX_GUARDIANS: u32 = 10;
+/// Maximum number of signers in a commitment's owner group.
+pub(crate) const MAX_SIGNERS: u32 = 10;
+/// Maximum number of change-history entries kept per commitment; older entries are dropped.
+pub(crate) const MAX_HISTORY: u32 = 50;
+/// Maximum size, in bytes, of the metadata attached to a stealth announcement.
+pub(crate) const MAX_ANNOUNCE_METADATA: u32 = 256;
+/// Maximum size, in bytes, of an encrypted record's ciphertext.
+pub(crate) const MAX_RECORD_CIPHERTEXT: u32 = 1024;
+/// Maximum size, in bytes, of a wrapped content key.
+pub(crate) const MAX_RECORD_ENVELOPE: u32 = 128;
+/// Maximum number of viewing keys granted access to an encrypted record.
+pub(crate) const MAX_RECORD_GRANTS: u32 = 20;
+/// Maximum number of shielded address commitments kept per username, current included.
+pub(crate) const MAX_SHIELDED_HISTORY: u32 = 10;
+/// Maximum number of reservations accepted by a single bulk import.
+pub(crate) const MAX_RESERVED_BATCH: u32 = 100;
+/// Maximum number of commitments resolved by a single batch resolution.
+pub(crate) const MAX_RESOLVE_BATCH: u32 = 100;
+/// Maximum number of custom resolver calls made by a single batch resolution.
+pub(crate) const MAX_RESOLVER_CALLS: u32 = 20;
+/// Maximum number of sibling hashes accepted in an allocation claim proof.
+pub(crate) const MAX_CLAIM_PROOF_DEPTH: u32 = 32;
 
 /// Storage keys for the Core contract's persistent and instance storage.
 #[contracttype]
@@ -18,8 +52,11 @@ pub enum DataKey {
     SmtRoot,
     /// Key for the primary Stellar address linked to a username hash.
     StellarAddress(BytesN<32>),
-    /// Key for the list of all Stellar addresses linked to a username hash.
+    /// Key for the unlabeled `Vec<Address>` list written before labels existed. Read
+    /// only as a fallback and dropped on the next write.
     StellarAddresses(BytesN<32>),
+    /// Key for the labeled list of all Stellar addresses linked to a username hash.
+    StellarAddressEntries(BytesN<32>),
     /// Key for the user's selected privacy mode.
     PrivacyMode(BytesN<32>),
     /// Key for the contract owner set during initialization (instance storage).
@@ -28,6 +65,68 @@ pub enum DataKey {
     ShieldedAddress(BytesN<32>),
     /// Key for the ledger timestamp at which a commitment was first registered.
     CreatedAt(BytesN<32>),
+    /// Key for the next nonce expected in a signature-verified chain link.
+    LinkNonce(BytesN<32>),
+    /// Key for the delegate grants on a username hash.
+    Delegates(BytesN<32>),
+    /// Key for a proposed, not yet accepted, ownership transfer.
+    PendingTransfer(BytesN<32>),
+    /// Key for the flag disabling direct ownership transfers (instance storage).
+    DirectTransfersDisabled,
+    /// Key for the recovery guardian configuration of a username hash.
+    Guardians(BytesN<32>),
+    /// Key for the open guardian recovery request of a username hash.
+    Recovery(BytesN<32>),
+    /// Key for an admin reservation blocking registration of a commitment.
+    Reserved(BytesN<32>),
+    /// Key for an allocation claim round, indexed by round id.
+    ClaimRound(u32),
+    /// Key for the number of claim rounds created (instance storage).
+    ClaimRoundCount,
+    /// Key marking a commitment as claimed in a round.
+    Claimed(u32, BytesN<32>),
+    /// Key for the irreversible flag making a username hash non-transferable.
+    TransferLocked(BytesN<32>),
+    /// Key for the freeze period applied after every ownership change (instance storage).
+    TransferCooldown,
+    /// Key for the timestamp until which a username hash is frozen after an ownership change.
+    LockedUntil(BytesN<32>),
+    /// Key for the signer group owning a username hash.
+    Multisig(BytesN<32>),
+    /// Key for the signer group's open proposal on a username hash.
+    MultisigProposal(BytesN<32>),
+    /// Key for the bounded resolution change history of a username hash.
+    History(BytesN<32>),
+    /// Key for the stealth meta-address registered on a username hash.
+    StealthMeta(BytesN<32>),
+    /// Key for the encrypted address record of a username hash.
+    EncryptedRecord(BytesN<32>),
+    /// Key for the per-field privacy policy of a username hash.
+    PrivacyPolicy(BytesN<32>),
+    /// Key for the recent shielded address commitments of a username hash.
+    ShieldedHistory(BytesN<32>),
+    /// Key for the plaintext username an owner published for a username hash.
+    Username(BytesN<32>),
+    /// Key for the external resolver contract a username hash delegates to.
+    CustomResolver(BytesN<32>),
+    /// Key for the ed25519 key of the off-chain gateway serving a username hash's records.
+    GatewaySigner(BytesN<32>),
+    /// Key for the merchant secret commitment, `sha256(secret)`, payer memos are derived from.
+    PayerMemoCommitment(BytesN<32>),
+    /// Key for the last counter-allocated payer memo of a username hash.
+    PayerMemoCounter(BytesN<32>),
+    /// Key for the generation of a username hash's payer memos, bumped on ownership change.
+    PayerMemoEpoch(BytesN<32>),
+    /// Key for the memo assigned to a payer, by username hash and generation.
+    PayerMemo(BytesN<32>, u32, Address),
+    /// Key for the payer a memo was assigned to, by username hash and generation.
+    MemoPayer(BytesN<32>, u32, u64),
+    /// Key for the number of username hashes an address owns.
+    OwnedCount(Address),
+    /// Key for the username hash in one slot of an owner's index.
+    OwnedAt(Address, u32),
+    /// Key for the slot a username hash occupies in its owner's index.
+    OwnedIndex(BytesN<32>),
 }
 
 pub fn set_privacy_mode(env: &Env, username_hash: &BytesN<32>, mode: &PrivacyMode) {
@@ -40,6 +139,22 @@ pub fn set_privacy_mode(env: &Env, username_hash: &BytesN<32>, mode: &PrivacyMod
     );
 }
 
+pub fn get_privacy_policy(env: &Env, username_hash: &BytesN<32>) -> Option<PrivacyPolicy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PrivacyPolicy(username_hash.clone()))
+}
+
+pub fn set_privacy_policy(env: &Env, username_hash: &BytesN<32>, policy: &PrivacyPolicy) {
+    let key = DataKey::PrivacyPolicy(username_hash.clone());
+    env.storage().persistent().set(&key, policy);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
 pub fn get_privacy_mode(env: &Env, username_hash: &BytesN<32>) -> PrivacyMode {
     env.storage()
         .persistent()
@@ -47,6 +162,18 @@ pub fn get_privacy_mode(env: &Env, username_hash: &BytesN<32>) -> PrivacyMode {
         .unwrap_or(PrivacyMode::Normal)
 }
 
+pub fn remove_privacy_mode(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PrivacyMode(username_hash.clone()));
+}
+
+pub fn remove_privacy_policy(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PrivacyPolicy(username_hash.clone()));
+}
+
 pub fn set_owner(env: &Env, owner: &Address) {
     env.storage().instance().set(&DataKey::Owner, owner);
 }
@@ -81,6 +208,29 @@ pub fn has_shielded_address(env: &Env, username_hash: &BytesN<32>) -> bool {
         .has(&DataKey::ShieldedAddress(username_hash.clone()))
 }
 
+pub fn get_shielded_history(env: &Env, username_hash: &BytesN<32>) -> Vec<ShieldedRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShieldedHistory(username_hash.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_shielded_history(env: &Env, username_hash: &BytesN<32>, records: &Vec<ShieldedRecord>) {
+    let key = DataKey::ShieldedHistory(username_hash.clone());
+    env.storage().persistent().set(&key, records);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_shielded_history(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ShieldedHistory(username_hash.clone()));
+}
+
 pub fn set_created_at(env: &Env, username_hash: &BytesN<32>, timestamp: u64) {
     let key = DataKey::CreatedAt(username_hash.clone());
     env.storage().persistent().set(&key, &timestamp);
@@ -96,3 +246,596 @@ pub fn get_created_at(env: &Env, username_hash: &BytesN<32>) -> Option<u64> {
         .persistent()
         .get(&DataKey::CreatedAt(username_hash.clone()))
 }
+
+pub fn get_link_nonce(env: &Env, username_hash: &BytesN<32>) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LinkNonce(username_hash.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_link_nonce(env: &Env, username_hash: &BytesN<32>, nonce: u64) {
+    let key = DataKey::LinkNonce(username_hash.clone());
+    env.storage().persistent().set(&key, &nonce);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_delegates(env: &Env, username_hash: &BytesN<32>) -> Vec<DelegateGrant> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegates(username_hash.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_delegates(env: &Env, username_hash: &BytesN<32>, grants: &Vec<DelegateGrant>) {
+    let key = DataKey::Delegates(username_hash.clone());
+    env.storage().persistent().set(&key, grants);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_pending_transfer(env: &Env, username_hash: &BytesN<32>) -> Option<PendingTransfer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingTransfer(username_hash.clone()))
+}
+
+pub fn set_pending_transfer(env: &Env, username_hash: &BytesN<32>, pending: &PendingTransfer) {
+    let key = DataKey::PendingTransfer(username_hash.clone());
+    env.storage().persistent().set(&key, pending);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_pending_transfer(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingTransfer(username_hash.clone()));
+}
+
+pub fn direct_transfers_enabled(env: &Env) -> bool {
+    !env.storage()
+        .instance()
+        .get::<DataKey, bool>(&DataKey::DirectTransfersDisabled)
+        .unwrap_or(false)
+}
+
+pub fn set_direct_transfers_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DirectTransfersDisabled, &!enabled);
+}
+
+pub fn get_guardians(env: &Env, username_hash: &BytesN<32>) -> Option<GuardianConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Guardians(username_hash.clone()))
+}
+
+pub fn set_guardians(env: &Env, username_hash: &BytesN<32>, config: &GuardianConfig) {
+    let key = DataKey::Guardians(username_hash.clone());
+    env.storage().persistent().set(&key, config);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_guardians(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Guardians(username_hash.clone()));
+}
+
+pub fn get_recovery(env: &Env, username_hash: &BytesN<32>) -> Option<RecoveryRequest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recovery(username_hash.clone()))
+}
+
+pub fn set_recovery(env: &Env, username_hash: &BytesN<32>, request: &RecoveryRequest) {
+    let key = DataKey::Recovery(username_hash.clone());
+    env.storage().persistent().set(&key, request);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_recovery(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Recovery(username_hash.clone()));
+}
+
+pub fn get_reserved(env: &Env, username_hash: &BytesN<32>) -> Option<ReservedName> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reserved(username_hash.clone()))
+}
+
+pub fn set_reserved(env: &Env, reserved: &ReservedName) {
+    let key = DataKey::Reserved(reserved.commitment.clone());
+    env.storage().persistent().set(&key, reserved);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_reserved(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Reserved(username_hash.clone()));
+}
+
+pub fn get_claim_round(env: &Env, round_id: u32) -> Option<ClaimRound> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimRound(round_id))
+}
+
+pub fn set_claim_round(env: &Env, round_id: u32, round: &ClaimRound) {
+    let key = DataKey::ClaimRound(round_id);
+    env.storage().persistent().set(&key, round);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_claim_round_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimRoundCount)
+        .unwrap_or(0)
+}
+
+pub fn set_claim_round_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ClaimRoundCount, &count);
+}
+
+pub fn is_claimed(env: &Env, round_id: u32, username_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Claimed(round_id, username_hash.clone()))
+}
+
+pub fn set_claimed(env: &Env, round_id: u32, username_hash: &BytesN<32>) {
+    let key = DataKey::Claimed(round_id, username_hash.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn is_transfer_locked(env: &Env, username_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TransferLocked(username_hash.clone()))
+}
+
+pub fn set_transfer_locked(env: &Env, username_hash: &BytesN<32>) {
+    let key = DataKey::TransferLocked(username_hash.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_transfer_locked(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TransferLocked(username_hash.clone()));
+}
+
+pub fn get_transfer_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TransferCooldown)
+        .unwrap_or(0)
+}
+
+pub fn set_transfer_cooldown(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TransferCooldown, &seconds);
+}
+
+pub fn get_locked_until(env: &Env, username_hash: &BytesN<32>) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LockedUntil(username_hash.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_locked_until(env: &Env, username_hash: &BytesN<32>, timestamp: u64) {
+    let key = DataKey::LockedUntil(username_hash.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_locked_until(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LockedUntil(username_hash.clone()));
+}
+
+pub fn get_multisig(env: &Env, username_hash: &BytesN<32>) -> Option<MultisigConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Multisig(username_hash.clone()))
+}
+
+pub fn set_multisig(env: &Env, username_hash: &BytesN<32>, config: &MultisigConfig) {
+    let key = DataKey::Multisig(username_hash.clone());
+    env.storage().persistent().set(&key, config);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+/// Removes the signer group and its open proposal.
+pub fn remove_multisig(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Multisig(username_hash.clone()));
+    remove_multisig_proposal(env, username_hash);
+}
+
+pub fn get_multisig_proposal(env: &Env, username_hash: &BytesN<32>) -> Option<MultisigProposal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MultisigProposal(username_hash.clone()))
+}
+
+pub fn set_multisig_proposal(env: &Env, username_hash: &BytesN<32>, proposal: &MultisigProposal) {
+    let key = DataKey::MultisigProposal(username_hash.clone());
+    env.storage().persistent().set(&key, proposal);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_multisig_proposal(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MultisigProposal(username_hash.clone()));
+}
+
+pub fn get_history(env: &Env, username_hash: &BytesN<32>) -> Vec<HistoryEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::History(username_hash.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_history(env: &Env, username_hash: &BytesN<32>, entries: &Vec<HistoryEntry>) {
+    let key = DataKey::History(username_hash.clone());
+    env.storage().persistent().set(&key, entries);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_history(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::History(username_hash.clone()));
+}
+
+pub fn get_stealth_meta(env: &Env, username_hash: &BytesN<32>) -> Option<StealthMetaAddress> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StealthMeta(username_hash.clone()))
+}
+
+pub fn set_stealth_meta(env: &Env, username_hash: &BytesN<32>, meta: &StealthMetaAddress) {
+    let key = DataKey::StealthMeta(username_hash.clone());
+    env.storage().persistent().set(&key, meta);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_stealth_meta(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StealthMeta(username_hash.clone()));
+}
+
+pub fn get_encrypted_record(env: &Env, username_hash: &BytesN<32>) -> Option<EncryptedRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EncryptedRecord(username_hash.clone()))
+}
+
+pub fn set_encrypted_record(env: &Env, username_hash: &BytesN<32>, record: &EncryptedRecord) {
+    let key = DataKey::EncryptedRecord(username_hash.clone());
+    env.storage().persistent().set(&key, record);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_encrypted_record(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EncryptedRecord(username_hash.clone()));
+}
+
+pub fn get_username(env: &Env, username_hash: &BytesN<32>) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Username(username_hash.clone()))
+}
+
+pub fn set_username(env: &Env, username_hash: &BytesN<32>, username: &String) {
+    let key = DataKey::Username(username_hash.clone());
+    env.storage().persistent().set(&key, username);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_username(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Username(username_hash.clone()));
+}
+
+pub fn get_custom_resolver(env: &Env, username_hash: &BytesN<32>) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CustomResolver(username_hash.clone()))
+}
+
+pub fn set_custom_resolver(env: &Env, username_hash: &BytesN<32>, resolver: &Address) {
+    let key = DataKey::CustomResolver(username_hash.clone());
+    env.storage().persistent().set(&key, resolver);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_custom_resolver(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CustomResolver(username_hash.clone()));
+}
+
+pub fn get_gateway_signer(env: &Env, username_hash: &BytesN<32>) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GatewaySigner(username_hash.clone()))
+}
+
+pub fn set_gateway_signer(env: &Env, username_hash: &BytesN<32>, signer: &BytesN<32>) {
+    let key = DataKey::GatewaySigner(username_hash.clone());
+    env.storage().persistent().set(&key, signer);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_gateway_signer(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::GatewaySigner(username_hash.clone()));
+}
+
+pub fn get_payer_memo_commitment(env: &Env, username_hash: &BytesN<32>) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayerMemoCommitment(username_hash.clone()))
+}
+
+pub fn set_payer_memo_commitment(
+    env: &Env,
+    username_hash: &BytesN<32>,
+    secret_commitment: &BytesN<32>,
+) {
+    let key = DataKey::PayerMemoCommitment(username_hash.clone());
+    env.storage().persistent().set(&key, secret_commitment);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_payer_memo_commitment(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PayerMemoCommitment(username_hash.clone()));
+}
+
+pub fn get_payer_memo_counter(env: &Env, username_hash: &BytesN<32>) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayerMemoCounter(username_hash.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_payer_memo_counter(env: &Env, username_hash: &BytesN<32>, memo: u64) {
+    let key = DataKey::PayerMemoCounter(username_hash.clone());
+    env.storage().persistent().set(&key, &memo);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_payer_memo_counter(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PayerMemoCounter(username_hash.clone()));
+}
+
+pub fn get_payer_memo_epoch(env: &Env, username_hash: &BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayerMemoEpoch(username_hash.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_payer_memo_epoch(env: &Env, username_hash: &BytesN<32>, epoch: u32) {
+    let key = DataKey::PayerMemoEpoch(username_hash.clone());
+    env.storage().persistent().set(&key, &epoch);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_payer_memo(
+    env: &Env,
+    username_hash: &BytesN<32>,
+    epoch: u32,
+    payer: &Address,
+) -> Option<u64> {
+    env.storage().persistent().get(&DataKey::PayerMemo(
+        username_hash.clone(),
+        epoch,
+        payer.clone(),
+    ))
+}
+
+pub fn get_memo_payer(
+    env: &Env,
+    username_hash: &BytesN<32>,
+    epoch: u32,
+    memo: u64,
+) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MemoPayer(username_hash.clone(), epoch, memo))
+}
+
+/// Records `memo` as assigned to `payer`, in both directions.
+pub fn set_payer_memo(
+    env: &Env,
+    username_hash: &BytesN<32>,
+    epoch: u32,
+    payer: &Address,
+    memo: u64,
+) {
+    let persistent = env.storage().persistent();
+    let forward = DataKey::PayerMemo(username_hash.clone(), epoch, payer.clone());
+    let reverse = DataKey::MemoPayer(username_hash.clone(), epoch, memo);
+    persistent.set(&forward, &memo);
+    persistent.set(&reverse, payer);
+    persistent.extend_ttl(
+        &forward,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+    persistent.extend_ttl(
+        &reverse,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_owned_count(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnedCount(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_owned_count(env: &Env, owner: &Address, count: u32) {
+    let key = DataKey::OwnedCount(owner.clone());
+    env.storage().persistent().set(&key, &count);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn get_owned_at(env: &Env, owner: &Address, slot: u32) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnedAt(owner.clone(), slot))
+}
+
+pub fn set_owned_at(env: &Env, owner: &Address, slot: u32, username_hash: &BytesN<32>) {
+    let key = DataKey::OwnedAt(owner.clone(), slot);
+    env.storage().persistent().set(&key, username_hash);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_owned_at(env: &Env, owner: &Address, slot: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::OwnedAt(owner.clone(), slot));
+}
+
+pub fn get_owned_index(env: &Env, username_hash: &BytesN<32>) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnedIndex(username_hash.clone()))
+}
+
+pub fn set_owned_index(env: &Env, username_hash: &BytesN<32>, slot: u32) {
+    let key = DataKey::OwnedIndex(username_hash.clone());
+    env.storage().persistent().set(&key, &slot);
+    env.storage().persistent().extend_ttl(
+        &key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
+}
+
+pub fn remove_owned_index(env: &Env, username_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::OwnedIndex(username_hash.clone()));
+}
@@ -1,13 +1,26 @@
+use crate::address_manager::ChainAddrKey;
+use crate::custom_resolver::CustomResolverInterface;
+use crate::poseidon::{self, Fr};
 use crate::registration::DataKey as RegistrationKey;
 use crate::smt_root::SmtRoot;
-use crate::types::{AddressMetadata, ChainType, PrivacyMode, PublicSignals};
+use crate::types::{
+    AddressMetadata, ChainType, DelegateGrant, DelegateRole, EncryptedRecord, HistoryEntry,
+    HistoryField, MultisigAction, MultisigConfig, MultisigProposal, PendingTransfer, PrivacyMode,
+    PrivacyPolicy, PublicSignals, RecordGrant, RecordPolicy, RecordView, ReservedName,
+    ReservedReason, ResolveStatus, ShieldedRecord, StealthMetaAddress, Visibility,
+};
 use crate::{Contract, ContractClient};
 use escrow_contract::types::{
     AutoPay, ScheduledPayment as EscrowScheduledPayment, VaultConfig, VaultState,
 };
 use shared::errors::CoreError;
-use soroban_sdk::testutils::{Address as _, Events, Ledger as _, MockAuth, MockAuthInvoke};
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Error, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::testutils::{
+    Address as _, Events, Ledger as _, MockAuth, MockAuthInvoke, MuxedAddress as _,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Error,
+    IntoVal, Map, MuxedAddress, String, Symbol, Val, Vec,
+};
 
 fn setup(env: &Env) -> (Address, ContractClient<'_>) {
     let contract_id = env.register(Contract, ());
@@ -361,7 +374,7 @@ fn test_set_memo_and_resolve_flow() {
 
     let signals = signals(&hash, root, new_root);
     client.register_resolver(&caller, &hash, &dummy_proof(&env), &signals);
-    client.set_memo(&hash, &4242u64);
+    client.set_memo(&caller, &hash, &4242u64);
 
     let (resolved_wallet, memo) = client.resolve(&hash);
     assert_eq!(resolved_wallet, caller);
@@ -398,7 +411,7 @@ fn test_set_privacy_mode_to_shielded() {
     assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Normal);
     assert_eq!(client.resolve(&hash), (owner.clone(), None));
 
-    client.set_privacy_mode(&hash, &PrivacyMode::Shielded);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
 
     assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Shielded);
     assert_eq!(client.resolve(&hash), (contract_id, None));
@@ -421,10 +434,10 @@ fn test_set_privacy_mode_to_normal() {
         &signals(&hash, root, new_root),
     );
 
-    client.set_privacy_mode(&hash, &PrivacyMode::Shielded);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
     assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Shielded);
 
-    client.set_privacy_mode(&hash, &PrivacyMode::Normal);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Normal);
     assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Normal);
 }
 
@@ -442,7 +455,7 @@ fn test_set_privacy_mode_non_owner_rejected() {
             .set(&RegistrationKey::Commitment(hash.clone()), &owner);
     });
 
-    let args: Vec<Val> = (hash.clone(), PrivacyMode::Shielded).into_val(&env);
+    let args: Vec<Val> = (attacker.clone(), hash.clone(), PrivacyMode::Shielded).into_val(&env);
     env.mock_auths(&[MockAuth {
         address: &attacker,
         invoke: &MockAuthInvoke {
@@ -529,7 +542,14 @@ fn test_resolve_stellar_after_ownership_transfer() {
 
     let signals = signals(&hash, root, BytesN::from_array(&env, &[43u8; 32]));
 
-    client.transfer(&owner, &hash, &new_owner, &dummy_proof(&env), &signals);
+    client.transfer(
+        &owner,
+        &hash,
+        &new_owner,
+        &dummy_proof(&env),
+        &signals,
+        &RecordPolicy::Keep,
+    );
 
     let new_address = Address::generate(&env);
     client.add_stellar_address(&new_owner, &hash, &new_address);
@@ -654,7 +674,7 @@ fn test_resolve_stellar_no_address_linked_when_not_set() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Error(Contract, #4007)")]
 fn test_add_stellar_address_wrong_owner_panics() {
     let env = Env::default();
     env.mock_all_auths();
@@ -668,6 +688,44 @@ fn test_add_stellar_address_wrong_owner_panics() {
     client.add_stellar_address(&attacker, &hash, &attacker);
 }
 
+#[test]
+fn test_legacy_stellar_address_list_migrates_on_write() {
+    use crate::storage::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 69);
+    client.register(&owner, &hash);
+
+    // Unlabeled list written before labels existed.
+    let legacy = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &DataKey::StellarAddresses(hash.clone()),
+            &Vec::from_array(&env, [legacy.clone()]),
+        );
+    });
+    assert_eq!(
+        client.get_stellar_addresses(&hash),
+        Vec::from_array(&env, [legacy.clone()])
+    );
+
+    let added = Address::generate(&env);
+    client.add_stellar_address(&owner, &hash, &added);
+    assert_eq!(
+        client.get_stellar_addresses(&hash),
+        Vec::from_array(&env, [legacy, added])
+    );
+    let leftover = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .has(&DataKey::StellarAddresses(hash.clone()))
+    });
+    assert!(!leftover);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #4001)")]
 fn test_add_stellar_address_not_registered_panics() {
@@ -1117,7 +1175,7 @@ fn test_transfer_ownership_succeeds() {
     let hash = commitment(&env, 30);
 
     client.register(&owner, &hash);
-    client.transfer_ownership(&owner, &hash, &new_owner);
+    client.transfer_ownership(&owner, &hash, &new_owner, &RecordPolicy::Keep);
 
     assert_eq!(client.get_owner(&hash), Some(new_owner));
 }
@@ -1135,7 +1193,7 @@ fn test_transfer_ownership_non_owner_panics() {
     let hash = commitment(&env, 31);
 
     client.register(&owner, &hash);
-    client.transfer_ownership(&attacker, &hash, &new_owner);
+    client.transfer_ownership(&attacker, &hash, &new_owner, &RecordPolicy::Keep);
 }
 
 /// Verifies that transfer sets the new owner, advances the SMT root, and emits a TRANSFER event.
@@ -1225,7 +1283,14 @@ fn test_transfer_same_owner_panics() {
         BytesN::from_array(&env, &[0u8; 32]),
     );
     // new_owner == old_owner must panic with SameOwner (#8)
-    client.transfer(&owner, &hash, &owner, &dummy_proof(&env), &signals);
+    client.transfer(
+        &owner,
+        &hash,
+        &owner,
+        &dummy_proof(&env),
+        &signals,
+        &RecordPolicy::Keep,
+    );
 }
 
 /// Verifies that `transfer_ownership` rejects a same-owner transfer with `SameOwner` (#8).
@@ -1241,7 +1306,7 @@ fn test_transfer_ownership_same_owner_fails() {
 
     client.register(&owner, &hash);
     // new_owner == current owner must return SameOwner (#8), not a generic host error.
-    client.transfer_ownership(&owner, &hash, &owner);
+    client.transfer_ownership(&owner, &hash, &owner, &RecordPolicy::Keep);
 }
 
 #[test]
@@ -1264,7 +1329,14 @@ fn test_transfer_non_owner_panics() {
         BytesN::from_array(&env, &[0u8; 32]),
     );
     // attacker is not the owner → Unauthorized (#7)
-    client.transfer(&attacker, &hash, &new_owner, &dummy_proof(&env), &signals);
+    client.transfer(
+        &attacker,
+        &hash,
+        &new_owner,
+        &dummy_proof(&env),
+        &signals,
+        &RecordPolicy::Keep,
+    );
 }
 
 #[test]
@@ -1307,7 +1379,14 @@ fn test_full_identity_lifecycle() {
         old_root: root1.clone(),
         new_root: root2.clone(),
     };
-    client.transfer(&owner, &hash, &new_owner, &dummy_proof(&env), &signals);
+    client.transfer(
+        &owner,
+        &hash,
+        &new_owner,
+        &dummy_proof(&env),
+        &signals,
+        &RecordPolicy::Keep,
+    );
 
     assert_eq!(client.get_owner(&hash), Some(new_owner.clone()));
     assert_eq!(client.get_smt_root(), root2);
@@ -1648,7 +1727,4518 @@ fn test_get_created_at_unchanged_after_transfer() {
 
     // Advance time and transfer — created_at must remain the original timestamp
     env.ledger().set_timestamp(2_000_000);
-    client.transfer_ownership(&owner, &hash, &new_owner);
+    client.transfer_ownership(&owner, &hash, &new_owner, &RecordPolicy::Keep);
 
     assert_eq!(client.get_created_at(&hash), Some(1_000_000u64));
 }
+
+// ── verified chain link tests ─────────────────────────────────────────────────
+
+fn evm_signer() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).expect("valid secp256k1 key")
+}
+
+/// Derives the `0x`-prefixed lowercase hex address for an EVM signing key.
+fn evm_address_of(env: &Env, key: &k256::ecdsa::SigningKey) -> Bytes {
+    let point = key.verifying_key().to_encoded_point(false);
+    let hash = env
+        .crypto()
+        .keccak256(&Bytes::from_slice(env, &point.as_bytes()[1..]))
+        .to_array();
+    let mut out = Bytes::from_slice(env, b"0x");
+    for b in &hash[12..] {
+        out.push_back(b"0123456789abcdef"[(b >> 4) as usize]);
+        out.push_back(b"0123456789abcdef"[(b & 0x0f) as usize]);
+    }
+    out
+}
+
+/// Produces a 65-byte `personal_sign` signature (`r || s || v`) over `message`.
+fn evm_sign(env: &Env, key: &k256::ecdsa::SigningKey, message: &Bytes) -> Bytes {
+    let mut prefixed = Bytes::from_slice(env, b"\x19Ethereum Signed Message:\n");
+    let mut digits = [0u8; 10];
+    let mut n = message.len();
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    prefixed.extend_from_slice(&digits[i..]);
+    prefixed.append(message);
+    let digest = env.crypto().keccak256(&prefixed).to_array();
+    let (sig, recovery_id) = key
+        .sign_prehash_recoverable(&digest)
+        .expect("signing should succeed");
+    let mut out = Bytes::from_slice(env, &sig.to_bytes());
+    out.push_back(27 + recovery_id.to_byte());
+    out
+}
+
+fn solana_signer() -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[9u8; 32])
+}
+
+fn solana_address_of(env: &Env, key: &ed25519_dalek::SigningKey) -> Bytes {
+    let mut buf = [0u8; 64];
+    let len = bs58::encode(key.verifying_key().as_bytes())
+        .onto(&mut buf[..])
+        .expect("base58 encoding fits in buffer");
+    Bytes::from_slice(env, &buf[..len])
+}
+
+fn solana_sign(env: &Env, key: &ed25519_dalek::SigningKey, message: &Bytes) -> Bytes {
+    use ed25519_dalek::Signer;
+    let mut buf = [0u8; 256];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    Bytes::from_slice(env, &key.sign(&buf[..len]).to_bytes())
+}
+
+#[test]
+fn test_add_verified_evm_address_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 110);
+    client.register(&owner, &hash);
+
+    let key = evm_signer();
+    let addr = evm_address_of(&env, &key);
+    let message = client.chain_link_message(&hash, &ChainType::Evm);
+    let sig = evm_sign(&env, &key, &message);
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &addr, &sig);
+
+    assert_eq!(
+        client.get_verified_chain_address(&hash, &ChainType::Evm),
+        Some(addr.clone())
+    );
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), Some(addr));
+    assert_eq!(client.get_link_nonce(&hash), 1);
+}
+
+#[test]
+fn test_unverified_chain_address_hidden_from_verified_view() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 111);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+    assert_eq!(
+        client.get_verified_chain_address(&hash, &ChainType::Evm),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5004)")]
+fn test_add_verified_evm_address_wrong_signer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 112);
+    client.register(&owner, &hash);
+
+    let message = client.chain_link_message(&hash, &ChainType::Evm);
+    let sig = evm_sign(&env, &evm_signer(), &message);
+
+    // Signature is from a different key than the claimed address.
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env), &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5004)")]
+fn test_add_verified_evm_address_replay_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 113);
+    client.register(&owner, &hash);
+
+    let key = evm_signer();
+    let addr = evm_address_of(&env, &key);
+    let message = client.chain_link_message(&hash, &ChainType::Evm);
+    let sig = evm_sign(&env, &key, &message);
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &addr, &sig);
+    // The nonce has advanced, so the same signature no longer matches.
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &addr, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5004)")]
+fn test_add_verified_evm_address_other_commitment_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 114);
+    let other = commitment(&env, 115);
+    client.register(&owner, &hash);
+    client.register(&owner, &other);
+
+    let key = evm_signer();
+    let addr = evm_address_of(&env, &key);
+    let message = client.chain_link_message(&other, &ChainType::Evm);
+    let sig = evm_sign(&env, &key, &message);
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &addr, &sig);
+}
+
+#[test]
+fn test_add_verified_solana_address_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 116);
+    client.register(&owner, &hash);
+
+    let key = solana_signer();
+    let addr = solana_address_of(&env, &key);
+    let message = client.chain_link_message(&hash, &ChainType::Solana);
+    let sig = solana_sign(&env, &key, &message);
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Solana, &addr, &sig);
+
+    assert_eq!(
+        client.get_verified_chain_address(&hash, &ChainType::Solana),
+        Some(addr)
+    );
+}
+
+/// A well-formed signature over the wrong message can only be rejected by the host's
+/// ed25519 check, which traps rather than returning a contract error.
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_add_verified_solana_address_bad_signature_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 117);
+    client.register(&owner, &hash);
+
+    let key = solana_signer();
+    let addr = solana_address_of(&env, &key);
+    let sig = solana_sign(&env, &key, &Bytes::from_slice(&env, b"unrelated"));
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Solana, &addr, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5004)")]
+fn test_add_verified_solana_address_non_canonical_signature_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 117);
+    client.register(&owner, &hash);
+
+    let key = solana_signer();
+    let addr = solana_address_of(&env, &key);
+    let message = client.chain_link_message(&hash, &ChainType::Solana);
+    // Keep R but push S past the group order, which no valid signature can have.
+    let mut sig = solana_sign(&env, &key, &message).slice(0..32);
+    sig.extend_from_array(&[0xff; 32]);
+
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Solana, &addr, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5005)")]
+fn test_add_verified_bitcoin_address_unsupported() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 118);
+    client.register(&owner, &hash);
+
+    client.add_verified_chain_address(
+        &owner,
+        &hash,
+        &ChainType::Bitcoin,
+        &bitcoin_address(&env),
+        &Bytes::from_slice(&env, &[1u8; 65]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5001)")]
+fn test_add_verified_chain_address_wrong_owner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let hash = commitment(&env, 119);
+    client.register(&owner, &hash);
+
+    let key = evm_signer();
+    let addr = evm_address_of(&env, &key);
+    let message = client.chain_link_message(&hash, &ChainType::Evm);
+    let sig = evm_sign(&env, &key, &message);
+
+    client.add_verified_chain_address(&attacker, &hash, &ChainType::Evm, &addr, &sig);
+}
+
+// ── multi-address / primary tests ─────────────────────────────────────────────
+
+fn second_evm_address(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"0x1111111111111111111111111111111111111111")
+}
+
+fn label(env: &Env, name: &str) -> AddressMetadata {
+    AddressMetadata {
+        label: Symbol::new(env, name),
+    }
+}
+
+#[test]
+fn test_multiple_chain_addresses_latest_is_primary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 130);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(second_evm_address(&env))
+    );
+    let listed = client.list_chain_addresses(&hash, &ChainType::Evm, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(
+        listed.get(0).expect("first entry missing").address,
+        evm_address(&env)
+    );
+}
+
+#[test]
+fn test_set_primary_chain_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 131);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+    client.set_primary_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5008)")]
+fn test_set_primary_chain_address_unknown_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 132);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.set_primary_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5006)")]
+fn test_add_duplicate_chain_address_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 133);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5007)")]
+fn test_add_chain_address_over_limit_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 134);
+    client.register(&owner, &hash);
+
+    for i in 0..=crate::storage::MAX_ADDRESSES_PER_CHAIN {
+        let mut raw = *b"0x0000000000000000000000000000000000000000";
+        raw[41] = b'0' + (i % 10) as u8;
+        raw[40] = b'0' + (i / 10) as u8;
+        client.add_chain_address(
+            &owner,
+            &hash,
+            &ChainType::Evm,
+            &Bytes::from_slice(&env, &raw),
+        );
+    }
+}
+
+#[test]
+fn test_remove_chain_address_entry_falls_back_to_latest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 135);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+    client.remove_chain_address_entry(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+    client.remove_chain_address_entry(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert_eq!(
+        client
+            .list_chain_addresses(&hash, &ChainType::Evm, &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_set_chain_address_label() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 136);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.set_chain_address_label(
+        &owner,
+        &hash,
+        &ChainType::Evm,
+        &evm_address(&env),
+        &label(&env, "treasury"),
+    );
+
+    let listed = client.list_chain_addresses(&hash, &ChainType::Evm, &0, &10);
+    assert_eq!(
+        listed.get(0).expect("entry missing").metadata,
+        label(&env, "treasury")
+    );
+}
+
+#[test]
+fn test_list_chain_addresses_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 137);
+    client.register(&owner, &hash);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+
+    let page = client.list_chain_addresses(&hash, &ChainType::Evm, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get(0).expect("entry missing").address,
+        second_evm_address(&env)
+    );
+    assert_eq!(
+        client
+            .list_chain_addresses(&hash, &ChainType::Evm, &5, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_legacy_primary_chain_address_listed_as_unverified() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 19);
+    client.register(&owner, &hash);
+
+    // Primary written before the per-chain list existed.
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &ChainAddrKey::ChainAddress(hash.clone(), ChainType::Evm),
+            &evm_address(&env),
+        );
+    });
+
+    let listed = client.list_chain_addresses(&hash, &ChainType::Evm, &0, &10);
+    assert_eq!(listed.len(), 1);
+    let record = listed.get(0).expect("entry missing");
+    assert_eq!(record.address, evm_address(&env));
+    assert!(!record.verified);
+
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &second_evm_address(&env));
+    assert_eq!(
+        client
+            .list_chain_addresses(&hash, &ChainType::Evm, &0, &10)
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_verified_link_upgrades_existing_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 138);
+    client.register(&owner, &hash);
+
+    let key = evm_signer();
+    let addr = evm_address_of(&env, &key);
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &addr);
+    client.add_chain_address(&owner, &hash, &ChainType::Evm, &evm_address(&env));
+
+    let message = client.chain_link_message(&hash, &ChainType::Evm);
+    let sig = evm_sign(&env, &key, &message);
+    client.add_verified_chain_address(&owner, &hash, &ChainType::Evm, &addr, &sig);
+
+    let listed = client.list_chain_addresses(&hash, &ChainType::Evm, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert!(listed.get(0).expect("entry missing").verified);
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), Some(addr));
+}
+
+#[test]
+fn test_set_primary_stellar_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 139);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.register(&owner, &hash);
+
+    client.add_stellar_address(&owner, &hash, &first);
+    client.add_stellar_address(&owner, &hash, &second);
+    client.set_primary_stellar_address(&owner, &hash, &first);
+
+    assert_eq!(client.resolve_stellar(&hash), first);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_set_primary_stellar_address_unlinked_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 140);
+    client.register(&owner, &hash);
+
+    client.add_stellar_address(&owner, &hash, &Address::generate(&env));
+    client.set_primary_stellar_address(&owner, &hash, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4012)")]
+fn test_add_duplicate_stellar_address_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 141);
+    let addr = Address::generate(&env);
+    client.register(&owner, &hash);
+
+    client.add_stellar_address(&owner, &hash, &addr);
+    client.add_stellar_address(&owner, &hash, &addr);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4013)")]
+fn test_add_stellar_address_over_limit_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 142);
+    client.register(&owner, &hash);
+
+    for _ in 0..=crate::storage::MAX_ADDRESSES_PER_CHAIN {
+        client.add_stellar_address(&owner, &hash, &Address::generate(&env));
+    }
+}
+
+#[test]
+fn test_list_stellar_addresses_with_labels() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 143);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.register(&owner, &hash);
+
+    client.add_stellar_address(&owner, &hash, &first);
+    client.add_stellar_address(&owner, &hash, &second);
+    client.set_stellar_address_label(&owner, &hash, &second, &label(&env, "payroll"));
+
+    let listed = client.list_stellar_addresses(&hash, &0, &10);
+    assert_eq!(listed.len(), 2);
+    let entry = listed.get(1).expect("second entry missing");
+    assert_eq!(entry.address, second);
+    assert_eq!(entry.metadata, label(&env, "payroll"));
+}
+
+// ── delegate tests ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_grant_delegate_listed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 150);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Addresses, &None);
+
+    let delegates = client.get_delegates(&hash);
+    assert_eq!(delegates.len(), 1);
+    assert_eq!(
+        delegates.get(0).expect("grant missing"),
+        DelegateGrant {
+            delegate: ops,
+            role: DelegateRole::Addresses,
+            expires_at: None,
+        }
+    );
+}
+
+#[test]
+fn test_address_manager_delegate_can_add_chain_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 151);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Addresses, &None);
+    client.add_chain_address(&ops, &hash, &ChainType::Evm, &evm_address(&env));
+
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5001)")]
+fn test_wrong_role_delegate_cannot_add_chain_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 152);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Payments, &None);
+    client.add_chain_address(&ops, &hash, &ChainType::Evm, &evm_address(&env));
+}
+
+#[test]
+fn test_payments_manager_delegate_manages_stellar_and_memo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let hash = commitment(&env, 153);
+    client.register(&owner, &hash);
+    client.register_resolver(
+        &owner,
+        &hash,
+        &dummy_proof(&env),
+        &signals(&hash, root, BytesN::from_array(&env, &[154u8; 32])),
+    );
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Payments, &None);
+    client.add_stellar_address(&ops, &hash, &wallet);
+    client.set_memo(&ops, &hash, &7u64);
+    client.add_shielded_address(&ops, &hash, &BytesN::from_array(&env, &[5u8; 32]));
+
+    assert_eq!(client.resolve_stellar(&hash), wallet);
+    assert_eq!(client.resolve(&hash), (owner, Some(7u64)));
+    assert!(client.is_shielded(&hash));
+}
+
+#[test]
+fn test_records_manager_delegate_sets_privacy_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 155);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Records, &None);
+    client.set_privacy_mode(&ops, &hash, &PrivacyMode::Shielded);
+
+    assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Shielded);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_memo_non_owner_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let caller = Address::generate(&env);
+    let hash = commitment(&env, 156);
+    client.register_resolver(
+        &caller,
+        &hash,
+        &dummy_proof(&env),
+        &signals(&hash, root, BytesN::from_array(&env, &[157u8; 32])),
+    );
+
+    client.set_memo(&Address::generate(&env), &hash, &1u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_expired_delegate_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 158);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(1_000);
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Records, &Some(2_000u64));
+    client.set_privacy_mode(&ops, &hash, &PrivacyMode::Shielded);
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+    client.set_privacy_mode(&ops, &hash, &PrivacyMode::Normal);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_revoked_delegate_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 159);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Records, &None);
+    client.revoke_delegate(&owner, &hash, &ops, &DelegateRole::Records);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+
+    client.set_privacy_mode(&ops, &hash, &PrivacyMode::Shielded);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_delegate_cannot_grant_delegates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 160);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Addresses, &None);
+    client.grant_delegate(
+        &ops,
+        &hash,
+        &Address::generate(&env),
+        &DelegateRole::Addresses,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4015)")]
+fn test_grant_delegate_past_expiry_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 161);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(5_000);
+    client.grant_delegate(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &DelegateRole::Addresses,
+        &Some(5_000u64),
+    );
+}
+
+#[test]
+fn test_regrant_delegate_replaces_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let ops = Address::generate(&env);
+    let hash = commitment(&env, 162);
+    client.register(&owner, &hash);
+
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Addresses, &Some(10u64));
+    client.grant_delegate(&owner, &hash, &ops, &DelegateRole::Addresses, &None);
+
+    let delegates = client.get_delegates(&hash);
+    assert_eq!(delegates.len(), 1);
+    assert_eq!(delegates.get(0).expect("grant missing").expires_at, None);
+}
+
+// ── two-step ownership transfer tests ─────────────────────────────────────────
+
+#[test]
+fn test_propose_and_accept_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let hash = commitment(&env, 170);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(1_000);
+    client.propose_transfer(&owner, &hash, &new_owner, &2_000, &RecordPolicy::Keep);
+
+    // Ownership does not move until the recipient accepts.
+    assert_eq!(client.get_owner(&hash), Some(owner.clone()));
+    assert_eq!(
+        client.get_pending_transfer(&hash),
+        Some(PendingTransfer {
+            from: owner,
+            to: new_owner.clone(),
+            expires_at: 2_000,
+            policy: RecordPolicy::Keep,
+        })
+    );
+
+    client.accept_transfer(&new_owner, &hash);
+
+    assert_eq!(client.get_owner(&hash), Some(new_owner));
+    assert_eq!(client.get_pending_transfer(&hash), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_accept_transfer_wrong_recipient_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let hash = commitment(&env, 171);
+    client.register(&owner, &hash);
+
+    client.propose_transfer(&owner, &hash, &new_owner, &100, &RecordPolicy::Keep);
+    client.accept_transfer(&attacker, &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4017)")]
+fn test_accept_transfer_after_expiry_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let hash = commitment(&env, 172);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(1_000);
+    client.propose_transfer(&owner, &hash, &new_owner, &2_000, &RecordPolicy::Keep);
+    env.ledger().set_timestamp(2_000);
+    client.accept_transfer(&new_owner, &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4016)")]
+fn test_accept_cancelled_transfer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let hash = commitment(&env, 173);
+    client.register(&owner, &hash);
+
+    client.propose_transfer(&owner, &hash, &new_owner, &100, &RecordPolicy::Keep);
+    client.cancel_transfer(&owner, &hash);
+    assert_eq!(client.get_pending_transfer(&hash), None);
+    client.accept_transfer(&new_owner, &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_propose_transfer_non_owner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let hash = commitment(&env, 174);
+    client.register(&owner, &hash);
+
+    client.propose_transfer(&attacker, &hash, &attacker, &100, &RecordPolicy::Keep);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4015)")]
+fn test_propose_transfer_past_expiry_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 175);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(500);
+    client.propose_transfer(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &500,
+        &RecordPolicy::Keep,
+    );
+}
+
+#[test]
+fn test_direct_transfer_clears_pending_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let invited = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 176);
+    client.register(&owner, &hash);
+
+    client.propose_transfer(&owner, &hash, &invited, &100, &RecordPolicy::Keep);
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Keep);
+
+    assert_eq!(client.get_pending_transfer(&hash), None);
+    assert_eq!(client.get_owner(&hash), Some(buyer));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4018)")]
+fn test_transfer_ownership_disabled_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 177);
+    client.register(&owner, &hash);
+
+    assert!(client.direct_transfers_enabled());
+    client.set_direct_transfers_enabled(&false);
+    assert!(!client.direct_transfers_enabled());
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+}
+
+// ── record policy on transfer tests ───────────────────────────────────────────
+
+/// Registers `hash` to `owner` and links a Stellar, EVM and shielded address plus
+/// one `Records` and one `Payments` delegate.
+fn populate_records(env: &Env, client: &ContractClient, owner: &Address, hash: &BytesN<32>) {
+    client.register(owner, hash);
+    client.add_stellar_address(owner, hash, owner);
+    client.add_chain_address(owner, hash, &ChainType::Evm, &evm_address(env));
+    client.add_shielded_address(owner, hash, &BytesN::from_array(env, &[7u8; 32]));
+    client.grant_delegate(
+        owner,
+        hash,
+        &Address::generate(env),
+        &DelegateRole::Records,
+        &None,
+    );
+    client.grant_delegate(
+        owner,
+        hash,
+        &Address::generate(env),
+        &DelegateRole::Payments,
+        &None,
+    );
+}
+
+#[test]
+fn test_transfer_ownership_clear_policy_wipes_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 180);
+    populate_records(&env, &client, &owner, &hash);
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Clear);
+
+    assert_eq!(client.get_owner(&hash), Some(buyer));
+    assert_eq!(client.get_stellar_addresses(&hash).len(), 0);
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert_eq!(
+        client
+            .list_chain_addresses(&hash, &ChainType::Evm, &0, &10)
+            .len(),
+        0
+    );
+    assert_eq!(client.get_shielded_address(&hash), None);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4006)")]
+fn test_transfer_ownership_clear_policy_unlinks_primary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 181);
+    populate_records(&env, &client, &owner, &hash);
+
+    client.transfer_ownership(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &RecordPolicy::Clear,
+    );
+    client.resolve_stellar(&hash);
+}
+
+#[test]
+fn test_transfer_ownership_keep_policy_retains_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 182);
+    populate_records(&env, &client, &owner, &hash);
+
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+
+    assert_eq!(client.resolve_stellar(&hash), owner);
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+    assert!(client.is_shielded(&hash));
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+}
+
+#[test]
+fn test_keep_policy_transfer_revokes_seller_delegates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let hash = commitment(&env, 218);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &operator, &DelegateRole::Payments, &None);
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Keep);
+
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+    assert_eq!(
+        client.try_add_stellar_address(&operator, &hash, &operator),
+        Err(Ok(Error::from_contract_error(
+            CoreError::Unauthorized as u32
+        )))
+    );
+}
+
+#[test]
+fn test_transfer_ownership_keep_non_payment_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 183);
+    populate_records(&env, &client, &owner, &hash);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
+
+    client.transfer_ownership(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &RecordPolicy::KeepNonPayment,
+    );
+
+    assert_eq!(client.get_stellar_addresses(&hash).len(), 0);
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert!(!client.is_shielded(&hash));
+    assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Shielded);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+}
+
+#[test]
+fn test_clear_policy_resets_resolver_and_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 204);
+    register_wallet(&env, &client, root, &hash, &owner, Some(7));
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::new(&env),
+    );
+    client.set_privacy_policy(&owner, &hash, &uniform_policy(&env, Visibility::Public));
+    assert_eq!(client.resolve(&hash), (owner.clone(), Some(7)));
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Clear);
+
+    assert_eq!(client.resolve(&hash), (buyer, None));
+    assert_eq!(client.get_encrypted_record(&hash), None);
+    assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Normal);
+}
+
+#[test]
+fn test_keep_non_payment_policy_resets_resolver_and_removes_encrypted_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 205);
+    register_wallet(&env, &client, root, &hash, &owner, Some(7));
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &soroban_sdk::vec![&env, record_grant(&env, 1, 0xE1)],
+    );
+    let mut policy = uniform_policy(&env, Visibility::Public);
+    policy.text = Visibility::Hidden;
+    client.set_privacy_policy(&owner, &hash, &policy);
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::KeepNonPayment);
+
+    assert_eq!(client.resolve(&hash), (buyer, None));
+    // The encrypted record routes payments, so it goes with its grants.
+    assert_eq!(client.get_encrypted_record(&hash), None);
+    assert_eq!(client.get_privacy_policy(&hash), policy);
+}
+
+#[test]
+fn test_keep_policy_keeps_resolver() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 206);
+    register_wallet(&env, &client, root, &hash, &owner, Some(7));
+
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+
+    assert_eq!(client.resolve(&hash), (owner, Some(7)));
+}
+
+#[test]
+fn test_accept_transfer_applies_proposed_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 184);
+    populate_records(&env, &client, &owner, &hash);
+
+    client.propose_transfer(&owner, &hash, &buyer, &100, &RecordPolicy::Clear);
+    // Records stay attached until the recipient accepts.
+    assert_eq!(client.get_stellar_addresses(&hash).len(), 1);
+
+    client.accept_transfer(&buyer, &hash);
+
+    assert_eq!(client.get_stellar_addresses(&hash).len(), 0);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+}
+
+#[test]
+fn test_clear_policy_emits_records_reset_event() {
+    use crate::events::records_reset_event;
+    use crate::transfer::Transfer;
+
+    let env = Env::default();
+    let (contract_id, _) = setup(&env);
+    let hash = commitment(&env, 185);
+
+    env.as_contract(&contract_id, || {
+        Transfer::apply_record_policy(&env, &hash, RecordPolicy::Clear);
+    });
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    let (_, topics, data) = events.get(0).expect("event missing");
+    let topic: Symbol = topics.get(0).expect("topic missing").into_val(&env);
+    assert_eq!(topic, records_reset_event(&env));
+    let (reset_hash, policy): (BytesN<32>, RecordPolicy) = data.into_val(&env);
+    assert_eq!(reset_hash, hash);
+    assert_eq!(policy, RecordPolicy::Clear);
+}
+
+// ── guardian recovery tests ───────────────────────────────────────────────────
+
+/// The 2-of-3 member set [`setup_members`] configures.
+enum Members {
+    /// Recovery guardians with a 100s delay.
+    Guardians,
+    /// A signer group the name is handed to.
+    Signers,
+}
+
+/// Registers `hash` to a fresh owner and configures three fresh members with a threshold
+/// of two. Returns the owner (the former owner for `Signers`) and the members.
+fn setup_members(
+    env: &Env,
+    client: &ContractClient,
+    hash: &BytesN<32>,
+    kind: Members,
+) -> (Address, [Address; 3]) {
+    let owner = Address::generate(env);
+    let members: [Address; 3] = core::array::from_fn(|_| Address::generate(env));
+    client.register(&owner, hash);
+    let list = Vec::from_array(env, members.clone());
+    match kind {
+        Members::Guardians => client.set_guardians(&owner, hash, &list, &2, &100),
+        Members::Signers => client.set_multisig(&owner, hash, &list, &2),
+    }
+    (owner, members)
+}
+
+#[test]
+fn test_guardian_recovery_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 190);
+    let (_, [g1, g2, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000);
+    client.recover(&g1, &hash, &new_owner);
+    assert_eq!(
+        client
+            .get_recovery(&hash)
+            .expect("request missing")
+            .executable_at,
+        None
+    );
+    client.recover(&g2, &hash, &new_owner);
+    assert_eq!(
+        client
+            .get_recovery(&hash)
+            .expect("request missing")
+            .executable_at,
+        Some(1_100)
+    );
+
+    env.ledger().set_timestamp(1_100);
+    client.execute_recovery(&hash);
+
+    assert_eq!(client.get_owner(&hash), Some(new_owner));
+    assert_eq!(client.get_recovery(&hash), None);
+    // Guardians survive recovery so the recovered owner stays protected.
+    assert!(client.get_guardians(&hash).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4023)")]
+fn test_execute_recovery_before_timelock_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 191);
+    let (_, [g1, g2, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000);
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g2, &hash, &new_owner);
+    env.ledger().set_timestamp(1_099);
+    client.execute_recovery(&hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4023)")]
+fn test_execute_recovery_below_threshold_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 192);
+    let (_, [g1, _, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+
+    client.recover(&g1, &hash, &Address::generate(&env));
+    env.ledger().set_timestamp(10_000);
+    client.execute_recovery(&hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4022)")]
+fn test_owner_veto_cancels_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 193);
+    let (owner, [g1, g2, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g2, &hash, &new_owner);
+    client.cancel_recovery(&owner, &hash);
+    assert_eq!(client.get_recovery(&hash), None);
+
+    env.ledger().set_timestamp(10_000);
+    client.execute_recovery(&hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_recover_by_non_guardian_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 194);
+    setup_members(&env, &client, &hash, Members::Guardians);
+
+    let attacker = Address::generate(&env);
+    client.recover(&attacker, &hash, &attacker);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4021)")]
+fn test_recover_duplicate_approval_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 195);
+    let (_, [g1, _, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g1, &hash, &new_owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4020)")]
+fn test_recover_conflicting_new_owner_after_threshold_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 196);
+    let (_, [g1, g2, g3]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g2, &hash, &new_owner);
+    client.recover(&g3, &hash, &Address::generate(&env));
+}
+
+#[test]
+fn test_recover_rogue_guardian_request_is_superseded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 219);
+    let (_, [g1, g2, g3]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let attacker = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000);
+    client.recover(&g1, &hash, &attacker);
+    client.recover(&g2, &hash, &new_owner);
+    // A tie leaves the first request open.
+    assert_eq!(
+        client
+            .get_recovery(&hash)
+            .expect("request missing")
+            .new_owner,
+        attacker
+    );
+    assert_eq!(
+        client.try_recover(&g1, &hash, &new_owner),
+        Err(Ok(Error::from_contract_error(4021)))
+    );
+
+    client.recover(&g3, &hash, &new_owner);
+
+    let request = client.get_recovery(&hash).expect("request missing");
+    assert_eq!(request.new_owner, new_owner);
+    assert_eq!(request.approvals, Vec::from_array(&env, [g2, g3]));
+    assert_eq!(request.executable_at, Some(1_100));
+    assert_eq!(
+        request.challengers.get(attacker),
+        Some(Vec::from_array(&env, [g1]))
+    );
+
+    env.ledger().set_timestamp(1_100);
+    client.execute_recovery(&hash);
+    assert_eq!(client.get_owner(&hash), Some(new_owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4019)")]
+fn test_set_guardians_threshold_above_count_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 197);
+    client.register(&owner, &hash);
+
+    let guardians = Vec::from_array(&env, [Address::generate(&env)]);
+    client.set_guardians(&owner, &hash, &guardians, &2, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4019)")]
+fn test_set_guardians_duplicate_guardian_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let hash = commitment(&env, 198);
+    client.register(&owner, &hash);
+
+    let guardians = Vec::from_array(&env, [guardian.clone(), guardian]);
+    client.set_guardians(&owner, &hash, &guardians, &1, &0);
+}
+
+#[test]
+fn test_transfer_clears_guardians_and_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 199);
+    let (owner, [g1, _, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+
+    client.recover(&g1, &hash, &Address::generate(&env));
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+
+    assert_eq!(client.get_guardians(&hash), None);
+    assert_eq!(client.get_recovery(&hash), None);
+}
+
+// ── burn tests ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_burn_removes_all_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 210);
+    populate_records(&env, &client, &owner, &hash);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
+    client.set_guardians(
+        &owner,
+        &hash,
+        &Vec::from_array(&env, [Address::generate(&env)]),
+        &1,
+        &0,
+    );
+
+    client.burn(&owner, &hash);
+
+    assert_eq!(client.get_owner(&hash), None);
+    assert_eq!(client.get_created_at(&hash), None);
+    assert_eq!(client.history(&hash, &0, &10).len(), 0);
+    assert_eq!(client.shielded_address_history(&hash).len(), 0);
+    assert_eq!(
+        client.try_resolve_stellar(&hash),
+        Err(Ok(Error::from_contract_error(CoreError::NotFound as u32)))
+    );
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert_eq!(client.get_shielded_address(&hash), None);
+    assert_eq!(client.get_privacy_mode(&hash), PrivacyMode::Normal);
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+    assert_eq!(client.get_guardians(&hash), None);
+}
+
+#[test]
+fn test_burn_allows_reregistration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let next = Address::generate(&env);
+    let hash = commitment(&env, 211);
+    client.register(&owner, &hash);
+
+    client.burn(&owner, &hash);
+    client.register(&next, &hash);
+
+    assert_eq!(client.get_owner(&hash), Some(next));
+}
+
+#[test]
+fn test_burn_leaves_smt_root_unchanged() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 212);
+    client.register(&owner, &hash);
+
+    client.burn(&owner, &hash);
+
+    assert_eq!(client.get_smt_root(), root);
+    assert_eq!(client.get_owner(&hash), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_burn_non_owner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 214);
+    client.register(&owner, &hash);
+
+    client.burn(&Address::generate(&env), &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_burn_unregistered_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    client.burn(&Address::generate(&env), &commitment(&env, 215));
+}
+
+// ── reserved name tests ───────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4025)")]
+fn test_register_reserved_name_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let hash = commitment(&env, 220);
+
+    client.reserve_name(&hash, &ReservedReason::Offensive, &None);
+    client.register(&Address::generate(&env), &hash);
+}
+
+#[test]
+fn test_register_reserved_name_by_claimant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let brand = Address::generate(&env);
+    let hash = commitment(&env, 221);
+
+    client.reserve_name(&hash, &ReservedReason::Brand, &Some(brand.clone()));
+    client.register(&brand, &hash);
+
+    assert_eq!(client.get_owner(&hash), Some(brand));
+    // The reservation outlives the claim so a burned name returns to reserved.
+    assert!(client.get_reserved(&hash).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4025)")]
+fn test_submit_proof_reserved_name_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    client.initialize(&Address::generate(&env));
+    let hash = commitment(&env, 222);
+
+    client.reserve_name(&hash, &ReservedReason::Protocol, &None);
+    let signals = signals(&hash, root, BytesN::from_array(&env, &[222u8; 32]));
+    client.submit_proof(&Address::generate(&env), &dummy_proof(&env), &signals);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4025)")]
+fn test_register_resolver_reserved_name_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    client.initialize(&Address::generate(&env));
+    let hash = commitment(&env, 223);
+
+    client.reserve_name(
+        &hash,
+        &ReservedReason::Brand,
+        &Some(Address::generate(&env)),
+    );
+    let signals = signals(&hash, root, BytesN::from_array(&env, &[223u8; 32]));
+    client.register_resolver(
+        &Address::generate(&env),
+        &hash,
+        &dummy_proof(&env),
+        &signals,
+    );
+}
+
+#[test]
+fn test_unreserve_name_allows_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 224);
+
+    client.reserve_name(&hash, &ReservedReason::Protocol, &None);
+    client.unreserve_name(&hash);
+    client.register(&owner, &hash);
+
+    assert_eq!(client.get_reserved(&hash), None);
+    assert_eq!(client.get_owner(&hash), Some(owner));
+}
+
+#[test]
+fn test_reserve_names_bulk_import() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+
+    let names = Vec::from_array(
+        &env,
+        [
+            ReservedName {
+                commitment: commitment(&env, 225),
+                reason: ReservedReason::Brand,
+                claimant: None,
+            },
+            ReservedName {
+                commitment: commitment(&env, 226),
+                reason: ReservedReason::Offensive,
+                claimant: None,
+            },
+        ],
+    );
+    client.reserve_names(&names);
+
+    assert_eq!(client.get_reserved(&commitment(&env, 226)), names.get(1));
+    assert!(client.get_reserved(&commitment(&env, 225)).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_unreserve_unknown_name_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+
+    client.unreserve_name(&commitment(&env, 227));
+}
+
+#[test]
+fn test_reserve_name_requires_admin_auth() {
+    let env = Env::default();
+    let (contract_id, client) = setup(&env);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    let hash = commitment(&env, 228);
+
+    env.mock_auths(&[]);
+    assert!(client
+        .try_reserve_name(&hash, &ReservedReason::Brand, &None)
+        .is_err());
+
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "reserve_name",
+            args: (hash.clone(), ReservedReason::Brand, None::<Address>).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.reserve_name(&hash, &ReservedReason::Brand, &None);
+    assert!(client.get_reserved(&hash).is_some());
+}
+
+// ── allocation claim tests ────────────────────────────────────────────────────
+
+fn claim_leaf(env: &Env, hash: &BytesN<32>, claimant: &Address) -> BytesN<32> {
+    use soroban_sdk::xdr::ToXdr;
+    let mut preimage = Bytes::from_array(env, &hash.to_array());
+    preimage.append(&claimant.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+fn claim_node(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut pair = Bytes::from_array(env, &left.to_array());
+    pair.append(&Bytes::from_array(env, &right.to_array()));
+    env.crypto().sha256(&pair).into()
+}
+
+/// Publishes a two-leaf round and returns (round_id, claimant_a, hash_a, proof_a).
+fn setup_claim_round(
+    env: &Env,
+    client: &ContractClient,
+    seed: u8,
+) -> (u32, Address, BytesN<32>, Vec<BytesN<32>>) {
+    let claimant_a = Address::generate(env);
+    let claimant_b = Address::generate(env);
+    let hash_a = commitment(env, seed);
+    let hash_b = commitment(env, seed + 1);
+    let leaf_a = claim_leaf(env, &hash_a, &claimant_a);
+    let leaf_b = claim_leaf(env, &hash_b, &claimant_b);
+    let root = claim_node(env, &leaf_a, &leaf_b);
+
+    let round_id = client.create_claim_round(&root, &1_000);
+    (round_id, claimant_a, hash_a, Vec::from_array(env, [leaf_b]))
+}
+
+#[test]
+fn test_claim_allocated_registers_name() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let (round_id, claimant, hash, proof) = setup_claim_round(&env, &client, 230);
+
+    client.claim_allocated(&claimant, &round_id, &hash, &proof);
+
+    assert_eq!(client.get_owner(&hash), Some(claimant));
+    assert!(client.is_claimed(&round_id, &hash));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4005)")]
+fn test_claim_allocated_wrong_claimant_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let (round_id, _, hash, proof) = setup_claim_round(&env, &client, 232);
+
+    client.claim_allocated(&Address::generate(&env), &round_id, &hash, &proof);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4027)")]
+fn test_claim_allocated_after_deadline_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let (round_id, claimant, hash, proof) = setup_claim_round(&env, &client, 234);
+
+    env.ledger().set_timestamp(1_000);
+    client.claim_allocated(&claimant, &round_id, &hash, &proof);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4028)")]
+fn test_claim_allocated_is_one_shot() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let (round_id, claimant, hash, proof) = setup_claim_round(&env, &client, 236);
+
+    client.claim_allocated(&claimant, &round_id, &hash, &proof);
+    client.burn(&claimant, &hash);
+    client.claim_allocated(&claimant, &round_id, &hash, &proof);
+}
+
+#[test]
+fn test_claim_rounds_are_independent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    client.initialize(&Address::generate(&env));
+    let (first, _, _, _) = setup_claim_round(&env, &client, 238);
+    let (second, claimant, hash, proof) = setup_claim_round(&env, &client, 240);
+
+    assert_eq!((first, second), (0, 1));
+    assert_ne!(
+        client.get_claim_round(&first),
+        client.get_claim_round(&second)
+    );
+    assert!(client
+        .try_claim_allocated(&claimant, &first, &hash, &proof)
+        .is_err());
+    client.claim_allocated(&claimant, &second, &hash, &proof);
+    assert_eq!(client.get_owner(&hash), Some(claimant));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_claim_allocated_unknown_round_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    client.claim_allocated(
+        &Address::generate(&env),
+        &7,
+        &commitment(&env, 242),
+        &Vec::new(&env),
+    );
+}
+
+// ── transfer lock tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_lock_transfers_sets_flag() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 250);
+    client.register(&owner, &hash);
+
+    assert!(!client.is_transfer_locked(&hash));
+    client.lock_transfers(&owner, &hash);
+    assert!(client.is_transfer_locked(&hash));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4029)")]
+fn test_lock_transfers_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 251);
+    client.register(&owner, &hash);
+
+    client.lock_transfers(&owner, &hash);
+    client.lock_transfers(&owner, &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_lock_transfers_non_owner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 252);
+    client.register(&Address::generate(&env), &hash);
+
+    client.lock_transfers(&Address::generate(&env), &hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4029)")]
+fn test_transfer_ownership_locked_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 253);
+    client.register(&owner, &hash);
+    client.lock_transfers(&owner, &hash);
+
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4029)")]
+fn test_zk_transfer_locked_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 254);
+    client.register(&owner, &hash);
+    client.lock_transfers(&owner, &hash);
+
+    client.transfer(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &dummy_proof(&env),
+        &signals(&hash, root, BytesN::from_array(&env, &[255u8; 32])),
+        &RecordPolicy::Keep,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4029)")]
+fn test_propose_transfer_locked_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 243);
+    client.register(&owner, &hash);
+    client.lock_transfers(&owner, &hash);
+
+    client.propose_transfer(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &(env.ledger().timestamp() + 100),
+        &RecordPolicy::Keep,
+    );
+}
+
+#[test]
+fn test_lock_transfers_voids_pending_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let hash = commitment(&env, 244);
+    client.register(&owner, &hash);
+    client.propose_transfer(
+        &owner,
+        &hash,
+        &recipient,
+        &(env.ledger().timestamp() + 100),
+        &RecordPolicy::Keep,
+    );
+
+    client.lock_transfers(&owner, &hash);
+
+    assert_eq!(client.get_pending_transfer(&hash), None);
+    assert!(client.try_accept_transfer(&recipient, &hash).is_err());
+    assert_eq!(client.get_owner(&hash), Some(owner));
+}
+
+#[test]
+fn test_locked_guardian_recovery_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let hash = commitment(&env, 245);
+    let (owner, [g1, g2, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+    client.lock_transfers(&owner, &hash);
+
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g2, &hash, &new_owner);
+    env.ledger().set_timestamp(10_000);
+
+    env.set_auths(&[]);
+    assert!(client.try_execute_recovery(&hash).is_err());
+
+    env.mock_all_auths();
+    client.execute_recovery(&hash);
+    assert_eq!(
+        env.auths().first().map(|(addr, _)| addr.clone()),
+        Some(admin)
+    );
+    assert_eq!(client.get_owner(&hash), Some(new_owner));
+    assert!(client.is_transfer_locked(&hash));
+}
+
+#[test]
+fn test_burn_clears_transfer_lock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 246);
+    client.register(&owner, &hash);
+    client.lock_transfers(&owner, &hash);
+
+    client.burn(&owner, &hash);
+
+    assert!(!client.is_transfer_locked(&hash));
+}
+
+// ── transfer cooldown tests ───────────────────────────────────────────────────
+
+/// Registers `hash`, sets a one-hour cooldown and transfers it at timestamp 1_000.
+/// Returns the new owner.
+fn transfer_under_cooldown(env: &Env, client: &ContractClient, hash: &BytesN<32>) -> Address {
+    client.initialize(&Address::generate(env));
+    client.set_transfer_cooldown(&3_600);
+    let owner = Address::generate(env);
+    let new_owner = Address::generate(env);
+    client.register(&owner, hash);
+    env.ledger().set_timestamp(1_000);
+    client.transfer_ownership(&owner, hash, &new_owner, &RecordPolicy::Keep);
+    new_owner
+}
+
+#[test]
+fn test_transfer_without_cooldown_does_not_freeze() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let hash = commitment(&env, 60);
+    client.register(&owner, &hash);
+
+    client.transfer_ownership(&owner, &hash, &new_owner, &RecordPolicy::Keep);
+
+    assert_eq!(client.transfer_cooldown(), 0);
+    assert_eq!(client.locked_until(&hash), 0);
+    client.add_stellar_address(&new_owner, &hash, &Address::generate(&env));
+}
+
+#[test]
+fn test_transfer_starts_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 61);
+
+    transfer_under_cooldown(&env, &client, &hash);
+
+    assert_eq!(client.transfer_cooldown(), 3_600);
+    assert_eq!(client.locked_until(&hash), 4_600);
+}
+
+#[test]
+fn test_huge_cooldown_saturates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 209);
+    client.initialize(&Address::generate(&env));
+    client.set_transfer_cooldown(&u64::MAX);
+    client.register(&owner, &hash);
+    env.ledger().set_timestamp(1_000);
+
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+
+    assert_eq!(client.locked_until(&hash), u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4030)")]
+fn test_stellar_address_change_during_cooldown_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 62);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+
+    client.add_stellar_address(&new_owner, &hash, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4030)")]
+fn test_chain_address_change_during_cooldown_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 63);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+
+    client.add_chain_address(&new_owner, &hash, &ChainType::Evm, &evm_address(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4030)")]
+fn test_shielded_address_change_during_cooldown_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 64);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+
+    client.add_shielded_address(&new_owner, &hash, &commitment(&env, 65));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4030)")]
+fn test_transfer_during_cooldown_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 66);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+
+    client.propose_transfer(
+        &new_owner,
+        &hash,
+        &Address::generate(&env),
+        &10_000,
+        &RecordPolicy::Keep,
+    );
+}
+
+#[test]
+fn test_cooldown_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 67);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+    let payee = Address::generate(&env);
+
+    env.ledger().set_timestamp(4_600);
+    client.add_stellar_address(&new_owner, &hash, &payee);
+    client.transfer_ownership(
+        &new_owner,
+        &hash,
+        &Address::generate(&env),
+        &RecordPolicy::Keep,
+    );
+
+    assert_eq!(client.resolve_stellar(&hash), payee);
+    assert_eq!(client.locked_until(&hash), 8_200);
+}
+
+#[test]
+fn test_recovery_allowed_during_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 68);
+    let new_owner = transfer_under_cooldown(&env, &client, &hash);
+    let g1 = Address::generate(&env);
+    let g2 = Address::generate(&env);
+    client.set_guardians(
+        &new_owner,
+        &hash,
+        &Vec::from_array(&env, [g1.clone(), g2.clone()]),
+        &2,
+        &100,
+    );
+    let recovered_owner = Address::generate(&env);
+
+    client.recover(&g1, &hash, &recovered_owner);
+    client.recover(&g2, &hash, &recovered_owner);
+    env.ledger().set_timestamp(1_100);
+    client.execute_recovery(&hash);
+
+    assert_eq!(client.get_owner(&hash), Some(recovered_owner));
+    assert_eq!(client.locked_until(&hash), 4_700);
+}
+
+// ── multisig ownership tests ──────────────────────────────────────────────────
+
+fn grant_payments(delegate: &Address) -> MultisigAction {
+    MultisigAction::GrantDelegate(delegate.clone(), DelegateRole::Payments, None)
+}
+
+#[test]
+fn test_set_multisig_makes_group_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 70);
+
+    let (_, [s1, s2, s3]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    assert_eq!(client.get_owner(&hash), Some(contract_id));
+    assert_eq!(
+        client.get_multisig(&hash),
+        Some(MultisigConfig {
+            signers: Vec::from_array(&env, [s1, s2, s3]),
+            threshold: 2,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_former_owner_loses_control_after_multisig() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 71);
+    let (owner, [_, _, _]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    client.transfer_ownership(&owner, &hash, &Address::generate(&env), &RecordPolicy::Keep);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4031)")]
+fn test_set_multisig_duplicate_signer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let hash = commitment(&env, 72);
+    client.register(&owner, &hash);
+
+    client.set_multisig(
+        &owner,
+        &hash,
+        &Vec::from_array(&env, [signer.clone(), signer]),
+        &1,
+    );
+}
+
+#[test]
+fn test_execute_multisig_grants_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 73);
+    let (_, [s1, _, s3]) = setup_members(&env, &client, &hash, Members::Signers);
+    let delegate = Address::generate(&env);
+    let payee = Address::generate(&env);
+
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1.clone(), s3.clone()]),
+        &hash,
+        &grant_payments(&delegate),
+    );
+    let signed: Vec<Address> = Vec::from_iter(&env, env.auths().iter().map(|(a, _)| a.clone()));
+    assert!(signed.contains(&s1) && signed.contains(&s3));
+
+    client.add_stellar_address(&delegate, &hash, &payee);
+    assert_eq!(client.resolve_stellar(&hash), payee);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4032)")]
+fn test_execute_multisig_below_threshold_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 74);
+    let (_, [s1, _, _]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1]),
+        &hash,
+        &grant_payments(&Address::generate(&env)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_execute_multisig_non_signer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 75);
+    let (owner, [s1, _, _]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1, owner]),
+        &hash,
+        &grant_payments(&Address::generate(&env)),
+    );
+}
+
+#[test]
+fn test_multisig_proposal_executes_at_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 76);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let delegate = Address::generate(&env);
+    let action = grant_payments(&delegate);
+
+    client.propose_multisig(&s1, &hash, &action);
+    assert_eq!(
+        client.get_multisig_proposal(&hash),
+        Some(MultisigProposal {
+            action: action.clone(),
+            approvals: Vec::from_array(&env, [s1]),
+        })
+    );
+    assert_eq!(client.get_delegates(&hash).len(), 0);
+
+    client.approve_multisig(&s2, &hash, &action);
+
+    assert_eq!(client.get_multisig_proposal(&hash), None);
+    assert_eq!(client.get_delegates(&hash).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_approve_multisig_mismatched_action_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 77);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    client.propose_multisig(&s1, &hash, &grant_payments(&Address::generate(&env)));
+    client.approve_multisig(&s2, &hash, &grant_payments(&Address::generate(&env)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4021)")]
+fn test_approve_multisig_twice_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 78);
+    let (_, [s1, _, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let action = grant_payments(&Address::generate(&env));
+
+    client.propose_multisig(&s1, &hash, &action);
+    client.approve_multisig(&s1, &hash, &action);
+}
+
+#[test]
+fn test_multisig_set_signers_discards_open_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 79);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let s4 = Address::generate(&env);
+    client.propose_multisig(&s1, &hash, &grant_payments(&s4));
+
+    let signers = Vec::from_array(&env, [s2.clone(), s4]);
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1, s2]),
+        &hash,
+        &MultisigAction::SetSigners(signers.clone(), 1),
+    );
+
+    assert_eq!(client.get_multisig_proposal(&hash), None);
+    assert_eq!(
+        client.get_multisig(&hash),
+        Some(MultisigConfig {
+            signers,
+            threshold: 1
+        })
+    );
+}
+
+#[test]
+fn test_multisig_transfer_out_dissolves_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 57);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let recipient = Address::generate(&env);
+
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1, s2]),
+        &hash,
+        &MultisigAction::ProposeTransfer(recipient.clone(), 100, RecordPolicy::Keep),
+    );
+    client.accept_transfer(&recipient, &hash);
+
+    assert_eq!(client.get_owner(&hash), Some(recipient));
+    assert_eq!(client.get_multisig(&hash), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_direct_transfer_of_group_owned_name_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 229);
+    setup_members(&env, &client, &hash, Members::Signers);
+
+    client.transfer_ownership(
+        &contract_id,
+        &hash,
+        &Address::generate(&env),
+        &RecordPolicy::Keep,
+    );
+}
+
+#[test]
+fn test_multisig_manages_guardians_and_vetoes_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 230);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let signers = Vec::from_array(&env, [s1, s2]);
+    let guardian = Address::generate(&env);
+
+    client.execute_multisig(
+        &signers,
+        &hash,
+        &MultisigAction::SetGuardians(Vec::from_array(&env, [guardian.clone()]), 1, 100),
+    );
+    client.recover(&guardian, &hash, &Address::generate(&env));
+    assert!(client.get_recovery(&hash).is_some());
+
+    client.execute_multisig(&signers, &hash, &MultisigAction::CancelRecovery);
+    assert_eq!(client.get_recovery(&hash), None);
+}
+
+#[test]
+fn test_multisig_cancels_transfer_and_locks_name() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 231);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let signers = Vec::from_array(&env, [s1, s2]);
+
+    client.execute_multisig(
+        &signers,
+        &hash,
+        &MultisigAction::ProposeTransfer(Address::generate(&env), 100, RecordPolicy::Keep),
+    );
+    client.execute_multisig(&signers, &hash, &MultisigAction::CancelTransfer);
+    assert_eq!(client.get_pending_transfer(&hash), None);
+
+    client.execute_multisig(&signers, &hash, &MultisigAction::LockTransfers);
+    assert!(client.is_transfer_locked(&hash));
+}
+
+#[test]
+fn test_multisig_publishes_username() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+    let signer = Address::generate(&env);
+    let signers = Vec::from_array(&env, [signer]);
+    client.set_multisig(&owner, &hash, &signers, &1);
+    let name = String::from_str(&env, "alice");
+
+    client.execute_multisig(
+        &signers,
+        &hash,
+        &MultisigAction::PublishUsername(name.clone()),
+    );
+    assert_eq!(client.get_username(&hash), Some(name));
+
+    client.execute_multisig(&signers, &hash, &MultisigAction::UnpublishUsername);
+    assert_eq!(client.get_username(&hash), None);
+}
+
+#[test]
+fn test_multisig_burn_removes_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 232);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    let signers = Vec::from_array(&env, [s1.clone(), s2]);
+    client.propose_multisig(&s1, &hash, &MultisigAction::LockTransfers);
+
+    client.execute_multisig(&signers, &hash, &MultisigAction::Burn);
+
+    assert_eq!(client.get_owner(&hash), None);
+    assert_eq!(client.get_multisig(&hash), None);
+    assert_eq!(client.get_multisig_proposal(&hash), None);
+}
+
+// ── change history tests ──────────────────────────────────────────────────────
+
+#[test]
+fn test_history_records_each_resolution_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let payee = Address::generate(&env);
+    let hash = commitment(&env, 120);
+
+    env.ledger().set_timestamp(100);
+    client.register(&owner, &hash);
+    env.ledger().set_timestamp(200);
+    client.add_stellar_address(&owner, &hash, &payee);
+    env.ledger().set_timestamp(300);
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
+
+    let history = client.history(&hash, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.get(2).expect("history entry missing"),
+        HistoryEntry {
+            timestamp: 300,
+            changed: HistoryField::PrivacyMode,
+            owner: Some(owner.clone()),
+            stellar_address: Some(payee),
+            memo: None,
+            privacy_mode: PrivacyMode::Shielded,
+        }
+    );
+    assert_eq!(
+        history.get(0).expect("history entry missing").changed,
+        HistoryField::Owner
+    );
+    assert_eq!(
+        history.get(1).expect("history entry missing").changed,
+        HistoryField::StellarAddress
+    );
+    assert_eq!(client.history(&hash, &1, &1).get(0), history.get(1));
+}
+
+#[test]
+fn test_resolve_at_returns_past_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let hash = commitment(&env, 121);
+
+    env.ledger().set_timestamp(100);
+    client.register(&owner, &hash);
+    env.ledger().set_timestamp(200);
+    client.add_stellar_address(&owner, &hash, &first);
+    env.ledger().set_timestamp(300);
+    client.add_stellar_address(&owner, &hash, &second);
+
+    assert_eq!(client.resolve_at(&hash, &50), None);
+    assert_eq!(
+        client
+            .resolve_at(&hash, &150)
+            .expect("no state at timestamp")
+            .stellar_address,
+        None
+    );
+    assert_eq!(
+        client
+            .resolve_at(&hash, &299)
+            .expect("no state at timestamp")
+            .stellar_address,
+        Some(first)
+    );
+    assert_eq!(
+        client
+            .resolve_at(&hash, &300)
+            .expect("no state at timestamp")
+            .stellar_address,
+        Some(second)
+    );
+}
+
+#[test]
+fn test_history_tracks_memo_on_resolver() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let wallet = Address::generate(&env);
+    let hash = commitment(&env, 122);
+
+    env.ledger().set_timestamp(100);
+    client.register_resolver(
+        &wallet,
+        &hash,
+        &dummy_proof(&env),
+        &signals(&hash, root, BytesN::from_array(&env, &[123u8; 32])),
+    );
+    env.ledger().set_timestamp(200);
+    client.set_memo(&wallet, &hash, &77);
+
+    let before = client
+        .resolve_at(&hash, &199)
+        .expect("no state at timestamp");
+    let after = client
+        .resolve_at(&hash, &200)
+        .expect("no state at timestamp");
+    assert_eq!((before.owner, before.memo), (Some(wallet.clone()), None));
+    assert_eq!((after.changed, after.memo), (HistoryField::Memo, Some(77)));
+}
+
+#[test]
+fn test_history_records_transfer_reset_and_burn_erases_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let hash = commitment(&env, 124);
+    client.register(&owner, &hash);
+    client.add_stellar_address(&owner, &hash, &Address::generate(&env));
+
+    env.ledger().set_timestamp(500);
+    client.transfer_ownership(&owner, &hash, &new_owner, &RecordPolicy::Clear);
+    let after_transfer = client
+        .resolve_at(&hash, &500)
+        .expect("no state at timestamp");
+    assert_eq!(after_transfer.owner, Some(new_owner.clone()));
+    assert_eq!(after_transfer.stellar_address, None);
+
+    env.ledger().set_timestamp(600);
+    client.burn(&new_owner, &hash);
+
+    // A burned name leaves no resolution trail behind.
+    assert_eq!(client.resolve_at(&hash, &599), None);
+    assert_eq!(client.history(&hash, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 125);
+    env.ledger().set_timestamp(1);
+    client.register(&owner, &hash);
+
+    for i in 0..50u64 {
+        env.ledger().set_timestamp(10 + i);
+        let mode = if i % 2 == 0 {
+            PrivacyMode::Shielded
+        } else {
+            PrivacyMode::Normal
+        };
+        client.set_privacy_mode(&owner, &hash, &mode);
+    }
+
+    let history = client.history(&hash, &0, &100);
+    assert_eq!(history.len(), 50);
+    assert_eq!(history.get(0).expect("history entry missing").timestamp, 10);
+    assert_eq!(client.resolve_at(&hash, &5), None);
+}
+
+// ── stealth address tests ─────────────────────────────────────────────────────
+
+fn stealth_meta(env: &Env) -> StealthMetaAddress {
+    let mut spending = [0x11u8; 33];
+    spending[0] = 0x02;
+    let mut viewing = [0x22u8; 33];
+    viewing[0] = 0x03;
+    StealthMetaAddress {
+        scheme_id: 1,
+        spending_pubkey: Bytes::from_array(env, &spending),
+        viewing_pubkey: Bytes::from_array(env, &viewing),
+    }
+}
+
+fn ephemeral_key(env: &Env) -> Bytes {
+    let mut key = [0x33u8; 33];
+    key[0] = 0x02;
+    Bytes::from_array(env, &key)
+}
+
+#[test]
+fn test_set_and_remove_stealth_meta_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 94);
+    client.register(&owner, &hash);
+
+    assert_eq!(client.get_stealth_meta_address(&hash), None);
+    client.set_stealth_meta_address(&owner, &hash, &stealth_meta(&env));
+    assert_eq!(
+        client.get_stealth_meta_address(&hash),
+        Some(stealth_meta(&env))
+    );
+
+    client.remove_stealth_meta_address(&owner, &hash);
+    assert_eq!(client.get_stealth_meta_address(&hash), None);
+}
+
+#[test]
+fn test_payments_delegate_sets_stealth_meta_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 95);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Payments, &None);
+
+    client.set_stealth_meta_address(&delegate, &hash, &stealth_meta(&env));
+    assert_eq!(
+        client.get_stealth_meta_address(&hash),
+        Some(stealth_meta(&env))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_stealth_meta_address_by_stranger_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 96);
+    client.register(&owner, &hash);
+
+    client.set_stealth_meta_address(&Address::generate(&env), &hash, &stealth_meta(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4033)")]
+fn test_set_stealth_meta_address_rejects_uncompressed_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 97);
+    client.register(&owner, &hash);
+
+    let mut meta = stealth_meta(&env);
+    meta.viewing_pubkey = Bytes::from_array(&env, &[0x04u8; 65]);
+    client.set_stealth_meta_address(&owner, &hash, &meta);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4033)")]
+fn test_set_stealth_meta_address_rejects_unknown_scheme() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 98);
+    client.register(&owner, &hash);
+
+    let mut meta = stealth_meta(&env);
+    meta.scheme_id = 2;
+    client.set_stealth_meta_address(&owner, &hash, &meta);
+}
+
+#[test]
+fn test_clear_policy_transfer_removes_stealth_meta_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 99);
+    client.register(&owner, &hash);
+    client.set_stealth_meta_address(&owner, &hash, &stealth_meta(&env));
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Clear);
+    assert_eq!(client.get_stealth_meta_address(&hash), None);
+}
+
+#[test]
+fn test_announce_emits_filterable_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let sender = Address::generate(&env);
+    let stealth_address = Bytes::from_array(&env, &[0x44u8; 20]);
+    let metadata = Bytes::from_array(&env, &[0xAB]);
+
+    client.announce(
+        &sender,
+        &1,
+        &stealth_address,
+        &ephemeral_key(&env),
+        &0xC7,
+        &metadata,
+    );
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().expect("No events emitted");
+    let name: Symbol = topics.get(0).expect("topic missing").into_val(&env);
+    let scheme_id: u32 = topics.get(1).expect("topic missing").into_val(&env);
+    let view_tag: u32 = topics.get(2).expect("topic missing").into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "ANNOUNCE"));
+    assert_eq!(scheme_id, 1);
+    assert_eq!(view_tag, 0xC7);
+    let data: (Bytes, Address, Bytes, Bytes) = data.into_val(&env);
+    assert_eq!(
+        data,
+        (stealth_address, sender, ephemeral_key(&env), metadata)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4034)")]
+fn test_announce_rejects_out_of_range_view_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    client.announce(
+        &Address::generate(&env),
+        &1,
+        &Bytes::from_array(&env, &[0x44u8; 20]),
+        &ephemeral_key(&env),
+        &256,
+        &Bytes::new(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4034)")]
+fn test_announce_rejects_oversized_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+
+    client.announce(
+        &Address::generate(&env),
+        &1,
+        &Bytes::from_array(&env, &[0x44u8; 20]),
+        &ephemeral_key(&env),
+        &0,
+        &Bytes::from_slice(&env, &[0u8; 257]),
+    );
+}
+
+// ── encrypted record tests ────────────────────────────────────────────────────
+
+fn record_grant(env: &Env, viewer: u8, envelope: u8) -> RecordGrant {
+    RecordGrant {
+        viewer: BytesN::from_array(env, &[viewer; 32]),
+        envelope: Bytes::from_array(env, &[envelope; 80]),
+    }
+}
+
+#[test]
+fn test_set_encrypted_record_bumps_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 101);
+    client.register(&owner, &hash);
+
+    let ciphertext = Bytes::from_array(&env, &[0xCC; 64]);
+    let grants = Vec::from_array(&env, [record_grant(&env, 1, 0xE1)]);
+    client.set_encrypted_record(&owner, &hash, &ciphertext, &grants);
+    assert_eq!(
+        client.get_encrypted_record(&hash),
+        Some(EncryptedRecord {
+            ciphertext: ciphertext.clone(),
+            version: 1,
+            grants,
+        })
+    );
+
+    client.set_encrypted_record(&owner, &hash, &ciphertext, &Vec::new(&env));
+    let record = client
+        .get_encrypted_record(&hash)
+        .expect("encrypted record missing");
+    assert_eq!(record.version, 2);
+    assert!(record.grants.is_empty());
+}
+
+#[test]
+fn test_grant_and_revoke_record_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 102);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Payments, &None);
+    client.set_encrypted_record(
+        &delegate,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::from_array(&env, [record_grant(&env, 1, 0xE1)]),
+    );
+
+    client.grant_record_access(&delegate, &hash, &record_grant(&env, 2, 0xE2));
+    client.grant_record_access(&owner, &hash, &record_grant(&env, 1, 0xE3));
+    let grants = client
+        .get_encrypted_record(&hash)
+        .expect("encrypted record missing")
+        .grants;
+    assert_eq!(
+        grants,
+        Vec::from_array(
+            &env,
+            [record_grant(&env, 1, 0xE3), record_grant(&env, 2, 0xE2)]
+        )
+    );
+
+    client.revoke_record_access(&owner, &hash, &BytesN::from_array(&env, &[1; 32]));
+    let record = client
+        .get_encrypted_record(&hash)
+        .expect("encrypted record missing");
+    assert_eq!(
+        record.grants,
+        Vec::from_array(&env, [record_grant(&env, 2, 0xE2)])
+    );
+    assert_eq!(record.version, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_revoke_unknown_viewer_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 103);
+    client.register(&owner, &hash);
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::new(&env),
+    );
+
+    client.revoke_record_access(&owner, &hash, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_grant_without_record_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 104);
+    client.register(&owner, &hash);
+
+    client.grant_record_access(&owner, &hash, &record_grant(&env, 1, 0xE1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4035)")]
+fn test_set_encrypted_record_rejects_duplicate_viewer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 105);
+    client.register(&owner, &hash);
+
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::from_array(
+            &env,
+            [record_grant(&env, 1, 0xE1), record_grant(&env, 1, 0xE2)],
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4035)")]
+fn test_set_encrypted_record_rejects_oversized_ciphertext() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 106);
+    client.register(&owner, &hash);
+
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_slice(&env, &[0xCC; 1025]),
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_encrypted_record_by_records_delegate_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 107);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Records, &None);
+
+    client.set_encrypted_record(
+        &delegate,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+fn test_transfer_clear_policy_removes_encrypted_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let hash = commitment(&env, 108);
+    client.register(&owner, &hash);
+    client.set_encrypted_record(
+        &owner,
+        &hash,
+        &Bytes::from_array(&env, &[0xCC; 64]),
+        &Vec::from_array(&env, [record_grant(&env, 1, 0xE1)]),
+    );
+
+    client.transfer_ownership(&owner, &hash, &buyer, &RecordPolicy::Clear);
+    assert_eq!(client.get_encrypted_record(&hash), None);
+}
+
+// ── privacy policy tests ──────────────────────────────────────────────────────
+
+/// Registers `hash` with a resolver wallet, memo, Stellar, EVM, Bitcoin and shielded
+/// addresses, returning the owner.
+fn setup_private_records(
+    env: &Env,
+    client: &ContractClient,
+    root: BytesN<32>,
+    hash: &BytesN<32>,
+) -> Address {
+    let owner = Address::generate(env);
+    client.register(&owner, hash);
+    client.register_resolver(
+        &owner,
+        hash,
+        &dummy_proof(env),
+        &signals(hash, root, BytesN::from_array(env, &[0xA0; 32])),
+    );
+    client.set_memo(&owner, hash, &42);
+    client.add_stellar_address(&owner, hash, &Address::generate(env));
+    client.add_chain_address(&owner, hash, &ChainType::Evm, &evm_address(env));
+    client.add_chain_address(
+        &owner,
+        hash,
+        &ChainType::Bitcoin,
+        &Bytes::from_slice(env, b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+    );
+    client.add_shielded_address(&owner, hash, &BytesN::from_array(env, &[0xAA; 32]));
+    owner
+}
+
+fn uniform_policy(env: &Env, visibility: Visibility) -> PrivacyPolicy {
+    let mut chains = Map::new(env);
+    for chain in [
+        ChainType::Evm,
+        ChainType::Bitcoin,
+        ChainType::Solana,
+        ChainType::Cosmos,
+    ] {
+        chains.set(chain, visibility);
+    }
+    PrivacyPolicy {
+        primary: visibility,
+        stellar: visibility,
+        chains,
+        memo: visibility,
+        shielded: visibility,
+        text: visibility,
+    }
+}
+
+#[test]
+fn test_privacy_policy_defaults_to_public() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 109);
+    let owner = setup_private_records(&env, &client, root, &hash);
+
+    let policy = client.get_privacy_policy(&hash);
+    assert_eq!(policy.primary, Visibility::Public);
+    assert!(policy.chains.is_empty());
+    assert_eq!(client.resolve(&hash), (owner, Some(42)));
+    assert_eq!(client.get_stellar_addresses(&hash).len(), 1);
+    assert!(client.is_shielded(&hash));
+}
+
+#[test]
+fn test_hidden_fields_do_not_leak_through_side_getters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 126);
+    let owner = setup_private_records(&env, &client, root, &hash);
+
+    client.set_privacy_policy(&owner, &hash, &uniform_policy(&env, Visibility::Hidden));
+
+    assert_eq!(client.resolve(&hash), (contract_id.clone(), None));
+    assert_eq!(client.resolve_stellar(&hash), contract_id);
+    assert!(client.get_stellar_addresses(&hash).is_empty());
+    assert!(client.list_stellar_addresses(&hash, &0, &10).is_empty());
+    for chain in [ChainType::Evm, ChainType::Bitcoin] {
+        assert_eq!(client.get_chain_address(&hash, &chain), None);
+        assert_eq!(client.get_verified_chain_address(&hash, &chain), None);
+        assert!(client
+            .list_chain_addresses(&hash, &chain, &0, &10)
+            .is_empty());
+    }
+    assert_eq!(client.get_shielded_address(&hash), None);
+    assert!(!client.is_shielded(&hash));
+    for entry in client.history(&hash, &0, &50).iter() {
+        assert_eq!((entry.stellar_address, entry.memo), (None, None));
+    }
+    let latest = client
+        .resolve_at(&hash, &u64::MAX)
+        .expect("no state at timestamp");
+    assert_eq!((latest.stellar_address, latest.memo), (None, None));
+}
+
+#[test]
+fn test_privacy_policy_applies_per_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 127);
+    let owner = setup_private_records(&env, &client, root, &hash);
+
+    let mut policy = uniform_policy(&env, Visibility::Public);
+    policy.chains = Map::from_array(&env, [(ChainType::Evm, Visibility::DelegatesOnly)]);
+    client.set_privacy_policy(&owner, &hash, &policy);
+
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert!(client
+        .list_chain_addresses(&hash, &ChainType::Evm, &0, &10)
+        .is_empty());
+    assert!(client
+        .get_chain_address(&hash, &ChainType::Bitcoin)
+        .is_some());
+}
+
+#[test]
+fn test_resolve_records_respects_reader() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 128);
+    let owner = setup_private_records(&env, &client, root, &hash);
+    let delegate = Address::generate(&env);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Records, &None);
+
+    let mut policy = uniform_policy(&env, Visibility::DelegatesOnly);
+    policy.primary = Visibility::Hidden;
+    policy.memo = Visibility::Public;
+    client.set_privacy_policy(&owner, &hash, &policy);
+
+    let full = client.resolve_records(&owner, &hash);
+    assert_eq!(full.wallet, Some(owner.clone()));
+    assert_eq!(full.memo, Some(42));
+    assert_eq!(full.stellar_addresses.len(), 1);
+    assert_eq!(full.chain_addresses.len(), 2);
+    assert!(full.shielded_address.is_some());
+
+    assert_eq!(
+        client.resolve_records(&delegate, &hash),
+        RecordView {
+            wallet: None,
+            ..full.clone()
+        }
+    );
+    assert_eq!(
+        client.resolve_records(&Address::generate(&env), &hash),
+        RecordView {
+            wallet: None,
+            memo: Some(42),
+            stellar_addresses: Vec::new(&env),
+            chain_addresses: Map::new(&env),
+            shielded_address: None,
+        }
+    );
+}
+
+#[test]
+fn test_shielded_mode_hides_primary_from_other_readers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 129);
+    let owner = setup_private_records(&env, &client, root, &hash);
+
+    client.set_privacy_mode(&owner, &hash, &PrivacyMode::Shielded);
+    assert_eq!(
+        client.resolve_records(&owner, &hash).wallet,
+        Some(owner.clone())
+    );
+    assert_eq!(
+        client
+            .resolve_records(&Address::generate(&env), &hash)
+            .wallet,
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_privacy_policy_by_payments_delegate_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 247);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Payments, &None);
+
+    client.set_privacy_policy(&delegate, &hash, &uniform_policy(&env, Visibility::Hidden));
+}
+
+// ── shielded rotation tests ──────────────────────────────────────────────────
+
+#[test]
+fn test_rotate_shielded_address_keeps_validity_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 248);
+    let first = BytesN::from_array(&env, &[0x11; 32]);
+    let second = BytesN::from_array(&env, &[0x22; 32]);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(1_000);
+    client.add_shielded_address(&owner, &hash, &first);
+    env.ledger().set_timestamp(2_000);
+    client.rotate_shielded_address(&owner, &hash, &second);
+
+    assert_eq!(client.get_shielded_address(&hash), Some(second.clone()));
+    assert_eq!(
+        client.shielded_address_history(&hash),
+        Vec::from_array(
+            &env,
+            [
+                ShieldedRecord {
+                    address_commitment: first.clone(),
+                    valid_from: 1_000,
+                    valid_until: Some(2_000),
+                },
+                ShieldedRecord {
+                    address_commitment: second.clone(),
+                    valid_from: 2_000,
+                    valid_until: None,
+                },
+            ]
+        )
+    );
+    assert!(client.was_shielded_address_valid(&hash, &first, &1_500));
+    assert!(!client.was_shielded_address_valid(&hash, &first, &2_000));
+    assert!(client.was_shielded_address_valid(&hash, &second, &2_000));
+    assert!(!client.was_shielded_address_valid(&hash, &second, &999));
+}
+
+#[test]
+fn test_rotate_shielded_address_drops_oldest_beyond_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 249);
+    client.register(&owner, &hash);
+
+    client.add_shielded_address(&owner, &hash, &BytesN::from_array(&env, &[0; 32]));
+    for i in 1..=10u8 {
+        env.ledger().set_timestamp(u64::from(i) * 100);
+        client.rotate_shielded_address(&owner, &hash, &BytesN::from_array(&env, &[i; 32]));
+    }
+
+    let history = client.shielded_address_history(&hash);
+    assert_eq!(history.len(), 10);
+    assert_eq!(
+        history.first().map(|r| r.address_commitment),
+        Some(BytesN::from_array(&env, &[1; 32]))
+    );
+    assert!(!client.was_shielded_address_valid(&hash, &BytesN::from_array(&env, &[0; 32]), &0));
+}
+
+#[test]
+fn test_remove_shielded_address_closes_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 25);
+    let shielded = BytesN::from_array(&env, &[0x33; 32]);
+    client.register(&owner, &hash);
+
+    env.ledger().set_timestamp(1_000);
+    client.add_shielded_address(&owner, &hash, &shielded);
+    env.ledger().set_timestamp(3_000);
+    client.remove_shielded_address(&owner, &hash);
+
+    assert_eq!(client.get_shielded_address(&hash), None);
+    assert!(!client.is_shielded(&hash));
+    assert!(client.was_shielded_address_valid(&hash, &shielded, &2_999));
+    assert!(!client.was_shielded_address_valid(&hash, &shielded, &3_000));
+}
+
+#[test]
+fn test_rotate_shielded_address_by_payments_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 26);
+    let next = BytesN::from_array(&env, &[0x45; 32]);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Payments, &None);
+    client.add_shielded_address(&owner, &hash, &BytesN::from_array(&env, &[0x44; 32]));
+
+    client.rotate_shielded_address(&delegate, &hash, &next);
+    assert_eq!(client.get_shielded_address(&hash), Some(next));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_rotate_shielded_address_without_current_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 27);
+    client.register(&owner, &hash);
+
+    client.rotate_shielded_address(&owner, &hash, &BytesN::from_array(&env, &[0x55; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4012)")]
+fn test_rotate_shielded_address_to_same_commitment_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 28);
+    let shielded = BytesN::from_array(&env, &[0x66; 32]);
+    client.register(&owner, &hash);
+    client.add_shielded_address(&owner, &hash, &shielded);
+
+    client.rotate_shielded_address(&owner, &hash, &shielded);
+}
+
+#[test]
+fn test_clear_policy_erases_shielded_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 217);
+    let first = BytesN::from_array(&env, &[0x11; 32]);
+    client.register(&owner, &hash);
+    env.ledger().set_timestamp(1_000);
+    client.add_shielded_address(&owner, &hash, &first);
+    env.ledger().set_timestamp(2_000);
+    client.rotate_shielded_address(&owner, &hash, &BytesN::from_array(&env, &[0x22; 32]));
+
+    client.transfer_ownership(
+        &owner,
+        &hash,
+        &Address::generate(&env),
+        &RecordPolicy::Clear,
+    );
+
+    assert_eq!(client.shielded_address_history(&hash).len(), 0);
+    assert!(!client.was_shielded_address_valid(&hash, &first, &1_500));
+}
+
+#[test]
+fn test_hidden_shielded_policy_hides_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hash = commitment(&env, 29);
+    let shielded = BytesN::from_array(&env, &[0x77; 32]);
+    client.register(&owner, &hash);
+    client.add_shielded_address(&owner, &hash, &shielded);
+
+    let mut policy = client.get_privacy_policy(&hash);
+    policy.shielded = Visibility::Hidden;
+    client.set_privacy_policy(&owner, &hash, &policy);
+
+    assert_eq!(client.shielded_address_history(&hash).len(), 0);
+    assert!(!client.was_shielded_address_valid(&hash, &shielded, &0));
+}
+
+// ── username publishing tests ────────────────────────────────────────────────
+
+/// Commitment of "alice" from `zk/witnesses/username_hash`.
+const ALICE_COMMITMENT: [u8; 32] = [
+    0x1d, 0x7f, 0x90, 0x6c, 0x20, 0x65, 0x5b, 0x0c, 0x69, 0x15, 0xce, 0x19, 0x35, 0x33, 0x33, 0x52,
+    0xa7, 0x6f, 0x04, 0xdb, 0xda, 0x9d, 0x07, 0x05, 0x48, 0x70, 0x1e, 0x9d, 0xef, 0xdc, 0xe9, 0x9c,
+];
+
+/// Registers the "alice" commitment to a fresh owner.
+fn register_alice(env: &Env, client: &ContractClient) -> (Address, BytesN<32>) {
+    let owner = Address::generate(env);
+    let hash = BytesN::from_array(env, &ALICE_COMMITMENT);
+    client.register(&owner, &hash);
+    (owner, hash)
+}
+
+#[test]
+fn test_poseidon_matches_circomlib_vectors() {
+    let fr = Fr::from_u64;
+    assert_eq!(
+        poseidon::hash2([fr(1), fr(2)]).to_bytes_be(),
+        [
+            0x11, 0x5c, 0xc0, 0xf5, 0xe7, 0xd6, 0x90, 0x41, 0x3d, 0xf6, 0x4c, 0x6b, 0x96, 0x62,
+            0xe9, 0xcf, 0x2a, 0x36, 0x17, 0xf2, 0x74, 0x32, 0x45, 0x51, 0x9e, 0x19, 0x60, 0x7a,
+            0x44, 0x17, 0x18, 0x9a,
+        ]
+    );
+    assert_eq!(
+        poseidon::hash4([fr(1), fr(2), fr(3), fr(4)]).to_bytes_be(),
+        [
+            0x29, 0x9c, 0x86, 0x7d, 0xb6, 0xc1, 0xfd, 0xd7, 0x9d, 0xce, 0xfa, 0x40, 0xe4, 0x51,
+            0x0b, 0x98, 0x37, 0xe6, 0x0e, 0xbb, 0x1c, 0xe0, 0x66, 0x3d, 0xba, 0xa5, 0x25, 0xdf,
+            0x65, 0x25, 0x04, 0x65,
+        ]
+    );
+}
+
+#[test]
+fn test_publish_username_stores_verified_name() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+    assert_eq!(client.get_username(&hash), None);
+
+    let name = String::from_str(&env, "alice");
+    client.publish_username(&owner, &hash, &name);
+
+    assert_eq!(client.get_username(&hash), Some(name));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4036)")]
+fn test_publish_username_with_wrong_preimage_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+
+    client.publish_username(&owner, &hash, &String::from_str(&env, "alicf"));
+}
+
+#[test]
+fn test_publish_username_rejects_malformed_names() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+
+    for name in ["", "alice\0", "alicé", "a123456789012345678901234567890123"] {
+        assert_eq!(
+            client.try_publish_username(&owner, &hash, &String::from_str(&env, name)),
+            Err(Ok(Error::from_contract_error(4036)))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_publish_username_by_non_owner_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    register_alice(&env, &client);
+
+    client.publish_username(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &ALICE_COMMITMENT),
+        &String::from_str(&env, "alice"),
+    );
+}
+
+#[test]
+fn test_unpublish_username_clears_name() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+    client.publish_username(&owner, &hash, &String::from_str(&env, "alice"));
+
+    client.unpublish_username(&owner, &hash);
+
+    assert_eq!(client.get_username(&hash), None);
+    assert_eq!(
+        client.try_unpublish_username(&owner, &hash),
+        Err(Ok(Error::from_contract_error(4001)))
+    );
+}
+
+#[test]
+fn test_burn_removes_published_username() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let (owner, hash) = register_alice(&env, &client);
+    client.publish_username(&owner, &hash, &String::from_str(&env, "alice"));
+
+    client.burn(&owner, &hash);
+
+    assert_eq!(client.get_username(&hash), None);
+}
+
+// ── custom resolver tests ────────────────────────────────────────────────────
+
+/// A custom resolver answering from values the test stores in its instance storage.
+#[contract]
+struct StoredResolver;
+
+#[contractimpl]
+impl CustomResolverInterface for StoredResolver {
+    fn resolve(env: Env, _commitment: BytesN<32>) -> Option<(Address, Option<u64>)> {
+        env.storage().instance().get(&symbol_short!("resolve"))
+    }
+
+    fn resolve_chain(env: Env, _commitment: BytesN<32>, chain: ChainType) -> Option<Bytes> {
+        env.storage().instance().get(&chain)
+    }
+
+    fn text(env: Env, _commitment: BytesN<32>, key: String) -> Option<String> {
+        env.storage().instance().get(&key)
+    }
+}
+
+/// A custom resolver whose every call fails.
+#[contract]
+struct FailingResolver;
+
+#[contractimpl]
+impl CustomResolverInterface for FailingResolver {
+    fn resolve(_env: Env, _commitment: BytesN<32>) -> Option<(Address, Option<u64>)> {
+        panic!("resolver down")
+    }
+
+    fn resolve_chain(_env: Env, _commitment: BytesN<32>, _chain: ChainType) -> Option<Bytes> {
+        panic!("resolver down")
+    }
+
+    fn text(_env: Env, _commitment: BytesN<32>, _key: String) -> Option<String> {
+        panic!("resolver down")
+    }
+}
+
+/// Registers a `StoredResolver` answering `resolve` with `deposit` and memo 7, EVM with
+/// a fixed address and `url` with a fixed text record.
+fn stored_resolver(env: &Env, deposit: &Address) -> Address {
+    let id = env.register(StoredResolver, ());
+    env.as_contract(&id, || {
+        let storage = env.storage().instance();
+        storage.set(&symbol_short!("resolve"), &(deposit.clone(), Some(7u64)));
+        storage.set(&ChainType::Evm, &second_evm_address(env));
+        storage.set(
+            &String::from_str(env, "url"),
+            &String::from_str(env, "https://pay.example"),
+        );
+    });
+    id
+}
+
+#[test]
+fn test_custom_resolver_answers_before_built_in_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 36);
+    let owner = setup_private_records(&env, &client, root, &hash);
+    let deposit = Address::generate(&env);
+    let resolver = stored_resolver(&env, &deposit);
+
+    client.set_custom_resolver(&owner, &hash, &resolver);
+
+    assert_eq!(client.get_custom_resolver(&hash), Some(resolver));
+    assert_eq!(client.resolve(&hash), (deposit.clone(), Some(7)));
+    assert_eq!(client.resolve_stellar(&hash), deposit);
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(second_evm_address(&env))
+    );
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Bitcoin),
+        Some(Bytes::from_slice(
+            &env,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        ))
+    );
+    assert_eq!(
+        client.get_verified_chain_address(&hash, &ChainType::Evm),
+        None
+    );
+    assert_eq!(
+        client.text(&hash, &String::from_str(&env, "url")),
+        Some(String::from_str(&env, "https://pay.example"))
+    );
+    assert_eq!(client.text(&hash, &String::from_str(&env, "avatar")), None);
+}
+
+#[test]
+fn test_failing_custom_resolver_falls_back_to_built_in_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 37);
+    let owner = setup_private_records(&env, &client, root, &hash);
+    let resolver = env.register(FailingResolver, ());
+
+    client.set_custom_resolver(&owner, &hash, &resolver);
+
+    assert_eq!(client.resolve(&hash), (owner, Some(42)));
+    assert_eq!(
+        client.get_chain_address(&hash, &ChainType::Evm),
+        Some(evm_address(&env))
+    );
+    assert_eq!(client.text(&hash, &String::from_str(&env, "url")), None);
+}
+
+#[test]
+fn test_custom_resolver_results_follow_privacy_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 38);
+    let owner = setup_private_records(&env, &client, root, &hash);
+    client.set_custom_resolver(
+        &owner,
+        &hash,
+        &stored_resolver(&env, &Address::generate(&env)),
+    );
+
+    client.set_privacy_policy(&owner, &hash, &uniform_policy(&env, Visibility::Hidden));
+
+    assert_eq!(client.resolve(&hash), (contract_id.clone(), None));
+    assert_eq!(client.resolve_stellar(&hash), contract_id);
+    assert_eq!(client.get_chain_address(&hash, &ChainType::Evm), None);
+    assert_eq!(client.text(&hash, &String::from_str(&env, "url")), None);
+}
+
+#[test]
+fn test_remove_custom_resolver_restores_built_in_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 45);
+    let owner = setup_private_records(&env, &client, root, &hash);
+    client.set_custom_resolver(
+        &owner,
+        &hash,
+        &stored_resolver(&env, &Address::generate(&env)),
+    );
+
+    client.remove_custom_resolver(&owner, &hash);
+
+    assert_eq!(client.get_custom_resolver(&hash), None);
+    assert_eq!(client.resolve(&hash), (owner.clone(), Some(42)));
+    assert_eq!(
+        client.try_remove_custom_resolver(&owner, &hash),
+        Err(Ok(Error::from_contract_error(4001)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_custom_resolver_by_records_delegate_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let hash = commitment(&env, 46);
+    client.register(&owner, &hash);
+    client.grant_delegate(&owner, &hash, &delegate, &DelegateRole::Records, &None);
+
+    client.set_custom_resolver(&delegate, &hash, &env.register(FailingResolver, ()));
+}
+
+// ── signed record tests ──────────────────────────────────────────────────────
+
+/// Returns the 32-byte contract id of a contract address.
+fn contract_id_bytes(env: &Env, contract: &Address) -> [u8; 32] {
+    use soroban_sdk::xdr::ToXdr;
+
+    let mut id = [0u8; 32];
+    contract.to_xdr(env).slice(8..40).copy_into_slice(&mut id);
+    id
+}
+
+/// Registers `hash` and declares a gateway signing for `contract_id`.
+fn setup_gateway(
+    env: &Env,
+    client: &ContractClient,
+    contract_id: &Address,
+    hash: &BytesN<32>,
+) -> (Address, gateway::Gateway) {
+    let owner = Address::generate(env);
+    client.register(&owner, hash);
+    let gateway = gateway::Gateway::new(&[0x5A; 32], contract_id_bytes(env, contract_id));
+    client.set_gateway_signer(&owner, hash, &BytesN::from_array(env, &gateway.signer()));
+    (owner, gateway)
+}
+
+#[test]
+fn test_resolve_with_signed_record_returns_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 47);
+    let (_, gateway) = setup_gateway(&env, &client, &contract_id, &hash);
+    env.ledger().set_timestamp(1_000);
+
+    let signed = gateway.sign(
+        &hash.to_array(),
+        b"stellar:GDQP2KPQGKIHYJGXNUIYOMHARUARCA7DJT5FO2FFOOKY3B2WSQHG4W37",
+        1_000,
+    );
+    let record = Bytes::from_slice(&env, &signed.record);
+
+    assert_eq!(
+        client.resolve_with_signed_record(
+            &hash,
+            &record,
+            &BytesN::from_array(&env, &signed.signature),
+            &signed.expiry,
+        ),
+        record
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4037)")]
+fn test_resolve_with_expired_signed_record_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 48);
+    let (_, gateway) = setup_gateway(&env, &client, &contract_id, &hash);
+    let signed = gateway.sign(&hash.to_array(), b"record", 1_000);
+    env.ledger().set_timestamp(1_001);
+
+    client.resolve_with_signed_record(
+        &hash,
+        &Bytes::from_slice(&env, &signed.record),
+        &BytesN::from_array(&env, &signed.signature),
+        &signed.expiry,
+    );
+}
+
+#[test]
+fn test_resolve_with_forged_signed_record_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 49);
+    let (_, gateway) = setup_gateway(&env, &client, &contract_id, &hash);
+    let signed = gateway.sign(&hash.to_array(), b"record", 1_000);
+    let signature = BytesN::from_array(&env, &signed.signature);
+
+    let tampered = Bytes::from_slice(&env, b"recorD");
+    assert!(client
+        .try_resolve_with_signed_record(&hash, &tampered, &signature, &signed.expiry)
+        .is_err());
+    let record = Bytes::from_slice(&env, &signed.record);
+    assert!(client
+        .try_resolve_with_signed_record(&hash, &record, &signature, &2_000)
+        .is_err());
+
+    let other = commitment(&env, 50);
+    setup_gateway(&env, &client, &contract_id, &other);
+    assert!(client
+        .try_resolve_with_signed_record(&other, &record, &signature, &signed.expiry)
+        .is_err());
+}
+
+#[test]
+fn test_remove_gateway_signer_invalidates_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 51);
+    let (owner, gateway) = setup_gateway(&env, &client, &contract_id, &hash);
+    let signed = gateway.sign(&hash.to_array(), b"record", 1_000);
+
+    client.remove_gateway_signer(&owner, &hash);
+
+    assert_eq!(client.get_gateway_signer(&hash), None);
+    assert_eq!(
+        client.try_resolve_with_signed_record(
+            &hash,
+            &Bytes::from_slice(&env, &signed.record),
+            &BytesN::from_array(&env, &signed.signature),
+            &signed.expiry,
+        ),
+        Err(Ok(Error::from_contract_error(4001)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4007)")]
+fn test_set_gateway_signer_by_stranger_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 52);
+    client.register(&Address::generate(&env), &hash);
+
+    client.set_gateway_signer(
+        &Address::generate(&env),
+        &hash,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+}
+
+// ── muxed resolution tests ───────────────────────────────────────────────────
+
+/// A G-account used as a custodial deposit wallet.
+const CUSTODY_ACCOUNT: &str = "GDQP2KPQGKIHYJGXNUIYOMHARUARCA7DJT5FO2FFOOKY3B2WSQHG4W37";
+
+/// Registers `hash` resolving to `wallet`, with `memo` if given.
+fn register_wallet(
+    env: &Env,
+    client: &ContractClient,
+    root: BytesN<32>,
+    hash: &BytesN<32>,
+    wallet: &Address,
+    memo: Option<u64>,
+) {
+    client.register(wallet, hash);
+    client.register_resolver(
+        wallet,
+        hash,
+        &dummy_proof(env),
+        &signals(hash, root, BytesN::from_array(env, &[0xB0; 32])),
+    );
+    if let Some(memo) = memo {
+        client.set_memo(wallet, hash, &memo);
+    }
+}
+
+#[test]
+fn test_resolve_muxed_combines_account_and_memo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 53);
+    let custody = Address::from_str(&env, CUSTODY_ACCOUNT);
+    register_wallet(&env, &client, root, &hash, &custody, Some(1_234_567));
+
+    let muxed = client.resolve_muxed(&hash).expect("muxed address");
+
+    assert_eq!(muxed.address(), custody);
+    assert_eq!(muxed.id(), Some(1_234_567));
+    assert_eq!(muxed, MuxedAddress::new(custody, 1_234_567));
+}
+
+#[test]
+fn test_resolve_muxed_without_memo_or_account_is_none() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let no_memo = commitment(&env, 54);
+    register_wallet(
+        &env,
+        &client,
+        root.clone(),
+        &no_memo,
+        &Address::from_str(&env, CUSTODY_ACCOUNT),
+        None,
+    );
+    let contract_wallet = commitment(&env, 58);
+    register_wallet(
+        &env,
+        &client,
+        client.get_smt_root(),
+        &contract_wallet,
+        &Address::generate(&env),
+        Some(7),
+    );
+
+    assert_eq!(client.resolve_muxed(&no_memo), None);
+    assert_eq!(client.resolve_muxed(&contract_wallet), None);
+}
+
+#[test]
+fn test_resolve_muxed_follows_privacy_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 59);
+    let custody = Address::from_str(&env, CUSTODY_ACCOUNT);
+    register_wallet(&env, &client, root, &hash, &custody, Some(99));
+
+    let mut policy = client.get_privacy_policy(&hash);
+    policy.memo = Visibility::Hidden;
+    client.set_privacy_policy(&custody, &hash, &policy);
+
+    assert_eq!(client.resolve_muxed(&hash), None);
+}
+
+// ── payer memo tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_resolve_for_payer_counts_memos_per_payer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 144);
+    let wallet = Address::generate(&env);
+    register_wallet(&env, &client, root, &hash, &wallet, Some(5));
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(client.resolve_for_payer(&hash, &alice), (wallet.clone(), 1));
+    assert_eq!(client.resolve_for_payer(&hash, &bob), (wallet.clone(), 2));
+    // Repeat lookups keep the payer's memo.
+    assert_eq!(client.resolve_for_payer(&hash, &alice), (wallet, 1));
+
+    assert_eq!(client.memo_payer(&hash, &1), Some(alice));
+    assert_eq!(client.memo_payer(&hash, &2), Some(bob));
+    assert_eq!(client.memo_payer(&hash, &3), None);
+}
+
+#[test]
+fn test_resolve_for_payer_derives_memo_from_secret_commitment() {
+    use crate::storage::DataKey;
+    use soroban_sdk::xdr::ToXdr;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 145);
+    let wallet = Address::generate(&env);
+    register_wallet(&env, &client, root, &hash, &wallet, None);
+    let secret = Bytes::from_array(&env, &[0x5E; 32]);
+    let secret_commitment: BytesN<32> = env.crypto().sha256(&secret).into();
+    client.set_payer_memo_commitment(&wallet, &hash, &Some(secret_commitment.clone()));
+    let payer = Address::generate(&env);
+
+    // Only the commitment is stored, never the secret.
+    let stored: Option<BytesN<32>> = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayerMemoCommitment(hash.clone()))
+    });
+    assert_eq!(stored, Some(secret_commitment.clone()));
+
+    // The merchant recomputes the memo off-chain from the secret and the payer.
+    let mut seed = Bytes::from_array(&env, &secret_commitment.to_array());
+    seed.append(&payer.clone().to_xdr(&env));
+    let digest = env.crypto().sha256(&seed).to_array();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest[..8]);
+    let expected = u64::from_be_bytes(head);
+
+    assert_eq!(
+        client.resolve_for_payer(&hash, &payer),
+        (wallet.clone(), expected)
+    );
+
+    // Clearing the commitment keeps assigned memos and counts up for new payers.
+    client.set_payer_memo_commitment(&wallet, &hash, &None);
+    assert_eq!(client.resolve_for_payer(&hash, &payer).1, expected);
+    let newcomer = Address::generate(&env);
+    assert_eq!(client.resolve_for_payer(&hash, &newcomer).1, 1);
+    assert_eq!(client.memo_payer(&hash, &expected), Some(payer));
+}
+
+#[test]
+fn test_resolve_for_payer_requires_payer_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 146);
+    register_wallet(&env, &client, root, &hash, &Address::generate(&env), None);
+    let payer = Address::generate(&env);
+
+    client.resolve_for_payer(&hash, &payer);
+
+    assert_eq!(
+        env.auths().first().map(|(addr, _)| addr.clone()),
+        Some(payer)
+    );
+}
+
+#[test]
+fn test_memo_payer_requires_owner_when_memo_not_public() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 147);
+    let owner = Address::generate(&env);
+    register_wallet(&env, &client, root, &hash, &owner, None);
+    let payer = Address::generate(&env);
+    client.resolve_for_payer(&hash, &payer);
+
+    client.memo_payer(&hash, &1);
+    assert!(env.auths().is_empty());
+
+    let mut policy = client.get_privacy_policy(&hash);
+    policy.memo = Visibility::Hidden;
+    client.set_privacy_policy(&owner, &hash, &policy);
+
+    assert_eq!(client.memo_payer(&hash, &1), Some(payer));
+    assert_eq!(
+        env.auths().first().map(|(addr, _)| addr.clone()),
+        Some(owner)
+    );
+}
+
+#[test]
+fn test_transfer_clear_resets_payer_memos() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let hash = commitment(&env, 148);
+    let owner = Address::generate(&env);
+    register_wallet(&env, &client, root, &hash, &owner, None);
+    client.set_payer_memo_commitment(&owner, &hash, &Some(BytesN::from_array(&env, &[1; 32])));
+    let payer = Address::generate(&env);
+    let old_memo = client.resolve_for_payer(&hash, &payer).1;
+
+    let new_owner = Address::generate(&env);
+    client.transfer(
+        &owner,
+        &hash,
+        &new_owner,
+        &dummy_proof(&env),
+        &signals(
+            &hash,
+            client.get_smt_root(),
+            BytesN::from_array(&env, &[0xB1; 32]),
+        ),
+        &RecordPolicy::Clear,
+    );
+
+    // The new owner sees none of the previous owner's payers and starts counting anew.
+    assert_eq!(client.memo_payer(&hash, &old_memo), None);
+    assert_eq!(client.resolve_for_payer(&hash, &payer).1, 1);
+}
+
+#[test]
+fn test_memo_payer_unregistered_commitment() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    let result = client.try_memo_payer(&commitment(&env, 149), &1);
+
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(CoreError::NotFound as u32)))
+    );
+}
+
+// ── batch resolution tests ───────────────────────────────────────────────────
+
+/// Registers `count` commitments, each resolving to its own wallet and Stellar address.
+fn register_batch(env: &Env, client: &ContractClient, count: u32) -> Vec<BytesN<32>> {
+    let mut hashes = Vec::new(env);
+    for i in 0..count {
+        let mut bytes = [0xBA; 32];
+        bytes[0] = i as u8;
+        let hash = BytesN::from_array(env, &bytes);
+        let owner = Address::generate(env);
+        let mut new_root = [0xB7; 32];
+        new_root[0] = i as u8;
+        client.register(&owner, &hash);
+        client.register_resolver(
+            &owner,
+            &hash,
+            &dummy_proof(env),
+            &signals(
+                &hash,
+                client.get_smt_root(),
+                BytesN::from_array(env, &new_root),
+            ),
+        );
+        client.add_stellar_address(&owner, &hash, &owner);
+        hashes.push_back(hash);
+    }
+    hashes
+}
+
+#[test]
+fn test_resolve_many_reports_status_per_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let resolved = commitment(&env, 163);
+    let wallet = Address::generate(&env);
+    register_wallet(&env, &client, root, &resolved, &wallet, Some(77));
+    let shielded = commitment(&env, 164);
+    let shielded_owner = Address::generate(&env);
+    register_wallet(
+        &env,
+        &client,
+        client.get_smt_root(),
+        &shielded,
+        &shielded_owner,
+        None,
+    );
+    client.set_privacy_mode(&shielded_owner, &shielded, &PrivacyMode::Shielded);
+    let no_address = commitment(&env, 165);
+    client.register(&Address::generate(&env), &no_address);
+    let unknown = commitment(&env, 166);
+
+    let statuses = client.resolve_many(&soroban_sdk::vec![
+        &env,
+        resolved.clone(),
+        unknown,
+        no_address,
+        shielded,
+        resolved
+    ]);
+
+    assert_eq!(
+        statuses,
+        soroban_sdk::vec![
+            &env,
+            ResolveStatus::Resolved(wallet.clone(), Some(77)),
+            ResolveStatus::NotFound,
+            ResolveStatus::NoAddress,
+            ResolveStatus::Shielded,
+            ResolveStatus::Resolved(wallet, Some(77)),
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_stellar_many_reports_status_per_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let linked = commitment(&env, 167);
+    let owner = Address::generate(&env);
+    client.register(&owner, &linked);
+    client.add_stellar_address(&owner, &linked, &owner);
+    let hidden = commitment(&env, 168);
+    let hidden_owner = Address::generate(&env);
+    client.register(&hidden_owner, &hidden);
+    client.add_stellar_address(&hidden_owner, &hidden, &hidden_owner);
+    let mut policy = client.get_privacy_policy(&hidden);
+    policy.stellar = Visibility::Hidden;
+    client.set_privacy_policy(&hidden_owner, &hidden, &policy);
+    let unlinked = commitment(&env, 169);
+    client.register(&Address::generate(&env), &unlinked);
+
+    let statuses = client.resolve_stellar_many(&soroban_sdk::vec![
+        &env,
+        linked,
+        hidden,
+        unlinked,
+        commitment(&env, 178)
+    ]);
+
+    assert_eq!(
+        statuses,
+        soroban_sdk::vec![
+            &env,
+            ResolveStatus::Resolved(owner, None),
+            ResolveStatus::Shielded,
+            ResolveStatus::NoAddress,
+            ResolveStatus::NotFound,
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_many_rejects_oversized_batch() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let mut hashes = Vec::new(&env);
+    for _ in 0..101 {
+        hashes.push_back(commitment(&env, 179));
+    }
+
+    assert_eq!(
+        client.try_resolve_many(&hashes),
+        Err(Ok(Error::from_contract_error(
+            CoreError::BatchTooLarge as u32
+        )))
+    );
+    assert_eq!(
+        client.try_resolve_stellar_many(&hashes),
+        Err(Ok(Error::from_contract_error(
+            CoreError::BatchTooLarge as u32
+        )))
+    );
+}
+
+/// Points each of `hashes` at its own `StoredResolver`, returning the deposit addresses.
+fn set_batch_resolvers(
+    env: &Env,
+    client: &ContractClient,
+    hashes: &Vec<BytesN<32>>,
+) -> Vec<Address> {
+    let mut deposits = Vec::new(env);
+    for hash in hashes.iter() {
+        let owner = client.get_owner(&hash).expect("registered");
+        let deposit = Address::generate(env);
+        client.set_custom_resolver(&owner, &hash, &stored_resolver(env, &deposit));
+        deposits.push_back(deposit);
+    }
+    deposits
+}
+
+#[test]
+fn test_resolve_many_limits_custom_resolver_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_root(&env);
+    let hashes = register_batch(&env, &client, 21);
+    set_batch_resolvers(&env, &client, &hashes);
+
+    assert_eq!(
+        client.try_resolve_many(&hashes),
+        Err(Ok(Error::from_contract_error(
+            CoreError::BatchTooLarge as u32
+        )))
+    );
+    assert_eq!(
+        client.try_resolve_stellar_many(&hashes),
+        Err(Ok(Error::from_contract_error(
+            CoreError::BatchTooLarge as u32
+        )))
+    );
+    assert_eq!(client.resolve_many(&hashes.slice(0..20)).len(), 20);
+}
+
+/// Network per-transaction limits the largest batch has to fit in. Native test runs skip
+/// Wasm VM costs, so a pass here leaves headroom rather than proving the on-chain figure.
+const TX_MAX_INSTRUCTIONS: u64 = 100_000_000;
+const TX_MEMORY_LIMIT: u64 = 41_943_040;
+
+#[test]
+fn test_resolve_many_full_batch_within_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_root(&env);
+    let hashes = register_batch(&env, &client, 100);
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    assert_eq!(statuses.len(), 100);
+    assert!(statuses
+        .iter()
+        .all(|status| matches!(status, ResolveStatus::Resolved(_, None))));
+}
+
+#[test]
+fn test_resolve_stellar_many_full_batch_within_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_root(&env);
+    let hashes = register_batch(&env, &client, 100);
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_stellar_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    assert_eq!(statuses.len(), 100);
+    assert!(statuses
+        .iter()
+        .all(|status| matches!(status, ResolveStatus::Resolved(_, None))));
+}
+
+#[test]
+fn test_resolve_many_full_batch_with_custom_resolvers_within_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_root(&env);
+    let hashes = register_batch(&env, &client, 100);
+    let deposits = set_batch_resolvers(&env, &client, &hashes.slice(0..20));
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    for (i, deposit) in deposits.iter().enumerate() {
+        assert_eq!(
+            statuses.get(i as u32),
+            Some(ResolveStatus::Resolved(deposit, Some(7)))
+        );
+    }
+    assert!(statuses
+        .slice(20..)
+        .iter()
+        .all(|status| matches!(status, ResolveStatus::Resolved(_, None))));
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_stellar_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    for (i, deposit) in deposits.iter().enumerate() {
+        assert_eq!(
+            statuses.get(i as u32),
+            Some(ResolveStatus::Resolved(deposit, None))
+        );
+    }
+}
+
+#[test]
+fn test_resolve_many_all_custom_resolvers_within_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _) = setup_with_root(&env);
+    let hashes = register_batch(&env, &client, 20);
+    let deposits = set_batch_resolvers(&env, &client, &hashes);
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    for (i, deposit) in deposits.iter().enumerate() {
+        assert_eq!(
+            statuses.get(i as u32),
+            Some(ResolveStatus::Resolved(deposit, Some(7)))
+        );
+    }
+
+    env.cost_estimate().budget().reset_default();
+    let statuses = client.resolve_stellar_many(&hashes);
+
+    let budget = env.cost_estimate().budget();
+    assert!(budget.cpu_instruction_cost() < TX_MAX_INSTRUCTIONS);
+    assert!(budget.memory_bytes_cost() < TX_MEMORY_LIMIT);
+    for (i, deposit) in deposits.iter().enumerate() {
+        assert_eq!(
+            statuses.get(i as u32),
+            Some(ResolveStatus::Resolved(deposit, None))
+        );
+    }
+}
+
+// ── owner index tests ────────────────────────────────────────────────────────
+
+/// Asserts that `owner`'s index holds exactly `expected`, in any order.
+fn assert_owns(client: &ContractClient, owner: &Address, expected: &[BytesN<32>]) {
+    let owned = client.owned_by(owner, &0, &50);
+    assert_eq!(client.owned_count(owner), expected.len() as u32);
+    assert_eq!(owned.len(), expected.len() as u32);
+    for hash in expected {
+        assert!(owned.contains(hash));
+    }
+}
+
+#[test]
+fn test_owned_by_lists_registered_commitments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let registered = commitment(&env, 186);
+    let proven = commitment(&env, 187);
+    client.register(&owner, &registered);
+    client.submit_proof(
+        &owner,
+        &dummy_proof(&env),
+        &signals(&proven, root, BytesN::from_array(&env, &[0xB2; 32])),
+    );
+
+    assert_owns(&client, &owner, &[registered.clone(), proven.clone()]);
+    assert_eq!(
+        client.owned_by(&owner, &0, &2),
+        Vec::from_array(&env, [registered, proven.clone()])
+    );
+    assert_eq!(
+        client.owned_by(&owner, &1, &5),
+        Vec::from_array(&env, [proven])
+    );
+    assert!(client.owned_by(&owner, &2, &5).is_empty());
+    assert_eq!(client.owned_count(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_owner_index_follows_every_transfer_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, root) = setup_with_root(&env);
+    let owner = Address::generate(&env);
+    let direct = commitment(&env, 188);
+    let proposed = commitment(&env, 189);
+    let proven = commitment(&env, 154);
+    let kept = commitment(&env, 157);
+    for hash in [&direct, &proposed, &proven, &kept] {
+        client.register(&owner, hash);
+    }
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.transfer_ownership(&owner, &direct, &alice, &RecordPolicy::Keep);
+    env.ledger().set_timestamp(1_000);
+    client.propose_transfer(&owner, &proposed, &bob, &2_000, &RecordPolicy::Keep);
+    client.accept_transfer(&bob, &proposed);
+    client.transfer(
+        &owner,
+        &proven,
+        &alice,
+        &dummy_proof(&env),
+        &signals(&proven, root, BytesN::from_array(&env, &[0xB3; 32])),
+        &RecordPolicy::Keep,
+    );
+
+    assert_owns(&client, &owner, &[kept]);
+    assert_owns(&client, &alice, &[direct.clone(), proven]);
+    assert_owns(&client, &bob, &[proposed]);
+
+    // Passing a name back re-indexes it under the original owner.
+    client.transfer_ownership(&alice, &direct, &owner, &RecordPolicy::Keep);
+    assert_eq!(client.owned_count(&owner), 2);
+    assert_eq!(client.owned_count(&alice), 1);
+}
+
+#[test]
+fn test_owner_index_follows_guardian_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let hash = commitment(&env, 123);
+    let (owner, [g1, g2, _]) = setup_members(&env, &client, &hash, Members::Guardians);
+    let new_owner = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000);
+    client.recover(&g1, &hash, &new_owner);
+    client.recover(&g2, &hash, &new_owner);
+    env.ledger().set_timestamp(1_100);
+    client.execute_recovery(&hash);
+
+    assert_owns(&client, &owner, &[]);
+    assert_owns(&client, &new_owner, &[hash]);
+}
+
+#[test]
+fn test_burn_removes_commitment_from_owner_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let burned = commitment(&env, 88);
+    let kept = commitment(&env, 89);
+    client.register(&owner, &burned);
+    client.register(&owner, &kept);
+
+    client.burn(&owner, &burned);
+
+    assert_owns(&client, &owner, core::slice::from_ref(&kept));
+    // Registering the burned name again indexes it afresh.
+    client.register(&owner, &burned);
+    assert_owns(&client, &owner, &[kept, burned]);
+}
+
+#[test]
+fn test_owner_index_lists_group_owned_names_under_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 216);
+    let (owner, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+
+    assert_eq!(client.owned_count(&owner), 0);
+    assert_eq!(
+        client.owned_by(&contract_id, &0, &10),
+        Vec::from_array(&env, [hash.clone()])
+    );
+
+    let recipient = Address::generate(&env);
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1, s2]),
+        &hash,
+        &MultisigAction::ProposeTransfer(recipient.clone(), 100, RecordPolicy::Keep),
+    );
+    client.accept_transfer(&recipient, &hash);
+
+    assert_eq!(client.owned_count(&contract_id), 0);
+    assert_eq!(
+        client.owned_by(&recipient, &0, &10),
+        Vec::from_array(&env, [hash])
+    );
+}
+
+#[test]
+fn test_burning_group_owned_name_removes_it_from_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client) = setup(&env);
+    let hash = commitment(&env, 233);
+    let (_, [s1, s2, _]) = setup_members(&env, &client, &hash, Members::Signers);
+    assert_eq!(client.owned_count(&contract_id), 1);
+
+    client.execute_multisig(
+        &Vec::from_array(&env, [s1, s2]),
+        &hash,
+        &MultisigAction::Burn,
+    );
+
+    assert_eq!(client.owned_count(&contract_id), 0);
+}
+
+#[test]
+fn test_owned_by_caps_page_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    for i in 0..51u8 {
+        let mut bytes = [0xC4; 32];
+        bytes[0] = i;
+        client.register(&owner, &BytesN::from_array(&env, &bytes));
+    }
+
+    assert_eq!(client.owned_count(&owner), 51);
+    assert_eq!(client.owned_by(&owner, &0, &100).len(), 50);
+    assert_eq!(client.owned_by(&owner, &50, &100).len(), 1);
+}
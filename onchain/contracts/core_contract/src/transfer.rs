@@ -1,9 +1,18 @@
 use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
 
+use crate::address_manager::AddressManager;
+use crate::delegates::Delegates;
 use crate::errors::CoreError;
-use crate::events::TRANSFER_EVENT;
+use crate::events::{
+    records_reset_event, transfer_accepted_event, transfer_cancelled_event,
+    transfer_proposed_event, transfers_locked_event, TRANSFER_EVENT,
+};
+use crate::history::History;
+use crate::owner_index::OwnerIndex;
 use crate::registration;
-use crate::types::PublicSignals;
+use crate::resolver::Resolver;
+use crate::storage::{self, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+use crate::types::{HistoryField, PendingTransfer, PublicSignals, RecordPolicy};
 use crate::{smt_root, zk_verifier};
 
 pub struct Transfer;
@@ -13,40 +22,46 @@ impl Transfer {
     ///
     /// A simple ownership transfer where the current owner directly assigns the username to a new owner.
     /// Both caller and new owner must be different. This operation does NOT require a ZK proof.
+    /// It can be disabled by the contract owner in favour of the two-step
+    /// [`Self::propose_transfer`] / [`Self::accept_transfer`] flow.
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
     /// - `caller`: The current owner authorizing the transfer. Must be authorized.
     /// - `commitment`: The 32-byte username commitment being transferred.
     /// - `new_owner`: The address that will become the new owner.
+    /// - `policy`: What happens to the linked records; see [`RecordPolicy`].
     ///
     /// ### Errors
+    /// - `DirectTransferDisabled`: If direct transfers have been disabled by the contract owner.
     /// - `NotFound`: If the commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `Unauthorized`: If the caller is not the current owner, or the username is
+    ///   group-owned.
+    /// - `TransfersLocked`: If the owner has made the username non-transferable.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     /// - `SameOwner`: If the new owner is the same as the current owner.
     ///
     /// ### Events
     /// - Emits `TRANSFER_EVENT` with (commitment, old_owner, new_owner).
+    /// - Emits `RECORDS_RESET` with (commitment, policy) unless `policy` is `Keep`.
     pub fn transfer_ownership(
         env: Env,
         caller: Address,
         commitment: BytesN<32>,
         new_owner: Address,
+        policy: RecordPolicy,
     ) {
-        caller.require_auth();
-        let key = registration::DataKey::Commitment(commitment.clone());
-        let current_owner: Address = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
-        if caller != current_owner {
-            panic_with_error!(&env, CoreError::Unauthorized);
+        if !storage::direct_transfers_enabled(&env) {
+            panic_with_error!(&env, CoreError::DirectTransferDisabled);
         }
+        Self::require_not_group(&env, &commitment);
+        let current_owner = Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::require_unlocked(&env, &commitment);
+        Self::require_not_frozen(&env, &commitment);
         if new_owner == current_owner {
             panic_with_error!(&env, CoreError::SameOwner);
         }
-        env.storage().persistent().set(&key, &new_owner);
+        Self::hand_over(&env, &commitment, &new_owner, policy);
         #[allow(deprecated)]
         env.events()
             .publish((TRANSFER_EVENT,), (commitment, caller, new_owner));
@@ -54,9 +69,10 @@ impl Transfer {
 
     /// Transfers username ownership with zero-knowledge proof validation.
     ///
-    /// An advanced ownership transfer that requires a valid ZK proof, enabling secure transfers
-    /// when the current owner cannot directly authorize (e.g., keyless recovery scenarios).
-    /// The proof must be valid against the current SMT root. Upon success, the SMT root is updated.
+    /// An advanced ownership transfer that requires a valid ZK proof in addition to the current
+    /// owner's authorization. The proof must be valid against the current SMT root. Upon success,
+    /// the SMT root is updated. Owners who lose their key recover through guardians instead;
+    /// see [`crate::recovery::Recovery`].
     ///
     /// ### Arguments
     /// - `env`: The Soroban contract environment.
@@ -65,10 +81,14 @@ impl Transfer {
     /// - `new_owner`: The address that will become the new owner.
     /// - `proof`: Serialized Groth16 proof validating the transfer.
     /// - `public_signals`: Public inputs including old_root, new_root, and commitment.
+    /// - `policy`: What happens to the linked records; see [`RecordPolicy`].
     ///
     /// ### Errors
     /// - `NotFound`: If the commitment is not registered.
-    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `Unauthorized`: If the caller is not the current owner, or the username is
+    ///   group-owned.
+    /// - `TransfersLocked`: If the owner has made the username non-transferable.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
     /// - `SameOwner`: If the new owner is the same as the current owner.
     /// - `RootNotSet`: If the SMT root has not been initialized.
     /// - `StaleRoot`: If the proof's old_root doesn't match the current SMT root.
@@ -77,6 +97,7 @@ impl Transfer {
     /// ### Events
     /// - Emits `TRANSFER_EVENT` with (commitment, old_owner, new_owner).
     /// - Updates the SMT root via `ROOT_UPDATED` event.
+    /// - Emits `RECORDS_RESET` with (commitment, policy) unless `policy` is `Keep`.
     pub fn transfer(
         env: Env,
         caller: Address,
@@ -84,17 +105,12 @@ impl Transfer {
         new_owner: Address,
         proof: Bytes,
         public_signals: PublicSignals,
+        policy: RecordPolicy,
     ) {
-        caller.require_auth();
-        let key = registration::DataKey::Commitment(commitment.clone());
-        let current_owner: Address = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NotFound));
-        if caller != current_owner {
-            panic_with_error!(&env, CoreError::Unauthorized);
-        }
+        Self::require_not_group(&env, &commitment);
+        let current_owner = Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::require_unlocked(&env, &commitment);
+        Self::require_not_frozen(&env, &commitment);
         if new_owner == current_owner {
             panic_with_error!(&env, CoreError::SameOwner);
         }
@@ -109,10 +125,325 @@ impl Transfer {
         if !zk_verifier::ZkVerifier::verify_groth16_proof(&env, &proof, &public_signals) {
             panic_with_error!(&env, CoreError::InvalidProof);
         }
-        env.storage().persistent().set(&key, &new_owner);
+        Self::hand_over(&env, &commitment, &new_owner, policy);
         smt_root::SmtRoot::update_root(&env, public_signals.new_root);
         #[allow(deprecated)]
         env.events()
             .publish((TRANSFER_EVENT,), (commitment, caller, new_owner));
     }
+
+    /// Proposes a two-step ownership transfer that the recipient must accept.
+    ///
+    /// Ownership does not move until `new_owner` calls [`Self::accept_transfer`] before
+    /// `expires_at`, so a mistyped recipient can never take the name. A new proposal
+    /// replaces any existing one.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The current owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment being transferred.
+    /// - `new_owner`: The address invited to become the new owner.
+    /// - `expires_at`: Ledger timestamp after which the proposal can no longer be accepted.
+    /// - `policy`: Applied to the linked records when the transfer is accepted.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `TransfersLocked`: If the owner has made the username non-transferable.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    /// - `SameOwner`: If the new owner is the same as the current owner.
+    /// - `InvalidExpiry`: If `expires_at` is not in the future.
+    ///
+    /// ### Events
+    /// - Emits `TRANSFER_PROPOSED` with (commitment, owner, new_owner, expires_at).
+    pub fn propose_transfer(
+        env: Env,
+        caller: Address,
+        commitment: BytesN<32>,
+        new_owner: Address,
+        expires_at: u64,
+        policy: RecordPolicy,
+    ) {
+        let current_owner = Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::propose(
+            &env,
+            &commitment,
+            current_owner,
+            new_owner,
+            expires_at,
+            policy,
+        );
+    }
+
+    /// (Internal) Records a transfer proposal once `from` has been authorized as the owner.
+    ///
+    /// Shared by [`Self::propose_transfer`] and group-owned commitments.
+    pub(crate) fn propose(
+        env: &Env,
+        commitment: &BytesN<32>,
+        from: Address,
+        new_owner: Address,
+        expires_at: u64,
+        policy: RecordPolicy,
+    ) {
+        Self::require_unlocked(env, commitment);
+        Self::require_not_frozen(env, commitment);
+        if new_owner == from {
+            panic_with_error!(env, CoreError::SameOwner);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            panic_with_error!(env, CoreError::InvalidExpiry);
+        }
+
+        let pending = PendingTransfer {
+            from: from.clone(),
+            to: new_owner.clone(),
+            expires_at,
+            policy,
+        };
+        storage::set_pending_transfer(env, commitment, &pending);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (transfer_proposed_event(env),),
+            (commitment.clone(), from, new_owner, expires_at),
+        );
+    }
+
+    /// Accepts a pending ownership transfer.
+    ///
+    /// Must be authorized by the proposed recipient before the proposal expires.
+    /// The proposal is void if ownership changed after it was made.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The proposed new owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment being transferred.
+    ///
+    /// ### Errors
+    /// - `NoPendingTransfer`: If there is no live proposal for the commitment.
+    /// - `Unauthorized`: If the caller is not the proposed recipient.
+    /// - `TransferExpired`: If the proposal has expired.
+    /// - `TransfersLocked`: If the username was made non-transferable after the proposal.
+    /// - `CooldownActive`: If the username changed hands within the transfer cooldown.
+    ///
+    /// ### Events
+    /// - Emits `TRANSFER_ACCEPTED` with (commitment, old_owner, new_owner).
+    /// - Emits `TRANSFER_EVENT` with (commitment, old_owner, new_owner).
+    /// - Emits `RECORDS_RESET` with (commitment, policy) unless the proposal's policy is `Keep`.
+    pub fn accept_transfer(env: Env, caller: Address, commitment: BytesN<32>) {
+        caller.require_auth();
+        let pending = storage::get_pending_transfer(&env, &commitment)
+            .unwrap_or_else(|| panic_with_error!(&env, CoreError::NoPendingTransfer));
+        if registration::Registration::get_owner(env.clone(), commitment.clone())
+            != Some(pending.from.clone())
+        {
+            panic_with_error!(&env, CoreError::NoPendingTransfer);
+        }
+        if caller != pending.to {
+            panic_with_error!(&env, CoreError::Unauthorized);
+        }
+        if env.ledger().timestamp() >= pending.expires_at {
+            panic_with_error!(&env, CoreError::TransferExpired);
+        }
+        Self::require_unlocked(&env, &commitment);
+        Self::require_not_frozen(&env, &commitment);
+
+        Self::hand_over(&env, &commitment, &caller, pending.policy.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (transfer_accepted_event(&env),),
+            (commitment.clone(), pending.from.clone(), caller.clone()),
+        );
+        #[allow(deprecated)]
+        env.events()
+            .publish((TRANSFER_EVENT,), (commitment, pending.from, caller));
+    }
+
+    /// Cancels a pending ownership transfer.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The current owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `NoPendingTransfer`: If there is no proposal to cancel.
+    ///
+    /// ### Events
+    /// - Emits `TRANSFER_CANCELLED` with (commitment, proposed_new_owner).
+    pub fn cancel_transfer(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::cancel(&env, &commitment);
+    }
+
+    /// (Internal) Withdraws the pending proposal once the owner has been authorized.
+    ///
+    /// Shared by [`Self::cancel_transfer`] and group-owned commitments.
+    pub(crate) fn cancel(env: &Env, commitment: &BytesN<32>) {
+        let pending = storage::get_pending_transfer(env, commitment)
+            .unwrap_or_else(|| panic_with_error!(env, CoreError::NoPendingTransfer));
+        storage::remove_pending_transfer(env, commitment);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (transfer_cancelled_event(env),),
+            (commitment.clone(), pending.to),
+        );
+    }
+
+    /// Retrieves the pending ownership transfer for a commitment, if any.
+    ///
+    /// Expired proposals are still returned so wallets can show them; check `expires_at`.
+    pub fn get_pending_transfer(env: Env, commitment: BytesN<32>) -> Option<PendingTransfer> {
+        storage::get_pending_transfer(&env, &commitment)
+    }
+
+    /// Makes a username permanently non-transferable (soulbound).
+    ///
+    /// The lock cannot be lifted. Afterwards every owner-initiated transfer path refuses
+    /// with `TransfersLocked`; only recovery approved by the contract owner can still move
+    /// the name. Linked records remain editable.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The current owner. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment to lock.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the current owner.
+    /// - `TransfersLocked`: If the username is already locked.
+    ///
+    /// ### Events
+    /// - Emits `TRANSFERS_LOCKED` with (commitment, owner).
+    pub fn lock_transfers(env: Env, caller: Address, commitment: BytesN<32>) {
+        let owner = Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::lock(&env, &commitment, owner);
+    }
+
+    /// (Internal) Makes the username soulbound once `owner` has been authorized.
+    ///
+    /// Shared by [`Self::lock_transfers`] and group-owned commitments.
+    pub(crate) fn lock(env: &Env, commitment: &BytesN<32>, owner: Address) {
+        Self::require_unlocked(env, commitment);
+        storage::set_transfer_locked(env, commitment);
+        storage::remove_pending_transfer(env, commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((transfers_locked_event(env),), (commitment.clone(), owner));
+    }
+
+    /// Returns `true` if the username has been made non-transferable.
+    pub fn is_transfer_locked(env: Env, commitment: BytesN<32>) -> bool {
+        storage::is_transfer_locked(&env, &commitment)
+    }
+
+    /// Returns the timestamp until which the username is frozen after its last ownership
+    /// change, or 0 if it has never been frozen. Frozen while `now < locked_until`.
+    pub fn locked_until(env: Env, commitment: BytesN<32>) -> u64 {
+        storage::get_locked_until(&env, &commitment)
+    }
+
+    /// (Internal) Panics with `Unauthorized` if the username is owned by a signer group.
+    ///
+    /// A group hands its name over only through a proposal the new owner accepts; see
+    /// [`crate::types::MultisigAction::ProposeTransfer`].
+    fn require_not_group(env: &Env, commitment: &BytesN<32>) {
+        if storage::get_multisig(env, commitment).is_some() {
+            panic_with_error!(env, CoreError::Unauthorized);
+        }
+    }
+
+    /// (Internal) Panics with `TransfersLocked` if the username is soulbound.
+    pub(crate) fn require_unlocked(env: &Env, commitment: &BytesN<32>) {
+        if storage::is_transfer_locked(env, commitment) {
+            panic_with_error!(env, CoreError::TransfersLocked);
+        }
+    }
+
+    /// (Internal) Panics with `CooldownActive` while the username is inside the cooldown
+    /// that follows an ownership change.
+    pub(crate) fn require_not_frozen(env: &Env, commitment: &BytesN<32>) {
+        if env.ledger().timestamp() < storage::get_locked_until(env, commitment) {
+            panic_with_error!(env, CoreError::CooldownActive);
+        }
+    }
+
+    /// (Internal) Moves ownership to a new party: clears the previous owner's guardians
+    /// and applies `policy` to the linked records.
+    pub(crate) fn hand_over(
+        env: &Env,
+        commitment: &BytesN<32>,
+        new_owner: &Address,
+        policy: RecordPolicy,
+    ) {
+        Self::set_owner(env, commitment, new_owner);
+        storage::remove_guardians(env, commitment);
+        Self::apply_record_policy(env, commitment, policy);
+    }
+
+    /// (Internal) Writes the new owner, moves the commitment between owner indexes, bumps
+    /// the record TTL, starts the transfer cooldown and clears every delegate grant and any
+    /// pending transfer proposal, recovery request or signer group.
+    ///
+    /// Delegates hold authority over the name rather than records, so they never survive
+    /// an ownership change, whatever the record policy.
+    ///
+    /// Every ownership change goes through here so all transfer paths stay consistent.
+    pub(crate) fn set_owner(env: &Env, commitment: &BytesN<32>, new_owner: &Address) {
+        let key = registration::DataKey::Commitment(commitment.clone());
+        if let Some(previous) = env.storage().persistent().get::<_, Address>(&key) {
+            OwnerIndex::remove(env, &previous, commitment);
+        }
+        OwnerIndex::add(env, new_owner, commitment);
+        env.storage().persistent().set(&key, new_owner);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        History::record(env, commitment, HistoryField::Owner);
+        let cooldown = storage::get_transfer_cooldown(env);
+        if cooldown > 0 {
+            storage::set_locked_until(
+                env,
+                commitment,
+                env.ledger().timestamp().saturating_add(cooldown),
+            );
+        }
+        Delegates::clear(env, commitment);
+        storage::remove_pending_transfer(env, commitment);
+        storage::remove_recovery(env, commitment);
+        storage::remove_multisig(env, commitment);
+    }
+
+    /// (Internal) Clears the records the previous owner linked, according to `policy`.
+    ///
+    /// Runs in the same invocation as the ownership change, so the reset is atomic with it.
+    /// Both clearing policies point the stored resolver record at the new owner.
+    pub(crate) fn apply_record_policy(env: &Env, commitment: &BytesN<32>, policy: RecordPolicy) {
+        let keep_non_payment = match policy {
+            RecordPolicy::Keep => return,
+            RecordPolicy::Clear => false,
+            RecordPolicy::KeepNonPayment => true,
+        };
+        AddressManager::clear_addresses(env, commitment);
+        if !keep_non_payment {
+            storage::remove_privacy_mode(env, commitment);
+            storage::remove_privacy_policy(env, commitment);
+        }
+        if let Some(owner) = registration::Registration::get_owner(env.clone(), commitment.clone())
+        {
+            Resolver::reset(env, commitment, &owner);
+        }
+        History::record(env, commitment, HistoryField::StellarAddress);
+        #[allow(deprecated)]
+        env.events()
+            .publish((records_reset_event(env),), (commitment.clone(), policy));
+    }
 }
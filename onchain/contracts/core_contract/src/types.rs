@@ -1,7 +1,7 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Symbol};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Symbol, Vec};
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AddressMetadata {
     pub label: Symbol,
 }
@@ -22,6 +22,24 @@ pub enum ChainType {
     Cosmos,
 }
 
+/// A chain address linked to a commitment.
+/// `verified` is `true` only when control of the address was proven by a signature.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainAddressRecord {
+    pub address: Bytes,
+    pub verified: bool,
+    pub metadata: AddressMetadata,
+}
+
+/// A Stellar address linked to a commitment, with its label.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StellarAddressEntry {
+    pub address: Address,
+    pub metadata: AddressMetadata,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PrivacyMode {
@@ -29,9 +47,274 @@ pub enum PrivacyMode {
     Shielded,
 }
 
+/// A shielded address commitment and the ledger-time window in which it was current.
+///
+/// `valid_until` is `None` while the commitment is still current.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShieldedRecord {
+    pub address_commitment: BytesN<32>,
+    pub valid_from: u64,
+    pub valid_until: Option<u64>,
+}
+
+/// Who may read a class of resolution fields.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Visibility {
+    /// Returned by every getter.
+    Public,
+    /// Returned only to the owner through `resolve_records`.
+    Hidden,
+    /// Returned only to the owner and active delegates through `resolve_records`.
+    DelegatesOnly,
+}
+
+/// Per-field read policy for a commitment's resolution records.
+///
+/// Chains missing from `chains` are `Public`. `PrivacyMode::Shielded` hides a public
+/// `primary` wallet as if it were `Hidden`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivacyPolicy {
+    pub primary: Visibility,
+    pub stellar: Visibility,
+    pub chains: Map<ChainType, Visibility>,
+    pub memo: Visibility,
+    pub shielded: Visibility,
+    pub text: Visibility,
+}
+
+/// The resolution records visible to an authenticated reader under the privacy policy.
+///
+/// Fields the reader may not see are `None` or empty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordView {
+    pub wallet: Option<Address>,
+    pub memo: Option<u64>,
+    pub stellar_addresses: Vec<Address>,
+    pub chain_addresses: Map<ChainType, Bytes>,
+    pub shielded_address: Option<BytesN<32>>,
+}
+
+/// Outcome of resolving one commitment of a batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolveStatus {
+    /// The wallet and public memo; the Stellar batch never carries a memo.
+    Resolved(Address, Option<u64>),
+    /// The commitment is not registered.
+    NotFound,
+    /// The commitment is registered but has no address to resolve to.
+    NoAddress,
+    /// The address exists but is not public under the privacy mode or policy.
+    Shielded,
+}
+
+/// Class of mutations a delegate may perform on a commitment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DelegateRole {
+    /// Records manager: privacy mode and other non-address records.
+    Records,
+    /// Address manager: non-Stellar chain addresses (add, verify, label, primary, remove).
+    Addresses,
+    /// Payments manager: Stellar addresses, shielded address and memo.
+    Payments,
+}
+
+/// A role granted by a commitment owner to a delegate address.
+/// `expires_at` is a ledger timestamp; `None` means the grant never expires.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateGrant {
+    pub delegate: Address,
+    pub role: DelegateRole,
+    pub expires_at: Option<u64>,
+}
+
+/// What happens to a commitment's linked records when its ownership changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordPolicy {
+    /// Remove all linked addresses, the encrypted record and the privacy settings, and
+    /// point the resolver record at the new owner.
+    Clear,
+    /// Leave all records untouched.
+    Keep,
+    /// Remove payment routing (Stellar, chain and shielded addresses, the encrypted record,
+    /// the custom resolver and payer memos) and point the resolver record at the new owner;
+    /// keep the privacy settings.
+    KeepNonPayment,
+}
+
+/// An ownership transfer proposed by `from` that `to` may accept before `expires_at`.
+/// `policy` is applied to the commitment's records on acceptance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    pub from: Address,
+    pub to: Address,
+    pub expires_at: u64,
+    pub policy: RecordPolicy,
+}
+
+/// An owner-configured M-of-N guardian set. Recovery needs `threshold` guardian
+/// approvals and becomes executable `delay` seconds after the threshold is reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianConfig {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+    pub delay: u64,
+}
+
+/// A guardian-initiated recovery of a commitment to `new_owner`.
+/// `executable_at` is set once the guardian threshold is reached. Until then, guardians
+/// backing other new owners are kept in `challengers`, by new owner; one that gathers more
+/// approvals than `approvals` takes over the request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    pub new_owner: Address,
+    pub approvals: Vec<Address>,
+    pub executable_at: Option<u64>,
+    pub challengers: Map<Address, Vec<Address>>,
+}
+
 /// Serialized Groth16 proof bytes submitted by the caller.
 pub type Proof = Bytes;
 
+/// Why the admin reserved a username.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReservedReason {
+    /// Trademark or brand name held for its owner.
+    Brand,
+    /// Offensive or abusive name that must never be registered.
+    Offensive,
+    /// Name reserved for the protocol itself.
+    Protocol,
+}
+
+/// An admin reservation on a commitment. Only `claimant`, when set, may register it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReservedName {
+    pub commitment: BytesN<32>,
+    pub reason: ReservedReason,
+    pub claimant: Option<Address>,
+}
+
+/// An admin-published allocation round of `(commitment, claimant)` leaves.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRound {
+    pub root: BytesN<32>,
+    pub deadline: u64,
+}
+
+/// The signer group owning a commitment: any `threshold` of `signers` act as the owner.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// An owner-level operation a signer group can perform on its commitment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MultisigAction {
+    /// Replace the signer set and threshold.
+    SetSigners(Vec<Address>, u32),
+    /// Propose a two-step transfer to `(new_owner, expires_at, policy)`.
+    ProposeTransfer(Address, u64, RecordPolicy),
+    /// Grant `(delegate, role, expires_at)`.
+    GrantDelegate(Address, DelegateRole, Option<u64>),
+    /// Revoke `(delegate, role)`.
+    RevokeDelegate(Address, DelegateRole),
+    /// Withdraw the group's pending transfer proposal.
+    CancelTransfer,
+    /// Make the username permanently non-transferable.
+    LockTransfers,
+    /// Replace the guardians with `(guardians, threshold, delay)`.
+    SetGuardians(Vec<Address>, u32, u64),
+    /// Veto the open recovery request.
+    CancelRecovery,
+    /// Publish the plaintext username.
+    PublishUsername(String),
+    /// Remove the published username.
+    UnpublishUsername,
+    /// Burn the username, dissolving the group.
+    Burn,
+}
+
+/// A group action awaiting approvals across ledgers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigProposal {
+    pub action: MultisigAction,
+    pub approvals: Vec<Address>,
+}
+
+/// Public keys a sender needs to derive one-time stealth addresses for a commitment.
+/// Key encodings depend on `scheme_id`; scheme 1 (secp256k1) uses 33-byte compressed keys.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StealthMetaAddress {
+    pub scheme_id: u32,
+    pub spending_pubkey: Bytes,
+    pub viewing_pubkey: Bytes,
+}
+
+/// A content key wrapped for one viewing key.
+///
+/// `viewer` is an X25519 public key held by a single payer or shared by a group;
+/// `envelope` is the record's content key sealed to it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordGrant {
+    pub viewer: BytesN<32>,
+    pub envelope: Bytes,
+}
+
+/// Address records encrypted under a content key, with one envelope per authorized viewer.
+///
+/// `version` increases every time the ciphertext is replaced, so clients can tell a
+/// rotated content key from a stale envelope.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EncryptedRecord {
+    pub ciphertext: Bytes,
+    pub version: u32,
+    pub grants: Vec<RecordGrant>,
+}
+
+/// The resolution-affecting field whose change produced a history entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HistoryField {
+    Owner,
+    StellarAddress,
+    Memo,
+    PrivacyMode,
+}
+
+/// Snapshot of a commitment's resolution state right after a change at `timestamp`.
+/// `owner` falls back to the resolver wallet for commitments without a registered owner.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub changed: HistoryField,
+    pub owner: Option<Address>,
+    pub stellar_address: Option<Address>,
+    pub memo: Option<u64>,
+    pub privacy_mode: PrivacyMode,
+}
+
 /// Public signals extracted from a Groth16 non-inclusion proof.
 /// `old_root` must match the current on-chain SMT root.
 /// `new_root` becomes the new SMT root after a successful registration.
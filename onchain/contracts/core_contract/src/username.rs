@@ -0,0 +1,102 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, String};
+
+use crate::delegates::Delegates;
+use crate::errors::CoreError;
+use crate::events::{username_published_event, username_unpublished_event};
+use crate::poseidon;
+use crate::storage;
+
+/// Longest username the `username_hash` circuit accepts, in ASCII characters.
+pub const MAX_USERNAME_LEN: u32 = 32;
+
+pub struct Username;
+
+impl Username {
+    /// Publishes the plaintext username of a commitment.
+    ///
+    /// The contract recomputes the circuit's Poseidon commitment of `username` (ASCII,
+    /// zero-padded to 32 bytes) and stores it only if it equals `commitment`, so a
+    /// published name is always the real preimage. Publishing is opt-in and reveals the
+    /// name permanently to anyone reading the ledger history, even after unpublishing.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner of the commitment. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    /// - `username`: The plaintext username.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered.
+    /// - `Unauthorized`: If the caller is not the owner.
+    /// - `InvalidUsername`: If `username` is empty, longer than `MAX_USERNAME_LEN`, has a
+    ///   NUL or non-ASCII byte, or does not hash to `commitment`.
+    ///
+    /// ### Events
+    /// - Emits `USERNAME_PUB` event with (commitment, username).
+    pub fn publish_username(env: Env, caller: Address, commitment: BytesN<32>, username: String) {
+        Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::publish(&env, &commitment, username);
+    }
+
+    /// (Internal) Checks and stores the plaintext once the owner has been authorized.
+    ///
+    /// Shared by [`Self::publish_username`] and group-owned commitments.
+    pub(crate) fn publish(env: &Env, commitment: &BytesN<32>, username: String) {
+        let len = username.len();
+        if len == 0 || len > MAX_USERNAME_LEN {
+            panic_with_error!(env, CoreError::InvalidUsername);
+        }
+        let mut bytes = [0u8; MAX_USERNAME_LEN as usize];
+        let chars = &mut bytes[..len as usize];
+        username.copy_into_slice(chars);
+        if chars.iter().any(|&c| c == 0 || !c.is_ascii())
+            || poseidon::username_hash(&bytes) != commitment.to_array()
+        {
+            panic_with_error!(env, CoreError::InvalidUsername);
+        }
+        storage::set_username(env, commitment, &username);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (username_published_event(env),),
+            (commitment.clone(), username),
+        );
+    }
+
+    /// Removes a published username.
+    ///
+    /// ### Arguments
+    /// - `env`: The Soroban contract environment.
+    /// - `caller`: The owner of the commitment. Must be authorized.
+    /// - `commitment`: The 32-byte username commitment.
+    ///
+    /// ### Errors
+    /// - `NotFound`: If the commitment is not registered or has no published username.
+    /// - `Unauthorized`: If the caller is not the owner.
+    ///
+    /// ### Events
+    /// - Emits `USERNAME_UNPUB` event with (commitment,).
+    pub fn unpublish_username(env: Env, caller: Address, commitment: BytesN<32>) {
+        Delegates::require_authorized(&env, &caller, &commitment, None);
+        Self::unpublish(&env, &commitment);
+    }
+
+    /// (Internal) Removes the plaintext once the owner has been authorized.
+    ///
+    /// Shared by [`Self::unpublish_username`] and group-owned commitments.
+    pub(crate) fn unpublish(env: &Env, commitment: &BytesN<32>) {
+        if storage::get_username(env, commitment).is_none() {
+            panic_with_error!(env, CoreError::NotFound);
+        }
+        storage::remove_username(env, commitment);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((username_unpublished_event(env),), (commitment.clone(),));
+    }
+
+    /// Returns the published username of a commitment, if any.
+    pub fn get_username(env: Env, commitment: BytesN<32>) -> Option<String> {
+        storage::get_username(&env, &commitment)
+    }
+}
@@ -20,7 +20,8 @@ use crate::storage::{
 };
 use crate::types::{AutoPay, DataKey, ScheduledPayment, VaultConfig, VaultState};
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, token, vec, Address, BytesN, Env, IntoVal, Symbol,
+    contract, contractimpl, panic_with_error, token, vec, Address, BytesN, Env, IntoVal,
+    MuxedAddress, Symbol,
 };
 
 #[contract]
@@ -283,7 +284,8 @@ impl EscrowContract {
     /// Executes a previously scheduled payment once its release time has passed.
     ///
     /// Transfers the reserved tokens from this contract to the resolved owner of
-    /// the destination vault. Can be called by anyone (trustless keeper/bot).
+    /// the destination vault, as a muxed account when the owner's username resolves to
+    /// one. Can be called by anyone (trustless keeper/bot).
     ///
     /// ### Arguments
     /// - `payment_id`: The unique ID returned by [`EscrowContract::schedule_payment`].
@@ -474,7 +476,8 @@ impl EscrowContract {
     ///
     /// This function is trustless and can be called by anyone (bots, keeper scripts, SDK).
     /// It checks if the interval has elapsed since the last payment, validates the vault
-    /// balance, transfers the tokens, and updates the state.
+    /// balance, transfers the tokens (to the recipient's muxed account when its username
+    /// resolves to one), and updates the state.
     ///
     /// ### Arguments
     /// - `from`: The commitment ID of the source vault that owns the rule.
@@ -619,10 +622,25 @@ impl EscrowContract {
     }
 }
 
-/// Returns the owner address of the vault identified by `commitment`, panicking
+/// Returns the payout destination of the vault identified by `commitment`, panicking
 /// with `VaultNotFound` if no vault config exists.
-fn resolve(env: &Env, commitment: &BytesN<32>) -> Address {
+///
+/// When the Registration contract's `resolve_muxed` maps the commitment to a muxed
+/// account of the vault owner, the payout goes there so custodial accounts credit the
+/// right customer. Otherwise, including when the call fails, it goes to the owner.
+fn resolve(env: &Env, commitment: &BytesN<32>) -> MuxedAddress {
     let config = read_vault_config(env, commitment)
         .unwrap_or_else(|| panic_with_error!(env, EscrowError::VaultNotFound));
-    config.owner
+    read_registration_contract(env)
+        .and_then(|registration| {
+            env.try_invoke_contract::<Option<MuxedAddress>, soroban_sdk::Error>(
+                &registration,
+                &Symbol::new(env, "resolve_muxed"),
+                vec![env, commitment.into_val(env)],
+            )
+            .ok()?
+            .ok()?
+        })
+        .filter(|muxed| muxed.address() == config.owner)
+        .unwrap_or_else(|| config.owner.into())
 }
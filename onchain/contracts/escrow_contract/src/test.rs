@@ -2,10 +2,12 @@ use crate::errors::EscrowError;
 use crate::types::{AutoPay, DataKey, LegacyVault, ScheduledPayment, VaultConfig, VaultState};
 use crate::EscrowContract;
 use crate::EscrowContractClient;
-use soroban_sdk::testutils::{Address as _, Events as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::testutils::{
+    Address as _, Events as _, Ledger, MockAuth, MockAuthInvoke, MuxedAddress as _,
+};
 use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
 
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Error, IntoVal};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Error, IntoVal, MuxedAddress};
 
 // ---------------------------------------------------------------------------
 // Mock Registration contract — exposes get_owner / set_owner for tests.
@@ -24,6 +26,16 @@ impl MockRegistrationContract {
     pub fn get_owner(env: Env, commitment: BytesN<32>) -> Option<Address> {
         env.storage().persistent().get(&commitment)
     }
+
+    /// Seed a muxed destination for a commitment (test helper only).
+    pub fn set_muxed(env: Env, commitment: BytesN<32>, muxed: MuxedAddress) {
+        env.storage().temporary().set(&commitment, &muxed);
+    }
+
+    /// Mirror of the real Registration::resolve_muxed interface.
+    pub fn resolve_muxed(env: Env, commitment: BytesN<32>) -> Option<MuxedAddress> {
+        env.storage().temporary().get(&commitment)
+    }
 }
 
 fn setup_test(
@@ -1767,3 +1779,128 @@ fn test_is_vault_active_returns_none_for_nonexistent_vault() {
         "nonexistent vault must return None"
     );
 }
+
+// ─── muxed payout tests ──────────────────────────────────────────────────────
+
+/// Mock token that records the destination of the last transfer, so tests can see
+/// the muxed id the escrow paid to without needing a trustline on a real asset.
+#[contract]
+pub struct MockMuxedToken;
+
+#[contractimpl]
+impl MockMuxedToken {
+    /// Mirror of the token interface transfer; records the recipient and memo id.
+    pub fn transfer(env: Env, _from: Address, to: MuxedAddress, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&0u32, &(to.address(), to.id(), amount));
+    }
+
+    /// Returns (recipient, muxed id, amount) of the last transfer.
+    pub fn last_transfer(env: Env) -> Option<(Address, Option<u64>, i128)> {
+        env.storage().instance().get(&0u32)
+    }
+}
+
+/// Deploys an escrow wired to a MockRegistrationContract and a MockMuxedToken,
+/// creates a funded `from` vault and a `to` vault owned by `to_owner`, then returns
+/// (client, reg_client, token_client, from, to).
+fn setup_muxed_payout<'a>(
+    env: &'a Env,
+    to_owner: &Address,
+) -> (
+    EscrowContractClient<'a>,
+    MockRegistrationContractClient<'a>,
+    MockMuxedTokenClient<'a>,
+    BytesN<32>,
+    BytesN<32>,
+) {
+    let (contract_id, client, _, _, from, to) = setup_test(env);
+    let reg_id = env.register(MockRegistrationContract, ());
+    client.initialize(&Address::generate(env), &reg_id);
+    let token = env.register(MockMuxedToken, ());
+
+    create_vault(
+        env,
+        &contract_id,
+        &from,
+        &Address::generate(env),
+        &token,
+        1000,
+    );
+    create_vault(env, &contract_id, &to, to_owner, &token, 0);
+
+    (
+        client,
+        MockRegistrationContractClient::new(env, &reg_id),
+        MockMuxedTokenClient::new(env, &token),
+        from,
+        to,
+    )
+}
+
+#[test]
+fn test_execute_scheduled_pays_muxed_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let custody = MuxedAddress::generate(&env).address();
+    let (client, reg_client, token, from, to) = setup_muxed_payout(&env, &custody);
+    reg_client.set_muxed(&to, MuxedAddress::new(custody.clone(), 42));
+
+    env.ledger().set_timestamp(1000);
+    let payment_id = client.schedule_payment(&from, &to, &400, &2000);
+    env.ledger().set_timestamp(2500);
+    client.execute_scheduled(&payment_id);
+
+    // The custody account is credited and the memo id travels with the transfer.
+    assert_eq!(token.last_transfer(), Some((custody, Some(42), 400)));
+}
+
+#[test]
+fn test_execute_scheduled_ignores_muxed_of_another_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let to_owner = MuxedAddress::generate(&env).address();
+    let (client, reg_client, token, from, to) = setup_muxed_payout(&env, &to_owner);
+    // A muxed account that does not wrap the vault owner must never receive funds.
+    let stranger = MuxedAddress::generate(&env);
+    reg_client.set_muxed(&to, &stranger);
+
+    env.ledger().set_timestamp(1000);
+    let payment_id = client.schedule_payment(&from, &to, &400, &2000);
+    env.ledger().set_timestamp(2500);
+    client.execute_scheduled(&payment_id);
+
+    assert_eq!(token.last_transfer(), Some((to_owner, None, 400)));
+}
+
+#[test]
+fn test_execute_scheduled_without_muxed_pays_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let to_owner = Address::generate(&env);
+    let (client, _, token, from, to) = setup_muxed_payout(&env, &to_owner);
+
+    env.ledger().set_timestamp(1000);
+    let payment_id = client.schedule_payment(&from, &to, &400, &2000);
+    env.ledger().set_timestamp(2500);
+    client.execute_scheduled(&payment_id);
+
+    assert_eq!(token.last_transfer(), Some((to_owner, None, 400)));
+}
+
+#[test]
+fn test_trigger_auto_pay_pays_muxed_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let custody = MuxedAddress::generate(&env).address();
+    let (client, reg_client, token, from, to) = setup_muxed_payout(&env, &custody);
+    reg_client.set_muxed(&to, MuxedAddress::new(custody.clone(), 7));
+
+    env.ledger().set_timestamp(1000);
+    let rule_id = client.setup_auto_pay(&from, &to, &250, &100);
+    env.ledger().set_timestamp(1100);
+    client.trigger_auto_pay(&from, &rule_id);
+
+    assert_eq!(token.last_transfer(), Some((custody, Some(7), 250)));
+}
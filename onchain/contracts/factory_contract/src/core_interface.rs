@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, BytesN, Env};
+
+/// The subset of the core contract interface the factory calls.
+#[allow(dead_code)]
+#[contractclient(name = "CoreContractClient")]
+pub trait CoreContractInterface {
+    /// Returns `true` if the owner has made the username non-transferable.
+    fn is_transfer_locked(env: Env, commitment: BytesN<32>) -> bool;
+}
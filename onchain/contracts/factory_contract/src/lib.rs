@@ -1,5 +1,7 @@
 #![no_std]
 
+/// Cross-contract client for the core contract.
+mod core_interface;
 /// Error types returned by the factory contract.
 mod errors;
 /// Event emission helpers.
@@ -14,6 +16,7 @@ mod test;
 
 use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env};
 
+use crate::core_interface::CoreContractClient;
 use crate::errors::FactoryError;
 use crate::events::{emit_ownership_transferred, emit_username_deployed};
 use crate::storage::{
@@ -100,12 +103,18 @@ impl FactoryContract {
     /// Transfers the ownership of a username record.
     ///
     /// This function can only be called by the configured auction contract.
+    /// Usernames their owner has made non-transferable in the core contract are refused.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment.
     /// * `username_hash` - The 32-byte hash identifying the unique username.
     /// * `new_owner` - The address that will be the new owner.
+    ///
+    /// # Panics
+    ///
+    /// * `FactoryError::Unauthorized` if the caller is not the configured auction contract.
+    /// * `FactoryError::TransfersLocked` if the core contract reports the username as locked.
     pub fn transfer_username(env: Env, username_hash: BytesN<32>, new_owner: Address) {
         let auction_contract = match read_auction_contract(&env) {
             Some(address) => address,
@@ -114,6 +123,9 @@ impl FactoryContract {
         auction_contract.require_auth();
 
         let mut record = get_username(&env, &username_hash).expect("Username not deployed");
+        if CoreContractClient::new(&env, &record.core_contract).is_transfer_locked(&username_hash) {
+            panic_with_error!(&env, FactoryError::TransfersLocked);
+        }
 
         let old_owner = record.owner.clone();
         record.owner = new_owner.clone();
@@ -14,11 +14,26 @@ struct StubContract;
 #[contractimpl]
 impl StubContract {}
 
+/// Core contract stand-in exposing only the transfer-lock view the factory calls.
+#[contract]
+struct CoreStub;
+
+#[contractimpl]
+impl CoreStub {
+    pub fn lock(env: Env, commitment: BytesN<32>) {
+        env.storage().instance().set(&commitment, &true);
+    }
+
+    pub fn is_transfer_locked(env: Env, commitment: BytesN<32>) -> bool {
+        env.storage().instance().has(&commitment)
+    }
+}
+
 fn setup_factory(env: &Env) -> (Address, FactoryContractClient<'_>, Address, Address) {
     let factory_id = env.register(FactoryContract, ());
     let factory = FactoryContractClient::new(env, &factory_id);
     let auction_contract = env.register(StubContract, ());
-    let core_contract = env.register(StubContract, ());
+    let core_contract = env.register(CoreStub, ());
 
     factory.configure(&auction_contract, &core_contract);
 
@@ -344,3 +359,72 @@ fn contract_getters_follow_soroban_convention() {
     assert_eq!(factory.auction_contract(), Some(auction_contract));
     assert_eq!(factory.core_contract(), Some(core_contract));
 }
+
+fn deploy_with_auth(env: &Env, factory_id: &Address, auction: &Address, owner: &Address) {
+    let hash = username_hash(env);
+    let deploy_args: Vec<Val> = (hash.clone(), owner.clone()).into_val(env);
+    env.mock_auths(&[MockAuth {
+        address: auction,
+        invoke: &MockAuthInvoke {
+            contract: factory_id,
+            fn_name: "deploy_username",
+            args: deploy_args,
+            sub_invokes: &[],
+        },
+    }]);
+    FactoryContractClient::new(env, factory_id).deploy_username(&hash, owner);
+}
+
+fn mock_transfer_auth(env: &Env, factory_id: &Address, auction: &Address, new_owner: &Address) {
+    let transfer_args: Vec<Val> = (username_hash(env), new_owner.clone()).into_val(env);
+    env.mock_auths(&[MockAuth {
+        address: auction,
+        invoke: &MockAuthInvoke {
+            contract: factory_id,
+            fn_name: "transfer_username",
+            args: transfer_args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn transfer_username_moves_unlocked_name() {
+    let env = Env::default();
+    let (factory_id, factory, auction_contract, _) = setup_factory(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    deploy_with_auth(&env, &factory_id, &auction_contract, &owner);
+
+    mock_transfer_auth(&env, &factory_id, &auction_contract, &new_owner);
+    factory.transfer_username(&username_hash(&env), &new_owner);
+
+    assert_eq!(
+        factory.get_username_owner(&username_hash(&env)),
+        Some(new_owner)
+    );
+}
+
+#[test]
+fn transfer_username_rejects_soulbound_name() {
+    let env = Env::default();
+    let (factory_id, factory, auction_contract, core_contract) = setup_factory(&env);
+    let owner = Address::generate(&env);
+    deploy_with_auth(&env, &factory_id, &auction_contract, &owner);
+    CoreStubClient::new(&env, &core_contract).lock(&username_hash(&env));
+
+    let new_owner = Address::generate(&env);
+    mock_transfer_auth(&env, &factory_id, &auction_contract, &new_owner);
+    let result = factory.try_transfer_username(&username_hash(&env), &new_owner);
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            FactoryError::TransfersLocked as u32
+        )))
+    );
+    assert_eq!(
+        factory.get_username_owner(&username_hash(&env)),
+        Some(owner)
+    );
+}
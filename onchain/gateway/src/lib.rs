@@ -0,0 +1,120 @@
+//! # Signed Record Gateway
+//!
+//! Produces the responses an off-chain gateway serves for the core contract's
+//! `resolve_with_signed_record`, so large record sets (such as subnames) need no
+//! on-chain storage. The owner registers [`Gateway::signer`] with `set_gateway_signer`;
+//! readers fetch a [`SignedRecord`] from the gateway and pass it to the contract.
+//!
+//! ## Format
+//!
+//! The gateway signs, with ed25519:
+//!
+//! `"ALIEN_GATEWAY_SIGNED_RECORD_V1" || contract_id (ScVal XDR) || commitment || expiry (u64 BE) || record`
+//!
+//! The contract id binds the signature to one deployment, and therefore one network.
+//! The record bytes are opaque to the contract.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+/// Domain tag matching the core contract's `SIGNED_RECORD_DOMAIN`.
+pub const DOMAIN: &[u8] = b"ALIEN_GATEWAY_SIGNED_RECORD_V1";
+
+/// XDR prefix of an `ScVal::Address(ScAddress::Contract(_))`.
+const CONTRACT_ADDRESS_XDR_PREFIX: [u8; 8] = [0, 0, 0, 18, 0, 0, 0, 1];
+
+/// Errors returned when checking a signed record locally.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The signer bytes are not a valid ed25519 public key.
+    InvalidSigner,
+    /// The signature does not match the signer and message.
+    InvalidSignature,
+    /// The current time is past the record's expiry.
+    Expired,
+}
+
+/// A gateway response, ready to pass to `resolve_with_signed_record`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedRecord {
+    /// The record bytes.
+    pub record: Vec<u8>,
+    /// The gateway's ed25519 signature over [`message`].
+    pub signature: [u8; 64],
+    /// Ledger timestamp after which the contract rejects the record.
+    pub expiry: u64,
+}
+
+/// Signs records for one core contract deployment.
+pub struct Gateway {
+    /// The gateway's ed25519 key.
+    key: SigningKey,
+    /// The core contract's 32-byte contract id.
+    contract_id: [u8; 32],
+}
+
+impl Gateway {
+    /// Creates a gateway from its ed25519 secret and the core contract id.
+    pub fn new(secret: &[u8; 32], contract_id: [u8; 32]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(secret),
+            contract_id,
+        }
+    }
+
+    /// Returns the public key to register with `set_gateway_signer`.
+    pub fn signer(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    /// Signs `record` for `commitment`, valid until `expiry`.
+    pub fn sign(&self, commitment: &[u8; 32], record: &[u8], expiry: u64) -> SignedRecord {
+        let message = message(&self.contract_id, commitment, record, expiry);
+        SignedRecord {
+            record: record.to_vec(),
+            signature: self.key.sign(&message).to_bytes(),
+            expiry,
+        }
+    }
+}
+
+/// Builds the message the gateway signs, byte-for-byte as the contract does.
+pub fn message(
+    contract_id: &[u8; 32],
+    commitment: &[u8; 32],
+    record: &[u8],
+    expiry: u64,
+) -> Vec<u8> {
+    let mut out = Vec::with_capacity(DOMAIN.len() + 80 + record.len());
+    out.extend_from_slice(DOMAIN);
+    out.extend_from_slice(&CONTRACT_ADDRESS_XDR_PREFIX);
+    out.extend_from_slice(contract_id);
+    out.extend_from_slice(commitment);
+    out.extend_from_slice(&expiry.to_be_bytes());
+    out.extend_from_slice(record);
+    out
+}
+
+/// Checks a signed record the way the contract will, at ledger timestamp `now`.
+///
+/// ### Errors
+/// - `InvalidSigner`: If `signer` is not a valid public key.
+/// - `Expired`: If `now` is past the record's expiry.
+/// - `InvalidSignature`: If the signature does not verify.
+pub fn verify(
+    signer: &[u8; 32],
+    contract_id: &[u8; 32],
+    commitment: &[u8; 32],
+    signed: &SignedRecord,
+    now: u64,
+) -> Result<(), Error> {
+    let key = VerifyingKey::from_bytes(signer).map_err(|_| Error::InvalidSigner)?;
+    if now > signed.expiry {
+        return Err(Error::Expired);
+    }
+    let message = message(contract_id, commitment, &signed.record, signed.expiry);
+    key.verify(&message, &Signature::from_bytes(&signed.signature))
+        .map_err(|_| Error::InvalidSignature)
+}
+
+#[cfg(test)]
+mod test;
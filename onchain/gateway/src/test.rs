@@ -0,0 +1,66 @@
+use super::*;
+
+const SECRET: [u8; 32] = [0x11; 32];
+const CONTRACT_ID: [u8; 32] = [0x22; 32];
+const COMMITMENT: [u8; 32] = [0x33; 32];
+
+#[test]
+fn test_signed_record_verifies() {
+    let gateway = Gateway::new(&SECRET, CONTRACT_ID);
+    let signed = gateway.sign(
+        &COMMITMENT,
+        b"evm:0x52908400098527886E0F7030069857D2E4169EE7",
+        1_000,
+    );
+
+    assert_eq!(
+        verify(&gateway.signer(), &CONTRACT_ID, &COMMITMENT, &signed, 1_000),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_expired_record_is_rejected() {
+    let gateway = Gateway::new(&SECRET, CONTRACT_ID);
+    let signed = gateway.sign(&COMMITMENT, b"record", 1_000);
+
+    assert_eq!(
+        verify(&gateway.signer(), &CONTRACT_ID, &COMMITMENT, &signed, 1_001),
+        Err(Error::Expired)
+    );
+}
+
+#[test]
+fn test_record_is_bound_to_commitment_and_contract() {
+    let gateway = Gateway::new(&SECRET, CONTRACT_ID);
+    let signed = gateway.sign(&COMMITMENT, b"record", 1_000);
+
+    assert_eq!(
+        verify(&gateway.signer(), &CONTRACT_ID, &[0x44; 32], &signed, 0),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify(&gateway.signer(), &[0x44; 32], &COMMITMENT, &signed, 0),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_tampered_record_is_rejected() {
+    let gateway = Gateway::new(&SECRET, CONTRACT_ID);
+    let mut signed = gateway.sign(&COMMITMENT, b"record", 1_000);
+    signed.record[0] ^= 1;
+
+    assert_eq!(
+        verify(&gateway.signer(), &CONTRACT_ID, &COMMITMENT, &signed, 0),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_message_layout() {
+    let message = message(&CONTRACT_ID, &COMMITMENT, b"r", 0x0102);
+    assert_eq!(&message[..DOMAIN.len()], DOMAIN);
+    assert_eq!(message.len(), DOMAIN.len() + 8 + 32 + 32 + 8 + 1);
+    assert_eq!(&message[message.len() - 3..], &[0x01, 0x02, b'r']);
+}
@@ -0,0 +1,228 @@
+//! # Encrypted Record Client
+//!
+//! Builds and opens the encrypted address records stored by the core contract's
+//! `set_encrypted_record`, so only payers holding a granted viewing key learn where a
+//! username resolves.
+//!
+//! ## Format
+//!
+//! - **Record**: `nonce (12) || ChaCha20-Poly1305(content_key, nonce, destinations)`,
+//!   authenticated against the commitment so it cannot be replayed on another username.
+//! - **Envelope**: `ephemeral_public (32) || ChaCha20-Poly1305(kek, 0, content_key)`, where
+//!   `kek = HKDF-SHA256(X25519(ephemeral, viewer))` salted with both public keys. Each
+//!   envelope uses a fresh ephemeral key, so the zero nonce is never reused under a key.
+//! - **Destinations**: one `chain:address` pair per line.
+//!
+//! A viewing key may belong to a single payer or be shared by a group; the contract
+//! does not distinguish them.
+//!
+//! Randomness is supplied by the caller: the content key, record nonce and every
+//! ephemeral secret must come from a CSPRNG and never be reused. After revoking a
+//! viewer, re-seal the record under a new content key.
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+/// Domain separation label for deriving key-encryption keys.
+const KEK_INFO: &[u8] = b"encrypted-records/v1/kek";
+/// Length of the record nonce prefix.
+const NONCE_LEN: usize = 12;
+/// Length of an X25519 public key.
+const KEY_LEN: usize = 32;
+
+/// Errors returned when sealing or opening records and envelopes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The bytes are too short, or the destinations or plaintext are not a valid
+    /// destination list.
+    Malformed,
+    /// The plaintext exceeds the cipher's length limit.
+    Encryption,
+    /// Authentication failed: wrong key, wrong commitment or tampered data.
+    Decryption,
+    /// The envelope's ephemeral key yields a non-contributory shared secret.
+    WeakKey,
+}
+
+/// One address a username resolves to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Destination {
+    /// Chain label, such as `stellar` or `evm`. Must not contain `:` or a newline.
+    pub chain: String,
+    /// Address on that chain. Must not contain a newline.
+    pub address: String,
+}
+
+/// Returns the X25519 public key to publish for a viewing secret.
+pub fn viewing_public_key(viewing_secret: &[u8; 32]) -> [u8; 32] {
+    PublicKey::from(&StaticSecret::from(*viewing_secret)).to_bytes()
+}
+
+/// Encrypts `destinations` under `content_key` for the record of `commitment`.
+///
+/// ### Errors
+/// - `Malformed`: If a chain label contains `:` or a newline, or an address contains a
+///   newline.
+/// - `Encryption`: If the encoded list exceeds the cipher's length limit.
+pub fn seal_record(
+    commitment: &[u8; 32],
+    content_key: &[u8; 32],
+    nonce: &[u8; 12],
+    destinations: &[Destination],
+) -> Result<Vec<u8>, Error> {
+    let mut out = nonce.to_vec();
+    out.extend(seal(
+        content_key,
+        nonce,
+        &encode(destinations)?,
+        commitment,
+    )?);
+    Ok(out)
+}
+
+/// Decrypts a record with its content key.
+///
+/// ### Errors
+/// - `Malformed`: If the record is truncated or its plaintext is not a destination list.
+/// - `Decryption`: If the key or commitment is wrong, or the record was tampered with.
+pub fn open_record(
+    commitment: &[u8; 32],
+    content_key: &[u8; 32],
+    record: &[u8],
+) -> Result<Vec<Destination>, Error> {
+    if record.len() < NONCE_LEN {
+        return Err(Error::Malformed);
+    }
+    let (nonce, ciphertext) = record.split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().map_err(|_| Error::Malformed)?;
+    decode(&open(content_key, &nonce, ciphertext, commitment)?)
+}
+
+/// Wraps `content_key` for the holder of `viewer`, producing a grant envelope.
+///
+/// ### Errors
+/// - `Encryption`: If the cipher rejects the key; never expected for a 32-byte key.
+pub fn wrap_key(
+    commitment: &[u8; 32],
+    content_key: &[u8; 32],
+    viewer: &[u8; 32],
+    ephemeral_secret: &[u8; 32],
+) -> Result<Vec<u8>, Error> {
+    let ephemeral = StaticSecret::from(*ephemeral_secret);
+    let ephemeral_public = PublicKey::from(&ephemeral).to_bytes();
+    let shared = ephemeral.diffie_hellman(&PublicKey::from(*viewer));
+    let kek = derive_kek(shared.as_bytes(), &ephemeral_public, viewer);
+
+    let mut out = ephemeral_public.to_vec();
+    out.extend(seal(&kek, &[0; NONCE_LEN], content_key, commitment)?);
+    Ok(out)
+}
+
+/// Recovers the content key from an envelope addressed to `viewing_secret`.
+///
+/// ### Errors
+/// - `Malformed`: If the envelope is truncated.
+/// - `WeakKey`: If the envelope's ephemeral key is a low-order point.
+/// - `Decryption`: If the envelope is for another viewer or commitment, or was tampered with.
+pub fn unwrap_key(
+    commitment: &[u8; 32],
+    envelope: &[u8],
+    viewing_secret: &[u8; 32],
+) -> Result<[u8; 32], Error> {
+    if envelope.len() < KEY_LEN {
+        return Err(Error::Malformed);
+    }
+    let (ephemeral_public, wrapped) = envelope.split_at(KEY_LEN);
+    let ephemeral_public: [u8; KEY_LEN] =
+        ephemeral_public.try_into().map_err(|_| Error::Malformed)?;
+    let secret = StaticSecret::from(*viewing_secret);
+    let shared = secret.diffie_hellman(&PublicKey::from(ephemeral_public));
+    if !shared.was_contributory() {
+        return Err(Error::WeakKey);
+    }
+    let viewer = PublicKey::from(&secret).to_bytes();
+    let kek = derive_kek(shared.as_bytes(), &ephemeral_public, &viewer);
+
+    open(&kek, &[0; NONCE_LEN], wrapped, commitment)?
+        .try_into()
+        .map_err(|_| Error::Malformed)
+}
+
+/// Decrypts a record using the envelope granted to `viewing_secret`.
+///
+/// ### Errors
+/// Any error of [`unwrap_key`] or [`open_record`].
+pub fn decrypt(
+    commitment: &[u8; 32],
+    record: &[u8],
+    envelope: &[u8],
+    viewing_secret: &[u8; 32],
+) -> Result<Vec<Destination>, Error> {
+    let content_key = unwrap_key(commitment, envelope, viewing_secret)?;
+    open_record(commitment, &content_key, record)
+}
+
+/// (Internal) Derives the key-encryption key for one envelope.
+fn derive_kek(shared: &[u8; 32], ephemeral_public: &[u8; 32], viewer: &[u8; 32]) -> [u8; 32] {
+    let mut salt = [0u8; 2 * KEY_LEN];
+    salt[..KEY_LEN].copy_from_slice(ephemeral_public);
+    salt[KEY_LEN..].copy_from_slice(viewer);
+    let mut kek = [0u8; 32];
+    // A 32-byte output is always within HKDF-SHA256's limit.
+    let _ = Hkdf::<Sha256>::new(Some(&salt), shared).expand(KEK_INFO, &mut kek);
+    kek
+}
+
+/// (Internal) Encrypts and authenticates `msg` with `aad`.
+fn seal(key: &[u8; 32], nonce: &[u8; NONCE_LEN], msg: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error> {
+    ChaCha20Poly1305::new(&Key::from(*key))
+        .encrypt(&Nonce::from(*nonce), Payload { msg, aad })
+        .map_err(|_| Error::Encryption)
+}
+
+/// (Internal) Verifies and decrypts `msg` with `aad`.
+fn open(key: &[u8; 32], nonce: &[u8; NONCE_LEN], msg: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error> {
+    ChaCha20Poly1305::new(&Key::from(*key))
+        .decrypt(&Nonce::from(*nonce), Payload { msg, aad })
+        .map_err(|_| Error::Decryption)
+}
+
+/// (Internal) Encodes destinations as `chain:address` lines, rejecting any field that
+/// would not parse back unchanged.
+fn encode(destinations: &[Destination]) -> Result<Vec<u8>, Error> {
+    if destinations
+        .iter()
+        .any(|d| d.chain.contains([':', '\n']) || d.address.contains('\n'))
+    {
+        return Err(Error::Malformed);
+    }
+    Ok(destinations
+        .iter()
+        .map(|d| format!("{}:{}", d.chain, d.address))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .into_bytes())
+}
+
+/// (Internal) Parses `chain:address` lines.
+fn decode(plaintext: &[u8]) -> Result<Vec<Destination>, Error> {
+    let text = std::str::from_utf8(plaintext).map_err(|_| Error::Malformed)?;
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split('\n')
+        .map(|line| {
+            let (chain, address) = line.split_once(':').ok_or(Error::Malformed)?;
+            Ok(Destination {
+                chain: chain.to_string(),
+                address: address.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test;
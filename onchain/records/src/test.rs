@@ -0,0 +1,115 @@
+use super::*;
+
+const COMMITMENT: [u8; 32] = [0x01; 32];
+const CONTENT_KEY: [u8; 32] = [0x02; 32];
+const NONCE: [u8; 12] = [0x03; 12];
+const PAYER: [u8; 32] = [0x04; 32];
+const OTHER: [u8; 32] = [0x05; 32];
+
+fn destinations() -> Vec<Destination> {
+    vec![
+        Destination {
+            chain: "stellar".to_string(),
+            address: "GDQP2KPQGKIHYJGXNUIYOMHARUARCA7DJT5FO2FFOOKY3B2WSQHG4W37".to_string(),
+        },
+        Destination {
+            chain: "evm".to_string(),
+            address: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn test_granted_payer_decrypts_record() {
+    let record =
+        seal_record(&COMMITMENT, &CONTENT_KEY, &NONCE, &destinations()).expect("record sealed");
+    let envelope = wrap_key(
+        &COMMITMENT,
+        &CONTENT_KEY,
+        &viewing_public_key(&PAYER),
+        &[0x06; 32],
+    )
+    .expect("key wrapped");
+
+    assert_eq!(
+        decrypt(&COMMITMENT, &record, &envelope, &PAYER),
+        Ok(destinations())
+    );
+}
+
+#[test]
+fn test_envelope_fits_contract_limit() {
+    let envelope = wrap_key(
+        &COMMITMENT,
+        &CONTENT_KEY,
+        &viewing_public_key(&PAYER),
+        &[0x06; 32],
+    )
+    .expect("key wrapped");
+    assert_eq!(envelope.len(), 80);
+}
+
+#[test]
+fn test_other_viewer_cannot_unwrap() {
+    let envelope = wrap_key(
+        &COMMITMENT,
+        &CONTENT_KEY,
+        &viewing_public_key(&PAYER),
+        &[0x06; 32],
+    )
+    .expect("key wrapped");
+    assert_eq!(
+        unwrap_key(&COMMITMENT, &envelope, &OTHER),
+        Err(Error::Decryption)
+    );
+}
+
+#[test]
+fn test_record_is_bound_to_commitment() {
+    let record =
+        seal_record(&COMMITMENT, &CONTENT_KEY, &NONCE, &destinations()).expect("record sealed");
+    assert_eq!(
+        open_record(&[0x09; 32], &CONTENT_KEY, &record),
+        Err(Error::Decryption)
+    );
+}
+
+#[test]
+fn test_tampered_record_is_rejected() {
+    let mut record =
+        seal_record(&COMMITMENT, &CONTENT_KEY, &NONCE, &destinations()).expect("record sealed");
+    let last = record.len() - 1;
+    record[last] ^= 1;
+    assert_eq!(
+        open_record(&COMMITMENT, &CONTENT_KEY, &record),
+        Err(Error::Decryption)
+    );
+    assert_eq!(
+        open_record(&COMMITMENT, &CONTENT_KEY, &[0; 4]),
+        Err(Error::Malformed)
+    );
+}
+
+#[test]
+fn test_low_order_ephemeral_key_is_rejected() {
+    let mut envelope = vec![0u8; 32];
+    envelope.extend([0u8; 48]);
+    assert_eq!(
+        unwrap_key(&COMMITMENT, &envelope, &PAYER),
+        Err(Error::WeakKey)
+    );
+}
+
+#[test]
+fn test_seal_record_rejects_unencodable_destinations() {
+    for (chain, address) in [("evm:1", "0xabc"), ("evm\n", "0xabc"), ("evm", "0xabc\nx")] {
+        let destination = Destination {
+            chain: chain.to_string(),
+            address: address.to_string(),
+        };
+        assert_eq!(
+            seal_record(&COMMITMENT, &CONTENT_KEY, &NONCE, &[destination]),
+            Err(Error::Malformed)
+        );
+    }
+}
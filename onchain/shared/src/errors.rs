@@ -72,6 +72,7 @@ pub enum FactoryError {
     Unauthorized = 3001,
     AlreadyDeployed = 3002,
     CoreContractNotConfigured = 3003,
+    TransfersLocked = 3004,
 }
 
 #[contracterror]
@@ -100,6 +101,56 @@ pub enum CoreError {
     AlreadyRegistered = 4010,
     /// The new SMT root matches the existing on-chain root.
     RootUnchanged = 4011,
+    /// The address is already linked to the commitment.
+    DuplicateAddress = 4012,
+    /// The commitment already holds the maximum number of addresses for this chain.
+    AddressLimitReached = 4013,
+    /// The commitment already has the maximum number of delegate grants.
+    DelegateLimitReached = 4014,
+    /// The supplied expiry timestamp is not in the future.
+    InvalidExpiry = 4015,
+    /// There is no live ownership transfer proposal for the commitment.
+    NoPendingTransfer = 4016,
+    /// The ownership transfer proposal has expired.
+    TransferExpired = 4017,
+    /// Direct (single-step) ownership transfers are disabled.
+    DirectTransferDisabled = 4018,
+    /// The guardian set or threshold is invalid.
+    InvalidGuardianConfig = 4019,
+    /// The open recovery request reached its threshold for a different new owner.
+    RecoveryInProgress = 4020,
+    /// The guardian has already approved the open recovery request.
+    AlreadyApproved = 4021,
+    /// There is no open recovery request for the commitment.
+    NoRecoveryRequest = 4022,
+    /// The recovery threshold is not met or its timelock has not elapsed.
+    RecoveryNotReady = 4023,
+    /// The commitment is reserved and the caller is not its designated claimant.
+    NameReserved = 4025,
+    /// A batch call exceeded its maximum number of entries.
+    BatchTooLarge = 4026,
+    /// The allocation claim round deadline has passed.
+    ClaimExpired = 4027,
+    /// The commitment has already been claimed in this round.
+    AlreadyClaimed = 4028,
+    /// The commitment has been made non-transferable by its owner.
+    TransfersLocked = 4029,
+    /// Payment records and transfers are frozen after a recent ownership change.
+    CooldownActive = 4030,
+    /// The signer set is empty, too large, has duplicates, or the threshold is out of range.
+    InvalidMultisigConfig = 4031,
+    /// Fewer distinct signers than the group threshold authorized the action.
+    InsufficientSigners = 4032,
+    /// The stealth meta-address has an unknown scheme or malformed keys.
+    InvalidStealthMetaAddress = 4033,
+    /// The stealth announcement has malformed keys, view tag or oversized metadata.
+    InvalidAnnouncement = 4034,
+    /// The encrypted record or one of its access grants is empty, oversized or duplicated.
+    InvalidEncryptedRecord = 4035,
+    /// The revealed username is malformed or does not hash to the commitment.
+    InvalidUsername = 4036,
+    /// The off-chain signed record is past its expiry.
+    SignedRecordExpired = 4037,
 }
 
 #[contracterror]
@@ -112,4 +163,14 @@ pub enum ChainAddressError {
     NotRegistered = 5002,
     /// The address format is invalid for the given chain type.
     InvalidAddress = 5003,
+    /// The ownership signature does not match the linked address.
+    InvalidSignature = 5004,
+    /// Signature-verified linking is not supported for the given chain type.
+    UnsupportedChain = 5005,
+    /// The address is already linked for this chain.
+    DuplicateAddress = 5006,
+    /// The chain already holds the maximum number of addresses.
+    AddressLimitReached = 5007,
+    /// The address is not linked for this chain.
+    AddressNotFound = 5008,
 }
@@ -0,0 +1,188 @@
+//! # Stealth Address Helpers
+//!
+//! Off-chain derivation and scanning for stealth payments to usernames registered in
+//! the core contract, following ERC-5564 scheme 1 (secp256k1 with view tags).
+//!
+//! ## Flow
+//!
+//! 1. The recipient derives a [`MetaAddress`] from their spending and viewing secrets
+//!    and registers it with `set_stealth_meta_address`.
+//! 2. A sender reads the meta-address, picks a fresh ephemeral secret and calls
+//!    [`generate`]. They pay the returned one-time address and call `announce` with
+//!    [`Announcement::ephemeral_pubkey`] and [`Announcement::view_tag`].
+//! 3. The recipient runs [`scan`] over `ANNOUNCE` events with their viewing secret. The
+//!    view tag rejects about 255 of every 256 unrelated announcements with one hash.
+//! 4. For a match, [`stealth_secret`] gives the private key controlling the one-time
+//!    address.
+//!
+//! The viewing secret can scan but not spend, so it can be handed to a scanning service.
+//!
+//! ## Derivation
+//!
+//! With ephemeral secret `r`, viewing key `V = v·G` and spending key `K = k·G`:
+//! - shared secret `S = r·V = v·R`, hashed as `s = keccak256(S.x)`
+//! - view tag `s[0]`
+//! - stealth public key `P = K + s·G`, with secret `k + s`
+//! - stealth address `keccak256(P_uncompressed[1..])[12..]`
+
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, U256};
+use sha3::{Digest, Keccak256};
+
+/// The core contract's scheme id for these derivations.
+pub const SCHEME_ID: u32 = 1;
+
+/// Errors returned by the stealth helpers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A secret is zero or not below the curve order.
+    InvalidSecret,
+    /// A public key is not a valid SEC1 secp256k1 point.
+    InvalidPublicKey,
+}
+
+/// A recipient's stealth meta-address: the keys registered on their commitment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MetaAddress {
+    /// Compressed spending public key `K`.
+    pub spending_pubkey: [u8; 33],
+    /// Compressed viewing public key `V`.
+    pub viewing_pubkey: [u8; 33],
+}
+
+impl MetaAddress {
+    /// Derives the meta-address for a spending and a viewing secret.
+    ///
+    /// ### Errors
+    /// - `InvalidSecret`: If either secret is not a valid secp256k1 scalar.
+    pub fn from_secrets(
+        spending_secret: &[u8; 32],
+        viewing_secret: &[u8; 32],
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            spending_pubkey: compress(&secret_key(spending_secret)?.public_key()),
+            viewing_pubkey: compress(&secret_key(viewing_secret)?.public_key()),
+        })
+    }
+}
+
+/// The values a sender publishes with `announce`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Announcement {
+    /// The one-time address the payment is sent to.
+    pub stealth_address: [u8; 20],
+    /// Compressed ephemeral public key `R`.
+    pub ephemeral_pubkey: [u8; 33],
+    /// First byte of the hashed shared secret.
+    pub view_tag: u8,
+}
+
+/// Derives a one-time address for `meta` using a fresh, never reused ephemeral secret.
+///
+/// ### Errors
+/// - `InvalidSecret`: If `ephemeral_secret` is not a valid secp256k1 scalar.
+/// - `InvalidPublicKey`: If a key in `meta` is malformed.
+pub fn generate(meta: &MetaAddress, ephemeral_secret: &[u8; 32]) -> Result<Announcement, Error> {
+    let ephemeral = secret_key(ephemeral_secret)?;
+    let hashed = hashed_secret(&ephemeral, &public_key(&meta.viewing_pubkey)?);
+    let stealth = stealth_pubkey(&public_key(&meta.spending_pubkey)?, &hashed)?;
+    Ok(Announcement {
+        stealth_address: address(&stealth),
+        ephemeral_pubkey: compress(&ephemeral.public_key()),
+        view_tag: hashed[0],
+    })
+}
+
+/// Returns whether `announcement` pays the recipient owning `viewing_secret` and
+/// `spending_pubkey`.
+///
+/// ### Errors
+/// - `InvalidSecret`: If `viewing_secret` is not a valid secp256k1 scalar.
+/// - `InvalidPublicKey`: If `spending_pubkey` or the ephemeral key is malformed.
+pub fn scan(
+    viewing_secret: &[u8; 32],
+    spending_pubkey: &[u8; 33],
+    announcement: &Announcement,
+) -> Result<bool, Error> {
+    let ephemeral = public_key(&announcement.ephemeral_pubkey)?;
+    let hashed = hashed_secret(&secret_key(viewing_secret)?, &ephemeral);
+    if hashed[0] != announcement.view_tag {
+        return Ok(false);
+    }
+    let stealth = stealth_pubkey(&public_key(spending_pubkey)?, &hashed)?;
+    Ok(address(&stealth) == announcement.stealth_address)
+}
+
+/// Returns the private key controlling the one-time address announced with
+/// `ephemeral_pubkey`.
+///
+/// ### Errors
+/// - `InvalidSecret`: If a secret is not a valid secp256k1 scalar, or the derived key is zero.
+/// - `InvalidPublicKey`: If `ephemeral_pubkey` is malformed.
+pub fn stealth_secret(
+    spending_secret: &[u8; 32],
+    viewing_secret: &[u8; 32],
+    ephemeral_pubkey: &[u8; 33],
+) -> Result<[u8; 32], Error> {
+    let hashed = hashed_secret(&secret_key(viewing_secret)?, &public_key(ephemeral_pubkey)?);
+    let spending = *secret_key(spending_secret)?.to_nonzero_scalar();
+    let stealth = Option::<NonZeroScalar>::from(NonZeroScalar::new(spending + reduce(&hashed)))
+        .ok_or(Error::InvalidSecret)?;
+    Ok(stealth.to_bytes().into())
+}
+
+/// Returns the one-time address controlled by `stealth_secret`.
+///
+/// ### Errors
+/// - `InvalidSecret`: If `stealth_secret` is not a valid secp256k1 scalar.
+pub fn address_of(stealth_secret: &[u8; 32]) -> Result<[u8; 20], Error> {
+    Ok(address(&secret_key(stealth_secret)?.public_key()))
+}
+
+/// (Internal) Parses a 32-byte secret scalar.
+fn secret_key(bytes: &[u8; 32]) -> Result<SecretKey, Error> {
+    SecretKey::from_slice(bytes).map_err(|_| Error::InvalidSecret)
+}
+
+/// (Internal) Parses a SEC1-encoded public key.
+fn public_key(bytes: &[u8]) -> Result<PublicKey, Error> {
+    PublicKey::from_sec1_bytes(bytes).map_err(|_| Error::InvalidPublicKey)
+}
+
+/// (Internal) Returns the compressed SEC1 encoding of `key`.
+fn compress(key: &PublicKey) -> [u8; 33] {
+    let mut out = [0u8; 33];
+    out.copy_from_slice(key.to_encoded_point(true).as_bytes());
+    out
+}
+
+/// (Internal) Returns `keccak256(x)` of the ECDH shared point `secret·public`.
+fn hashed_secret(secret: &SecretKey, public: &PublicKey) -> [u8; 32] {
+    let shared = (public.to_projective() * *secret.to_nonzero_scalar()).to_affine();
+    let encoded = shared.to_encoded_point(true);
+    Keccak256::digest(&encoded.as_bytes()[1..]).into()
+}
+
+/// (Internal) Reduces a hash to a scalar modulo the curve order.
+fn reduce(hashed: &[u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*hashed))
+}
+
+/// (Internal) Returns `spending + hashed·G`.
+fn stealth_pubkey(spending: &PublicKey, hashed: &[u8; 32]) -> Result<PublicKey, Error> {
+    let point = spending.to_projective() + ProjectivePoint::GENERATOR * reduce(hashed);
+    PublicKey::from_affine(point.to_affine()).map_err(|_| Error::InvalidPublicKey)
+}
+
+/// (Internal) Returns the last 20 bytes of `keccak256` over the uncompressed point.
+fn address(key: &PublicKey) -> [u8; 20] {
+    let encoded = key.to_encoded_point(false);
+    let hash = Keccak256::digest(&encoded.as_bytes()[1..]);
+    let mut out = [0u8; 20];
+    out.copy_from_slice(&hash[12..]);
+    out
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,70 @@
+use super::*;
+
+const SPENDING: [u8; 32] = [0x11; 32];
+const VIEWING: [u8; 32] = [0x22; 32];
+
+fn meta() -> MetaAddress {
+    MetaAddress::from_secrets(&SPENDING, &VIEWING).expect("valid secrets")
+}
+
+#[test]
+fn test_meta_address_keys_are_compressed() {
+    let meta = meta();
+    assert!(matches!(meta.spending_pubkey[0], 0x02 | 0x03));
+    assert!(matches!(meta.viewing_pubkey[0], 0x02 | 0x03));
+    assert_ne!(meta.spending_pubkey, meta.viewing_pubkey);
+}
+
+#[test]
+fn test_recipient_finds_and_controls_payment() {
+    let meta = meta();
+    let announcement = generate(&meta, &[0x33; 32]).expect("valid ephemeral");
+
+    assert!(scan(&VIEWING, &meta.spending_pubkey, &announcement).expect("scan"));
+    let secret = stealth_secret(&SPENDING, &VIEWING, &announcement.ephemeral_pubkey)
+        .expect("stealth secret");
+    assert_eq!(
+        address_of(&secret).expect("address"),
+        announcement.stealth_address
+    );
+}
+
+#[test]
+fn test_ephemeral_secrets_give_unlinkable_addresses() {
+    let meta = meta();
+    let first = generate(&meta, &[0x33; 32]).expect("valid ephemeral");
+    let second = generate(&meta, &[0x44; 32]).expect("valid ephemeral");
+    assert_ne!(first.stealth_address, second.stealth_address);
+    assert_ne!(first.ephemeral_pubkey, second.ephemeral_pubkey);
+}
+
+#[test]
+fn test_other_recipient_does_not_match() {
+    let other = MetaAddress::from_secrets(&[0x55; 32], &[0x66; 32]).expect("valid secrets");
+    let announcement = generate(&other, &[0x33; 32]).expect("valid ephemeral");
+    assert!(!scan(&VIEWING, &meta().spending_pubkey, &announcement).expect("scan"));
+}
+
+#[test]
+fn test_wrong_view_tag_is_rejected() {
+    let meta = meta();
+    let mut announcement = generate(&meta, &[0x33; 32]).expect("valid ephemeral");
+    announcement.view_tag = announcement.view_tag.wrapping_add(1);
+    assert!(!scan(&VIEWING, &meta.spending_pubkey, &announcement).expect("scan"));
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert_eq!(
+        MetaAddress::from_secrets(&[0; 32], &VIEWING),
+        Err(Error::InvalidSecret)
+    );
+    assert_eq!(
+        MetaAddress::from_secrets(&SPENDING, &[0xff; 32]),
+        Err(Error::InvalidSecret)
+    );
+
+    let mut meta = meta();
+    meta.viewing_pubkey[0] = 0x01;
+    assert_eq!(generate(&meta, &[0x33; 32]), Err(Error::InvalidPublicKey));
+}
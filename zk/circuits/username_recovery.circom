@@ -0,0 +1,64 @@
+pragma circom 2.0.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "username_hash_impl.circom";
+
+// Proves knowledge of the username behind a registered commitment and of the
+// secret salt behind the owner's recovery commitment, authorizing the on-chain
+// owner to be rotated without the current owner's key.
+//
+//   commitment          = UsernameHash(username)        (as registered)
+//   recovery_commitment = Poseidon(commitment, salt)    (set by the owner)
+//
+// The username commitment alone is unsalted, so knowing the username is not
+// enough: the salt is what makes the proof an ownership credential.
+//
+// Public inputs:
+//   circuit_id          — fixed to RECOVERY_CIRCUIT_ID so proofs for other circuits
+//                         (which share the commitment input) can never be replayed here.
+//   commitment          — the registered username commitment being recovered.
+//   recovery_commitment — the salted commitment the owner registers on-chain.
+//   owner_binding       — sha256(contract_id XDR || new_owner XDR) with the top byte
+//                         cleared; pins the proof to one contract and one new owner so
+//                         it cannot be front-run to a different address.
+//   nonce               — the contract's per-commitment recovery nonce; each accepted
+//                         proof increments it, so a proof can only be used once.
+template UsernameRecovery() {
+
+    var RECOVERY_CIRCUIT_ID = 2;
+
+    // PRIVATE
+    signal input username[32];
+    signal input salt;
+
+    // PUBLIC
+    signal input circuit_id;
+    signal input commitment;
+    signal input recovery_commitment;
+    signal input owner_binding;
+    signal input nonce;
+
+    circuit_id === RECOVERY_CIRCUIT_ID;
+
+    component usernameHash = UsernameHash();
+    for (var i = 0; i < 32; i++) {
+        usernameHash.username[i] <== username[i];
+    }
+
+    commitment === usernameHash.username_hash;
+
+    component salted = Poseidon(2);
+    salted.inputs[0] <== usernameHash.username_hash;
+    salted.inputs[1] <== salt;
+
+    recovery_commitment === salted.out;
+
+    // Bind the remaining public inputs into the constraint system so the
+    // compiler cannot drop them and a proof cannot be replayed with other values.
+    signal bindingSquare;
+    signal nonceSquare;
+    bindingSquare <== owner_binding * owner_binding;
+    nonceSquare <== nonce * nonce;
+}
+
+component main {public [circuit_id, commitment, recovery_commitment, owner_binding, nonce]} = UsernameRecovery();
@@ -31,6 +31,9 @@ if errorlevel 1 goto :error
 call :compile_circuit "username_hash" "username_hash_main.circom"
 if errorlevel 1 goto :error
 
+call :compile_circuit "username_recovery" "username_recovery.circom"
+if errorlevel 1 goto :error
+
 echo ================================================
 echo    All circuits compiled successfully!
 echo ================================================
@@ -16,6 +16,7 @@ CIRCUITS=(
   "merkle_update_proof|merkle/merkle_update_proof.circom"
   "username_merkle|username_merkle.circom"
   "username_hash|username_hash_main.circom"
+  "username_recovery|username_recovery.circom"
 )
 
 GREEN="\033[0;32m"
@@ -20,6 +20,7 @@ declare -A CONTRACT_NAMES=(
   ["merkle_update_proof"]="MerkleUpdateProofVerifier"
   ["username_merkle"]="UsernameMerkleVerifier"
   ["username_hash"]="UsernameHashVerifier"
+  ["username_recovery"]="UsernameRecoveryVerifier"
 )
 
 CIRCUITS=(
@@ -29,6 +30,7 @@ CIRCUITS=(
   "merkle_update_proof"
   "username_merkle"
   "username_hash"
+  "username_recovery"
 )
 
 GREEN="\033[0;32m"
@@ -19,6 +19,7 @@ CIRCUITS=(
   "merkle_update_proof"
   "username_merkle"
   "username_hash"
+  "username_recovery"
 )
 
 # Power of 2 constraints — merkle_inclusion has ~8070 constraints, needs >= 14